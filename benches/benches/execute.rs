@@ -0,0 +1,67 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, Compiled};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::{Symbol, TuringMachine};
+
+/// The count of ones on the input tape (the erase machine takes about
+/// `LEN * LEN` steps).
+const LEN: usize = 200;
+
+/// Erases ones from the right end, returning to the left end every time.
+fn new_erase_machine<S: Symbol>(blank: S, one: S) -> Classic<S> {
+    let mut program = Program::new(vec![blank.clone(), one.clone()], State(3));
+    #[rustfmt::skip]
+    program
+        .extend([
+            (1, one.clone(), 1, one.clone(), Move::Right),
+            (1, blank.clone(), 2, blank.clone(), Move::Left),
+            (2, one.clone(), 3, blank.clone(), Move::Left),
+            (2, blank.clone(), 0, blank.clone(), Move::None),
+            (3, one.clone(), 3, one.clone(), Move::Left),
+            (3, blank.clone(), 1, blank.clone(), Move::Right),
+        ])
+        .unwrap();
+    Classic::new(program, blank).unwrap()
+}
+
+fn bench<S: Symbol>(c: &mut Criterion, name: &str, blank: S, one: S) {
+    let classic = new_erase_machine(blank, one.clone());
+    let compiled: Compiled<S> = Compiled::from_classic(&classic).unwrap();
+    let tape = Tape::new(vec![one; LEN]);
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("classic", |b| {
+        b.iter(|| classic.translate_nrm(black_box(tape.clone())).unwrap())
+    });
+    group.bench_function("compiled", |b| {
+        b.iter(|| compiled.translate_nrm(black_box(tape.clone())).unwrap())
+    });
+
+    let conf = Configuration::new_nrm(tape).unwrap();
+    group.bench_function("classic until", |b| {
+        b.iter(|| {
+            classic
+                .execute_until(black_box(conf.clone()), |conf| conf.state == State(0))
+                .unwrap()
+        })
+    });
+    group.bench_function("compiled until", |b| {
+        b.iter(|| {
+            compiled
+                .execute_until(black_box(conf.clone()), |conf| conf.state == State(0))
+                .unwrap()
+        })
+    });
+    group.finish();
+}
+
+fn erase(c: &mut Criterion) {
+    bench(c, "erase char", '_', '1');
+    bench(c, "erase Box<char>", Box::new('_'), Box::new('1'));
+}
+
+criterion_group!(benches, erase);
+criterion_main!(benches);
@@ -1,10 +1,9 @@
 extern crate turing_machine_rs;
 
 use turing_machine_rs::instruction::{Move, State};
-use turing_machine_rs::machines::Classic;
+use turing_machine_rs::machines::{Classic, MetaMachine};
 use turing_machine_rs::program::{Extend, Program};
 use turing_machine_rs::state::{Configuration, Tape};
-use turing_machine_rs::TuringMachine;
 
 // For more comfortable coding, use Result<(), String>:
 // `?` postfix symbol is better then `.unwrap()` postfix method call.
@@ -62,7 +61,8 @@ fn main() -> Result<(), String> {
         zerofy.clone(),
         l_shift.clone(),
     ]);
-    let result_choose_third = hyper_machine.translate_nrm(choose_second)?;
+    // MetaMachine uses the hyper machine as the control machine
+    let meta_machine = MetaMachine::from_control(&hyper_machine, choose_second)?;
 
     let expected_choose_third = Tape::new([
         r_shift.clone(),
@@ -75,19 +75,23 @@ fn main() -> Result<(), String> {
         l_shift.clone(),
     ]);
 
-    assert_eq!(expected_choose_third, result_choose_third);
+    assert_eq!(&expected_choose_third, meta_machine.machines());
     println!("If you're reading this, hyper machine successful transform choose second machine");
 
+    // MetaMachine executes machines one after another and resets the state
     let tape = Tape::from("0101101110");
-    let mut conf = Configuration::new_nrm(tape.clone())?;
-    for machine in result_choose_third.as_vec() {
-        conf = machine.execute(conf).unwrap();
-        conf.state = State(1)
+    let reports = meta_machine.run(Configuration::new_nrm(tape.clone())?, 1000)?;
+    for report in reports.iter() {
+        println!(
+            "Stage {} takes {} steps: {}",
+            report.index, report.steps, report.configuration
+        );
     }
+    let result = reports.last().unwrap().configuration.tape();
     println!(
         "Choose third machine translate {} into {}",
         String::from_iter(tape.as_vec()),
-        String::from_iter(conf.tape().as_vec())
+        String::from_iter(result.as_vec())
     );
 
     Ok(())
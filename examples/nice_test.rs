@@ -9,7 +9,7 @@ use turing_machine_rs::TuringMachine;
 // For more comfortable coding, use Result<(), String>:
 // `?` postfix symbol is better then `.unwrap()` postfix method call.
 fn main() -> Result<(), String> {
-    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', 'e', '_'];
+    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', '_'];
     let mut program = Program::new(alphabet, State(4));
     program.extend([
         (1, 't', 2, 'n', Move::Right),
@@ -0,0 +1,25 @@
+# Cases for binary_addition.tm:
+#   cargo run --features cli --bin tm -- test examples/programs/binary_addition.tm examples/programs/binary_addition.spec
+name: zero plus zero
+input: 0+0
+start: std
+output: 0
+trim: yes
+
+name: one plus one
+input: 1+1
+start: std
+output: 10
+trim: yes
+
+name: the example
+input: 10101+111
+start: std
+output: "11100     "
+index: 6
+
+name: zero needs the blank on the left
+input: 0+1
+start: std
+output: 1
+trim: yes
@@ -0,0 +1,37 @@
+# Binary addition: translates the tape `a+b` into ` c ` where c is a + b.
+# Run with the standard configuration:
+#   cargo run --features cli --bin tm -- run examples/programs/binary_addition.tm "10101+111" --std
+alphabet: ' ' 0 1 +
+l_state: 8
+
+# Sub 1, also init zero check
+1, ' ' -> 0, ' ', -
+1, 0 -> 1, 0, <
+1, 1 -> 2, 0, >
+1, + -> 6, +, >
+# Subs part
+2, ' ' -> 3, ' ', <
+2, 0 -> 2, 1, >
+# Find + on left
+3, 0 -> 3, 0, <
+3, 1 -> 3, 1, <
+3, + -> 4, +, <
+# Add 1
+4, ' ' -> 5, 1, >
+4, 0 -> 5, 1, >
+4, 1 -> 4, 0, <
+# Find + on right
+5, 0 -> 5, 0, >
+5, 1 -> 5, 1, >
+5, + -> 6, +, >
+# Zero check
+6, ' ' -> 8, ' ', <
+6, 0 -> 6, 0, >
+6, 1 -> 7, 1, >
+# Find last num
+7, ' ' -> 1, ' ', <
+7, 0 -> 7, 0, >
+7, 1 -> 7, 1, >
+# Clear + and after
+8, 0 -> 8, ' ', <
+8, + -> 0, ' ', >
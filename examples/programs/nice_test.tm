@@ -0,0 +1,12 @@
+# Replaces `nice` by `test` and `test` by `nice`.
+#   cargo run --features cli --bin tm -- run examples/programs/nice_test.tm test
+alphabet: _ t e s n i c
+1, t -> 2, n, >
+2, e -> 3, i, >
+3, s -> 4, c, >
+4, t -> 0, e, -
+# Revers
+1, n -> 2, t, >
+2, i -> 3, e, >
+3, c -> 4, s, >
+4, e -> 0, t, -
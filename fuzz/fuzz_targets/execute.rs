@@ -0,0 +1,98 @@
+#![no_main]
+
+use std::cell::Cell;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use turing_machine_rs::instruction::{Instruction, Move, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::Program;
+use turing_machine_rs::state::{Configuration, SparseTape, Tape, TapeStorage};
+use turing_machine_rs::TuringMachine;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    alphabet: Vec<u8>,
+    l_state: u8,
+    instructions: Vec<(u8, u8, u8, u8, u8)>,
+    default: u8,
+    tape: Vec<u8>,
+    index: usize,
+    start: u8,
+    steps: u8,
+}
+
+fn movement(byte: u8) -> Move {
+    match byte % 3 {
+        0 => Move::Left,
+        1 => Move::None,
+        _ => Move::Right,
+    }
+}
+
+fn configuration<T: TapeStorage<u8>>(
+    tape: T,
+    index: usize,
+    start: u8,
+) -> Result<Configuration<u8, T>, String> {
+    match start % 3 {
+        0 => Configuration::new_nrm(tape),
+        1 => Configuration::new_std(tape),
+        _ => Configuration::new(tape, index, State(1)),
+    }
+}
+
+/// Executes the configuration step by step and by `execute_until`,
+/// the index must always be in the tape bounds.
+fn execute<T: TapeStorage<u8>>(machine: &Classic<u8, T>, conf: Configuration<u8, T>, steps: u8) {
+    let mut once = conf.clone();
+    for _ in 0..steps {
+        if once.state == State(0) {
+            break;
+        }
+        once = match machine.execute_once(once) {
+            Ok(conf) => conf,
+            Err(_) => break,
+        };
+        assert!(once.index() < once.len());
+    }
+
+    let count = Cell::new(0);
+    let until = |conf: &Configuration<u8, T>| {
+        count.set(count.get() + 1);
+        conf.state == State(0) || count.get() > steps as usize
+    };
+    if let Ok(conf) = machine.execute_until(conf, until) {
+        assert!(conf.index() < conf.len());
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let mut program = match Program::try_new(input.alphabet, State(input.l_state as usize)) {
+        Ok(program) => program,
+        Err(_) => return,
+    };
+    for (h_state, h_symbol, t_state, t_symbol, byte) in input.instructions {
+        let _ = program.insert(Instruction::build(
+            State(h_state as usize),
+            h_symbol,
+            State(t_state as usize),
+            t_symbol,
+            movement(byte),
+        ));
+    }
+
+    if let Ok(machine) = Classic::new(program.clone(), input.default) {
+        let tape = Tape::new(input.tape.iter().cloned());
+        if let Ok(conf) = configuration(tape, input.index, input.start) {
+            execute(&machine, conf, input.steps);
+        }
+    }
+    if let Ok(machine) = Classic::<u8, SparseTape<u8>>::for_storage(program, input.default) {
+        let tape = SparseTape::from_symbols(input.default, input.tape);
+        if let Ok(conf) = configuration(tape, input.index, input.start) {
+            execute(&machine, conf, input.steps);
+        }
+    }
+});
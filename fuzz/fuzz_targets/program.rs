@@ -0,0 +1,65 @@
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use turing_machine_rs::instruction::{Head, Instruction, Move, State};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::With;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    alphabet: Vec<u8>,
+    l_state: usize,
+    instructions: Vec<(usize, u8, usize, u8, u8)>,
+    other_l_state: usize,
+    removed: Vec<(usize, u8)>,
+}
+
+fn movement(byte: u8) -> Move {
+    match byte % 3 {
+        0 => Move::Left,
+        1 => Move::None,
+        _ => Move::Right,
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let tuples: Vec<_> = input
+        .instructions
+        .iter()
+        .map(|&(h_state, h_symbol, t_state, t_symbol, byte)| {
+            (h_state, h_symbol, t_state, t_symbol, movement(byte))
+        })
+        .collect();
+
+    // Duplicated symbols are rejected
+    let mut extended = match Program::try_new(input.alphabet.clone(), State(input.l_state)) {
+        Ok(program) => program,
+        Err(_) => return,
+    };
+    // Extending interrupts on the first invalid instruction
+    let _ = extended.extend(tuples.clone());
+
+    let mut program = Program::new(input.alphabet.clone(), State(input.l_state));
+    for (h_state, h_symbol, t_state, t_symbol, movement) in tuples {
+        let inst = Instruction::build(State(h_state), h_symbol, State(t_state), t_symbol, movement);
+        let _ = program.insert(inst);
+    }
+    for inst in program.instructions() {
+        assert_eq!(program.get(&inst.head), Ok(Some(inst)));
+    }
+
+    let other = Program::new(input.alphabet, State(input.other_l_state));
+    if let Ok(merged) = program.with(&other) {
+        assert_eq!(merged.instructions().len(), program.instructions().len());
+    }
+
+    for (state, symbol) in input.removed {
+        let head = Head::new(State(state), symbol);
+        if let Ok(Some(inst)) = program.remove(&head) {
+            assert_eq!(inst.head, head);
+            assert_eq!(program.get(&head), Ok(None));
+        }
+    }
+});
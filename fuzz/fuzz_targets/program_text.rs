@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use turing_machine_rs::instruction::Instruction;
+use turing_machine_rs::program::Program;
+
+fuzz_target!(|text: &str| {
+    if let Ok(program) = text.parse::<Program<char>>() {
+        let source = program.to_source();
+        assert_eq!(source.parse::<Program<char>>(), Ok(program), "{}", source);
+    }
+    let _ = text.parse::<Instruction<char>>();
+});
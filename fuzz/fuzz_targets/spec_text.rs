@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use turing_machine_rs::spec::Spec;
+
+fuzz_target!(|text: &str| {
+    let _ = text.parse::<Spec<char>>();
+});
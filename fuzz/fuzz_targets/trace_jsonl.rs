@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use turing_machine_rs::trace::Trace;
+
+fuzz_target!(|text: &str| {
+    if let Ok(trace) = Trace::<char>::from_jsonl(text) {
+        let jsonl = trace.to_jsonl();
+        assert_eq!(Trace::from_jsonl(&jsonl), Ok(trace), "{}", jsonl);
+    }
+});
@@ -0,0 +1,226 @@
+//! Procedural macros for [Turing Machine RS](https://github.com/Helltraitor/turing-machine-rs).
+//!
+//! The [`compile!`] macro compiles the program (written in the text format
+//! of [`turing_machine_rs::program::Program`]) into the specialised Rust
+//! function with one `match` arm per state which works on the byte tape.
+//! The crate [`Program`] parser is used, so the text is the single source
+//! of truth for both: the generated function and the
+//! [`turing_machine_rs::machines::Classic`] machine. The text is emitted too,
+//! as the `NAME_SOURCE` constant (e.g. `INVERT_SOURCE` for the `invert`
+//! function), so it can be parsed at runtime without repeating it.
+//!
+//! The generated function has the signature
+//! `fn(Vec<u8>, usize, usize) -> Result<(Vec<u8>, usize, usize), String>`:
+//! it takes and returns the tape, the index and the state (like
+//! [`turing_machine_rs::state::Configuration::destruct`]) and returns
+//! the same result and the same errors as the
+//! [`turing_machine_rs::TuringMachine::execute`] method
+//! of the [`turing_machine_rs::machines::Classic`] machine.
+//!
+//! # Examples
+//! ```rust
+//! turing_machine_macros::compile!(pub fn invert, '_', r"
+//!     alphabet: _ 0 1
+//!     1, 0 -> 1, 1, >
+//!     1, 1 -> 1, 0, >
+//!     1, _ -> 0, _, -
+//! ");
+//!
+//! fn main() -> Result<(), String> {
+//!     let (tape, index, state) = invert(b"0110".to_vec(), 0, 1)?;
+//!     assert_eq!(tape, b"1001_");
+//!     assert_eq!((index, state), (4, 0));
+//!     assert!(INVERT_SOURCE.contains("alphabet: _ 0 1"));
+//!     Ok(())
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitChar, LitStr, Token, Visibility};
+
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::program::Program;
+
+/// Input of the [`compile!`] macro: `vis fn name, 'blank', "program"`.
+struct Input {
+    vis: Visibility,
+    name: Ident,
+    blank: LitChar,
+    text: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        input.parse::<Token![fn]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let blank = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let text = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Input {
+            vis,
+            name,
+            blank,
+            text,
+        })
+    }
+}
+
+/// Compiles the program text into the function which executes the program
+/// on the byte tape like [`turing_machine_rs::TuringMachine::execute`].
+/// The blank symbol is used for the tape growth (like the default symbol
+/// of [`turing_machine_rs::machines::Classic`]).
+///
+/// The syntax is `compile!(vis fn name, 'blank', "program text")`.
+/// The program text is also emitted as the `NAME_SOURCE` constant with
+/// the same visibility. All symbols of the alphabet must be ASCII characters (bytes).
+///
+/// Invalid programs are reported as compile errors.
+#[proc_macro]
+pub fn compile(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &Input) -> syn::Result<TokenStream2> {
+    let error = |msg: String| syn::Error::new(input.text.span(), msg);
+    let program: Program<char> = input.text.value().parse().map_err(error)?;
+    let blank = input.blank.value();
+    if !program.alphabet().contains(&blank) {
+        return Err(syn::Error::new(
+            input.blank.span(),
+            format!(
+                "new error: default symbol {} is not in alphabet {:?}",
+                blank,
+                program.alphabet()
+            ),
+        ));
+    }
+    if let Some(symbol) = program.alphabet().iter().find(|symbol| !symbol.is_ascii()) {
+        return Err(error(format!(
+            "compile error: symbol {:?} is not an ASCII character",
+            symbol
+        )));
+    }
+
+    // Instructions are grouped by head states in order of states
+    let mut states: Vec<State> = program
+        .instructions()
+        .iter()
+        .map(|inst| inst.head.state)
+        .collect();
+    states.sort();
+    states.dedup();
+    let arms = states.iter().map(|&state| {
+        let symbols = program
+            .instructions()
+            .iter()
+            .filter(|inst| inst.head.state == state)
+            .map(|inst| {
+                let head = byte(inst.head.symbol);
+                let next = Literal::usize_unsuffixed(inst.tail.state.0);
+                let symbol = byte(inst.tail.symbol);
+                let movement = match inst.tail.movement {
+                    Move::Left => quote!(Left),
+                    Move::None => quote!(None),
+                    Move::Right => quote!(Right),
+                };
+                quote!(#head => (#next, #symbol, Movement::#movement),)
+            });
+        let state = Literal::usize_unsuffixed(state.0);
+        quote! {
+            #state => match symbol {
+                #(#symbols)*
+                _ => return Err(uncovered(state, symbol)),
+            },
+        }
+    });
+
+    let Input {
+        vis, name, text, ..
+    } = input;
+    let source = format_ident!("{}_SOURCE", name.to_string().to_uppercase());
+    let source_doc = format!("The program text of the [`{}`] function.", name);
+    let blank = byte(blank);
+    let l_state = Literal::usize_unsuffixed(program.l_state().0);
+    let doc = format!(
+        "Executes the compiled program ({} instructions, the last state {}) on the byte tape.",
+        program.instructions().len(),
+        program.l_state()
+    );
+    Ok(quote! {
+        #[doc = #source_doc]
+        #vis const #source: &str = #text;
+
+        #[doc = #doc]
+        #[allow(unreachable_patterns, clippy::all)]
+        #vis fn #name(
+            mut tape: ::std::vec::Vec<u8>,
+            mut index: usize,
+            mut state: usize,
+        ) -> ::std::result::Result<(::std::vec::Vec<u8>, usize, usize), ::std::string::String> {
+            enum Movement {
+                Left,
+                None,
+                Right,
+            }
+
+            fn uncovered(state: usize, symbol: u8) -> ::std::string::String {
+                ::std::format!(
+                    "uncovered case: have no tail for head ({}, {}) in program",
+                    state,
+                    symbol as char
+                )
+            }
+
+            if tape.len() <= index {
+                return Err(::std::format!(
+                    "index out of bounds: the len is {} but the index is {}",
+                    tape.len(),
+                    index
+                ));
+            }
+            if #l_state < state {
+                return Err(::std::format!(
+                    "get error: required state {} is large then largest {}",
+                    state,
+                    #l_state
+                ));
+            }
+            loop {
+                let symbol = tape[index];
+                let (next, write, movement) = match state {
+                    0 => return Ok((tape, index, state)),
+                    #(#arms)*
+                    _ => return Err(uncovered(state, symbol)),
+                };
+                state = next;
+                tape[index] = write;
+                match movement {
+                    Movement::Left if index == 0 => tape.insert(0, #blank),
+                    Movement::Left => index -= 1,
+                    Movement::None => {}
+                    Movement::Right => {
+                        index += 1;
+                        if index == tape.len() {
+                            tape.push(#blank);
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Returns the byte literal of the ASCII [`char`].
+fn byte(symbol: char) -> Literal {
+    Literal::u8_suffixed(symbol as u8)
+}
@@ -0,0 +1,120 @@
+use turing_machine_rs::instruction::State;
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::Program;
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::TuringMachine;
+
+turing_machine_macros::compile!(fn invert, '_', r"
+    alphabet: _ 0 1
+    1, 0 -> 1, 1, >
+    1, 1 -> 1, 0, >
+    1, _ -> 0, _, -
+");
+
+turing_machine_macros::compile!(fn increment, '_', r"
+    alphabet: _ 0 1
+    l_state: 2
+    1, 1 -> 1, 0, <
+    1, 0 -> 2, 1, >
+    1, _ -> 2, 1, >
+    2, 0 -> 2, 0, >
+    2, 1 -> 2, 1, >
+    2, _ -> 0, _, <
+");
+
+// Halts on the first `#`, has no instructions for `_`
+turing_machine_macros::compile!(fn uncovered, '_', r"
+    alphabet: _ 0 1 #
+    1, 0 -> 1, 1, >
+    1, 1 -> 1, 0, >
+    1, # -> 0, #, -
+");
+
+type Compiled = fn(Vec<u8>, usize, usize) -> Result<(Vec<u8>, usize, usize), String>;
+
+/// Returns all words over the alphabet with the length from `1` to `len`.
+fn words(alphabet: &[char], len: usize) -> Vec<Vec<char>> {
+    let mut words: Vec<Vec<char>> = vec![Vec::new()];
+    let mut result = Vec::new();
+    for _ in 0..len {
+        words = words
+            .iter()
+            .flat_map(|word| {
+                alphabet.iter().map(move |&symbol| {
+                    let mut word = word.clone();
+                    word.push(symbol);
+                    word
+                })
+            })
+            .collect();
+        result.extend(words.iter().cloned());
+    }
+    result
+}
+
+/// Checks that the compiled function and the classic machine have the same
+/// results (or errors) for all words and all start indexes.
+fn differential(text: &str, compiled: Compiled) {
+    let program: Program<char> = text.parse().unwrap();
+    let machine = Classic::new(program.clone(), '_').unwrap();
+
+    for word in words(program.alphabet(), 5) {
+        for index in 0..word.len() {
+            let conf = Configuration::new(Tape::new(word.clone()), index, State(1)).unwrap();
+            let expected = machine.execute(conf).map(|conf| {
+                let (tape, index, state) = conf.destruct();
+                let tape = tape.as_vec().iter().map(|&symbol| symbol as u8).collect();
+                (tape, index, state.0)
+            });
+            let bytes = word.iter().map(|&symbol| symbol as u8).collect();
+            assert_eq!(compiled(bytes, index, 1), expected, "{:?} {}", word, index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn invert_differential() {
+        differential(INVERT_SOURCE, invert);
+    }
+
+    #[test]
+    fn increment_differential() {
+        differential(INCREMENT_SOURCE, increment);
+    }
+
+    #[test]
+    fn uncovered_differential() {
+        differential(UNCOVERED_SOURCE, uncovered);
+    }
+
+    #[test]
+    fn execute() {
+        let result = increment(b"1011".to_vec(), 3, 1);
+        assert_eq!(result, Ok((b"1100_".to_vec(), 3, 0)));
+
+        let result = invert(b"0".to_vec(), 0, 0);
+        assert_eq!(result, Ok((b"0".to_vec(), 0, 0)));
+    }
+
+    #[test]
+    fn fail_execute() {
+        let error = uncovered(b"01_".to_vec(), 0, 1).unwrap_err();
+        assert_eq!(
+            error,
+            "uncovered case: have no tail for head (1, _) in program"
+        );
+
+        let error = invert(b"01".to_vec(), 2, 1).unwrap_err();
+        assert_eq!(
+            error,
+            "index out of bounds: the len is 2 but the index is 2"
+        );
+
+        let error = invert(b"01".to_vec(), 0, 2).unwrap_err();
+        assert_eq!(error, "get error: required state 2 is large then largest 1");
+    }
+}
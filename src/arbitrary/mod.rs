@@ -0,0 +1,252 @@
+//! Provides [`proptest`] integration: [`Arbitrary`] implementations,
+//! strategies for valid values over the given alphabet and ready-made
+//! [`properties`].
+//!
+//! This module is available only with the `proptest` feature.
+//!
+//! [`Arbitrary`] implementations generate any values (e.g. any [`State`]
+//! up to [`usize::MAX`]) and are useful for invariants of the library
+//! itself. Strategies [`state`], [`instruction`], [`tape`], [`configuration`]
+//! and [`program`] generate values which are valid for the alphabet
+//! and the last state, so they are useful for machines.
+//!
+//! # Examples
+//! ```rust
+//! use proptest::prelude::*;
+//! use turing_machine_rs::arbitrary::{program, properties, tape};
+//! use turing_machine_rs::instruction::State;
+//!
+//! proptest! {
+//!     fn with_preserves_components(
+//!         first in program(vec!['0', '1'], State(3)),
+//!         second in program(vec!['0', '1'], State(2)),
+//!         tape in tape(vec!['0', '1'], 1..8),
+//!     ) {
+//!         properties::with_preserves_components(&first, &second, &'0', &tape, 100)?;
+//!     }
+//! }
+//!
+//! fn main() {
+//!     with_preserves_components();
+//! }
+//! ```
+
+pub mod properties;
+
+use std::ops::{Range, RangeInclusive};
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::option;
+use proptest::sample::select;
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+use crate::instruction::{Head, Instruction, Move, State, Tail};
+use crate::program::Program;
+use crate::state::{Configuration, Tape};
+use crate::Symbol;
+
+/// Maximal tape length of [`Arbitrary`] tapes and configurations.
+const TAPE_LEN: usize = 32;
+
+/// Maximal last state of [`Arbitrary`] programs.
+const L_STATE: usize = 8;
+
+impl Arbitrary for State {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<State>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        any::<usize>().prop_map(State).boxed()
+    }
+}
+
+impl Arbitrary for Move {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Move>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        select(vec![Move::Left, Move::None, Move::Right]).boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Head<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Head<S>>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<State>(), any::<S>())
+            .prop_map(|(state, symbol)| Head::new(state, symbol))
+            .boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Tail<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Tail<S>>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<State>(), any::<S>(), any::<Move>())
+            .prop_map(|(state, symbol, movement)| Tail::new(state, symbol, movement))
+            .boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Instruction<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Instruction<S>>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (any::<Head<S>>(), any::<Tail<S>>())
+            .prop_map(|(head, tail)| Instruction::new(head, tail))
+            .boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Tape<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Tape<S>>;
+
+    /// Generates tapes up to `32` symbols (including empty tapes).
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vec(any::<S>(), 0..TAPE_LEN).prop_map(Tape::new).boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Configuration<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Configuration<S>>;
+
+    /// Generates configurations with non-empty tapes up to `32` symbols,
+    /// any valid index and any [`State`].
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (vec(any::<S>(), 1..TAPE_LEN), any::<usize>(), any::<State>())
+            .prop_map(|(symbols, index, state)| {
+                let index = index % symbols.len();
+                Configuration::new(Tape::new(symbols), index, state)
+                    .expect("index is always in bounds")
+            })
+            .boxed()
+    }
+}
+
+impl<S: Symbol + Arbitrary + 'static> Arbitrary for Program<S> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Program<S>>;
+
+    /// Generates valid programs with the alphabet up to `8` unique symbols
+    /// and the last state up to `8`.
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        (vec(any::<S>(), 1..8), 1..=L_STATE)
+            .prop_flat_map(|(symbols, l_state)| {
+                let mut alphabet = Vec::new();
+                for symbol in symbols {
+                    if !alphabet.contains(&symbol) {
+                        alphabet.push(symbol);
+                    }
+                }
+                program(alphabet, State(l_state))
+            })
+            .boxed()
+    }
+}
+
+/// Returns the strategy of [`State`]s in the range (inclusive).
+pub fn state(range: RangeInclusive<usize>) -> impl Strategy<Value = State> {
+    range.prop_map(State)
+}
+
+/// Returns the strategy of [`Instruction`]s which can be inserted
+/// into the [`Program`] with the alphabet and the last state.
+///
+/// # Panics
+/// Panics when the alphabet is empty or the last state is `0`.
+pub fn instruction<S: Symbol + 'static>(
+    alphabet: Vec<S>,
+    l_state: State,
+) -> impl Strategy<Value = Instruction<S>> {
+    assert!(l_state.0 > 0, "the last state must be greater than 0");
+    (
+        state(1..=l_state.0),
+        select(alphabet.clone()),
+        tail(alphabet, l_state),
+    )
+        .prop_map(|(state, symbol, tail)| Instruction::new(Head::new(state, symbol), tail))
+}
+
+/// Returns the strategy of [`Tape`]s with symbols from the alphabet
+/// and the length in the range.
+///
+/// # Panics
+/// Panics when the alphabet is empty.
+pub fn tape<S: Symbol + 'static>(
+    alphabet: Vec<S>,
+    len: Range<usize>,
+) -> impl Strategy<Value = Tape<S>> {
+    vec(select(alphabet), len).prop_map(Tape::new)
+}
+
+/// Returns the strategy of [`Configuration`]s with symbols from
+/// the alphabet, the tape length in the range (empty tapes are skipped),
+/// any valid index and the state from `1` to the last state.
+///
+/// # Panics
+/// Panics when the alphabet is empty or the last state is `0`.
+pub fn configuration<S: Symbol + 'static>(
+    alphabet: Vec<S>,
+    l_state: State,
+    len: Range<usize>,
+) -> impl Strategy<Value = Configuration<S>> {
+    assert!(l_state.0 > 0, "the last state must be greater than 0");
+    let len = len.start.max(1)..len.end.max(2);
+    (
+        vec(select(alphabet), len),
+        any::<usize>(),
+        state(1..=l_state.0),
+    )
+        .prop_map(|(symbols, index, state)| {
+            let index = index % symbols.len();
+            Configuration::new(Tape::new(symbols), index, state).expect("index is always in bounds")
+        })
+}
+
+/// Returns the strategy of valid [`Program`]s with the alphabet and the last
+/// state. Each [`Head`] has an [`Instruction`] with the probability `0.8`,
+/// instructions are inserted in the order of states and the alphabet.
+///
+/// # Panics
+/// Panics when the alphabet is empty or has duplicated symbols
+/// or the last state is `0`.
+pub fn program<S: Symbol + 'static>(
+    alphabet: Vec<S>,
+    l_state: State,
+) -> impl Strategy<Value = Program<S>> {
+    assert!(l_state.0 > 0, "the last state must be greater than 0");
+    let heads: Vec<Head<S>> = (1..=l_state.0)
+        .flat_map(|state| {
+            alphabet
+                .iter()
+                .map(move |symbol| Head::new(State(state), symbol.clone()))
+        })
+        .collect();
+    let tails = vec(
+        option::weighted(0.8, tail(alphabet.clone(), l_state)),
+        heads.len(),
+    );
+    (Just(heads), tails).prop_map(move |(heads, tails)| {
+        let mut program = Program::new(alphabet.clone(), l_state);
+        for (head, tail) in heads.into_iter().zip(tails) {
+            if let Some(tail) = tail {
+                program
+                    .insert(Instruction::new(head, tail))
+                    .expect("instruction is always valid");
+            }
+        }
+        program
+    })
+}
+
+fn tail<S: Symbol + 'static>(alphabet: Vec<S>, l_state: State) -> impl Strategy<Value = Tail<S>> {
+    (state(0..=l_state.0), select(alphabet), any::<Move>())
+        .prop_map(|(state, symbol, movement)| Tail::new(state, symbol, movement))
+}
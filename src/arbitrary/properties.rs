@@ -0,0 +1,154 @@
+//! Ready-made properties for [`proptest`]. Each property returns
+//! [`TestCaseError`] when it fails, so it can be used with `?` inside
+//! the `proptest!` macro. Inputs which cannot be checked (e.g. when the
+//! machine doesn't halt within the step limit) are accepted.
+
+use proptest::test_runner::TestCaseError;
+use proptest::{prop_assert, prop_assert_eq};
+
+use crate::instruction::State;
+use crate::machines::{Classic, Tracer};
+use crate::program::Program;
+use crate::state::{Configuration, SparseTape, Tape, TapeStorage};
+use crate::trace::Trace;
+use crate::{Symbol, TuringMachine, With};
+
+/// Result of the execution within the step limit: [`None`] when the machine
+/// doesn't halt, the final [`Configuration`] or the error otherwise.
+type Execution<S, T> = Option<Result<Configuration<S, T>, String>>;
+
+/// Executes the [`Configuration`] until the halt or the step limit.
+fn execute<S: Symbol, T: TapeStorage<S>>(
+    machine: &impl TuringMachine<S, T>,
+    mut conf: Configuration<S, T>,
+    max_steps: usize,
+) -> Execution<S, T> {
+    for _ in 0..max_steps {
+        if conf.state == State(0) {
+            return Some(Ok(conf));
+        }
+        conf = match machine.execute_once(conf) {
+            Ok(conf) => conf,
+            Err(msg) => return Some(Err(msg)),
+        };
+    }
+    match conf.state == State(0) {
+        true => Some(Ok(conf)),
+        false => None,
+    }
+}
+
+/// Checks that the machine of `first.with(second)` gives the same result
+/// as the machine of `first` followed by the machine of `second` (when
+/// `first` halts the state is reset to `1`). Both programs must have
+/// the same alphabet which contains the default symbol.
+///
+/// Cases which don't halt within `max_steps` (for each component) are
+/// accepted. The empty tape starts on the single default symbol cell.
+pub fn with_preserves_components<S: Symbol>(
+    first: &Program<S>,
+    second: &Program<S>,
+    default: &S,
+    tape: &Tape<S>,
+    max_steps: usize,
+) -> Result<(), TestCaseError> {
+    let composed = first
+        .with(second)
+        .map_err(|msg| TestCaseError::fail(format!("programs cannot be composed: {}", msg)))?;
+    let composed = Classic::new(composed, default.clone()).map_err(TestCaseError::fail)?;
+    let first = Classic::new(first.clone(), default.clone()).map_err(TestCaseError::fail)?;
+    let second = Classic::new(second.clone(), default.clone()).map_err(TestCaseError::fail)?;
+
+    let conf = Configuration::with_blank(tape.clone(), default.clone(), false);
+    let expected = match execute(&first, conf.clone(), max_steps) {
+        None => return Ok(()),
+        Some(Err(_)) => Err(()),
+        Some(Ok(mut middle)) => {
+            middle.state = State(1);
+            match execute(&second, middle, max_steps) {
+                None => return Ok(()),
+                Some(Err(_)) => Err(()),
+                Some(Ok(last)) => Ok(last),
+            }
+        }
+    };
+    // Composed machine makes the same count of steps (errors have other states)
+    match execute(&composed, conf, max_steps * 2) {
+        Some(Ok(found)) => prop_assert_eq!(Ok(found), expected),
+        Some(Err(msg)) => prop_assert!(expected.is_err(), "unexpected error: {}", msg),
+        None => prop_assert!(false, "composed machine doesn't halt"),
+    }
+    Ok(())
+}
+
+/// Checks that the [`Program`] text (see [`Program::to_source`]) is parsed
+/// back to the same [`Program`].
+pub fn source_round_trip(program: &Program<char>) -> Result<(), TestCaseError> {
+    let source = program.to_source();
+    let parsed: Program<char> = source.parse().map_err(TestCaseError::fail)?;
+    prop_assert_eq!(&parsed, program, "source:\n{}", source);
+    Ok(())
+}
+
+/// Checks that the [`Trace`] recorded by the [`Tracer`] (within `max_steps`)
+/// is imported back from JSON Lines and verified by the [`Program`].
+/// The default symbol must be in the [`Program`] alphabet.
+pub fn trace_round_trip(
+    program: &Program<char>,
+    default: char,
+    tape: &Tape<char>,
+    max_steps: usize,
+) -> Result<(), TestCaseError> {
+    let machine = Classic::new(program.clone(), default).map_err(TestCaseError::fail)?;
+    let tracer = Tracer::new(machine);
+    let conf = Configuration::with_blank(tape.clone(), default, false);
+    let _ = execute(&tracer, conf, max_steps);
+
+    if let Some(trace) = tracer.take_trace() {
+        let jsonl = trace.to_jsonl();
+        let imported: Trace<char> = Trace::from_jsonl(&jsonl).map_err(TestCaseError::fail)?;
+        prop_assert_eq!(&imported, &trace, "jsonl:\n{}", jsonl);
+        imported.verify(program).map_err(TestCaseError::fail)?;
+    }
+    Ok(())
+}
+
+/// Checks that the [`Classic`] machine gives the same result on the [`Tape`]
+/// and on the [`SparseTape`] (symbols, index and state). The default symbol
+/// must be in the [`Program`] alphabet.
+pub fn storages_agree<S: Symbol>(
+    program: &Program<S>,
+    default: &S,
+    tape: &Tape<S>,
+    max_steps: usize,
+) -> Result<(), TestCaseError> {
+    let dense = Classic::new(program.clone(), default.clone()).map_err(TestCaseError::fail)?;
+    let sparse = Classic::<S, SparseTape<S>>::for_storage(program.clone(), default.clone())
+        .map_err(TestCaseError::fail)?;
+
+    let conf = Configuration::with_blank(tape.clone(), default.clone(), false);
+    let s_tape = SparseTape::from_symbols(default.clone(), tape.as_vec().iter().cloned());
+    let s_conf = Configuration::with_blank(s_tape, default.clone(), false);
+
+    match (
+        execute(&dense, conf, max_steps),
+        execute(&sparse, s_conf, max_steps),
+    ) {
+        (None, None) => {}
+        (Some(Err(_)), Some(Err(_))) => {}
+        (Some(Ok(dense)), Some(Ok(sparse))) => {
+            let d_symbols: Vec<S> = dense.tape().iter().cloned().collect();
+            let s_symbols: Vec<S> = sparse.tape().iter().cloned().collect();
+            prop_assert_eq!(d_symbols, s_symbols);
+            prop_assert_eq!(dense.index(), sparse.index());
+            prop_assert_eq!(dense.state, sparse.state);
+        }
+        (dense, sparse) => prop_assert!(
+            false,
+            "storages disagree: {:?} and {:?}",
+            dense.map(|r| r.map(|c| c.index())),
+            sparse.map(|r| r.map(|c| c.index()))
+        ),
+    }
+    Ok(())
+}
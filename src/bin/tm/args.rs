@@ -0,0 +1,84 @@
+/// Parsed command-line arguments: positional arguments and options
+/// (`--name value`, `--name=value` or boolean `--name`).
+pub struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    /// Parses arguments. Options from `valued` require a value, options
+    /// from `flags` are boolean, all others are rejected.
+    pub fn parse(
+        args: impl IntoIterator<Item = String>,
+        valued: &[&str],
+        flags: &[&str],
+    ) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let option = match arg.strip_prefix("--") {
+                Some(option) => option,
+                None => {
+                    positional.push(arg);
+                    continue;
+                }
+            };
+            let (name, value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (option, None),
+            };
+            if valued.contains(&name) {
+                let value = match value {
+                    Some(value) => value,
+                    None => args
+                        .next()
+                        .ok_or_else(|| format!("option --{} requires a value", name))?,
+                };
+                options.push((name.to_string(), Some(value)));
+            } else if flags.contains(&name) && value.is_none() {
+                options.push((name.to_string(), None));
+            } else {
+                return Err(format!("unknown option --{}", option));
+            }
+        }
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    /// Returns the positional argument by the index.
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(String::as_str)
+    }
+
+    /// Returns the count of positional arguments.
+    pub fn positional_len(&self) -> usize {
+        self.positional.len()
+    }
+
+    /// Returns `true` when the boolean option is set.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(option, _)| option == name)
+    }
+
+    /// Returns the last value of the option.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Returns the parsed value of the option or the default value.
+    pub fn parsed<V: std::str::FromStr>(&self, name: &str, default: V) -> Result<V, String> {
+        match self.value(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid value {:?} for option --{}", value, name)),
+            None => Ok(default),
+        }
+    }
+}
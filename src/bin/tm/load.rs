@@ -0,0 +1,81 @@
+use std::fs;
+use std::io::{self, Read};
+
+use turing_machine_rs::program::Program;
+use turing_machine_rs::state::{Configuration, Tape};
+
+use crate::args::Args;
+
+/// Loads the [`Program`] from the file.
+pub fn program(path: &str) -> Result<Program<char>, String> {
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("cannot read file {}: {}", path, err))?;
+    text.parse().map_err(|msg| format!("{}: {}", path, msg))
+}
+
+/// Returns the blank symbol: the `--blank` option or the first symbol
+/// of the [`Program`] alphabet.
+pub fn blank(program: &Program<char>, args: &Args) -> Result<char, String> {
+    let blank = match args.value("blank") {
+        Some(value) => symbol(value)?,
+        None => match program.alphabet().first() {
+            Some(blank) => *blank,
+            None => return Err(String::from("program alphabet is empty")),
+        },
+    };
+    match program.alphabet().contains(&blank) {
+        true => Ok(blank),
+        false => Err(format!(
+            "blank {:?} is not in alphabet {:?}",
+            blank,
+            program.alphabet()
+        )),
+    }
+}
+
+/// Parses the symbol which can be quoted, e.g. `' '`.
+pub fn symbol(token: &str) -> Result<char, String> {
+    let token = match token.len() > 2 && token.starts_with('\'') && token.ends_with('\'') {
+        true => &token[1..token.len() - 1],
+        false => token,
+    };
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(symbol), None) => Ok(symbol),
+        _ => Err(format!("symbol {:?} must be a single character", token)),
+    }
+}
+
+/// Returns the input tape: the argument or the first line of stdin
+/// when the argument is missing or equals to `-`.
+pub fn input(arg: Option<&str>) -> Result<String, String> {
+    match arg {
+        Some(tape) if tape != "-" => Ok(tape.to_string()),
+        _ => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| format!("cannot read stdin: {}", err))?;
+            Ok(text.lines().next().unwrap_or_default().to_string())
+        }
+    }
+}
+
+/// Constructs the normal (or standard with `--std`) [`Configuration`].
+/// The empty input is the tape with the single blank symbol.
+pub fn configuration(
+    input: &str,
+    program: &Program<char>,
+    blank: char,
+    std: bool,
+) -> Result<Configuration<char>, String> {
+    if let Some(symbol) = input.chars().find(|ch| !program.alphabet().contains(ch)) {
+        return Err(format!(
+            "input symbol {:?} is not in alphabet {:?}",
+            symbol,
+            program.alphabet()
+        ));
+    }
+    let tape = Tape::from(input);
+    Ok(Configuration::with_blank(tape, blank, std))
+}
@@ -0,0 +1,64 @@
+//! `tm` is the command-line runner for program files
+//! (see [`turing_machine_rs::program::Program`] text format).
+
+mod args;
+mod load;
+mod repl;
+mod run;
+mod spec;
+mod step;
+
+use std::env;
+use std::process::exit;
+
+const USAGE: &str = "\
+Usage: tm <COMMAND> [ARGS]
+
+Commands:
+    run     runs the program on the tape
+    step    steps the program on the tape interactively
+    repl    builds and runs the program incrementally
+    test    runs the spec file cases against the program
+    help    prints this message";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("run") => run::run(args),
+        Some("step") => step::step(args),
+        Some("repl") => repl::repl(args),
+        Some("test") => spec::test(args),
+        Some("help") | Some("--help") | Some("-h") => match args.next().as_deref() {
+            Some("run") => {
+                println!("{}", run::USAGE);
+                Ok(true)
+            }
+            Some("step") => {
+                println!("{}", step::USAGE);
+                Ok(true)
+            }
+            Some("repl") => {
+                println!("{}", repl::USAGE);
+                Ok(true)
+            }
+            Some("test") => {
+                println!("{}", spec::USAGE);
+                Ok(true)
+            }
+            _ => {
+                println!("{}", USAGE);
+                Ok(true)
+            }
+        },
+        Some(command) => Err(format!("unknown command {:?}\n\n{}", command, USAGE)),
+        None => Err(String::from(USAGE)),
+    };
+    match result {
+        Ok(true) => {}
+        Ok(false) => exit(1),
+        Err(msg) => {
+            eprintln!("tm: {}", msg);
+            exit(2);
+        }
+    }
+}
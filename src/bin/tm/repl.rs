@@ -0,0 +1,278 @@
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+use turing_machine_rs::instruction::{Head, Instruction, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::{Alphabet, Program};
+use turing_machine_rs::state::{Configuration, Renderer, Style};
+use turing_machine_rs::TuringMachine;
+
+use crate::args::Args;
+use crate::load;
+
+pub const USAGE: &str = "\
+tm repl [PROGRAM]
+
+Builds and runs the program incrementally. Commands are read line by line
+from stdin, errors are printed immediately.";
+
+const COMMANDS: &str = "\
+Commands:
+    alphabet SYMBOL...     set the alphabet (instructions are kept)
+    l_state STATE          set the last state (instructions are kept)
+    [add] INSTRUCTION      add the instruction, e.g. `1, 0 -> 2, 1, >`
+    remove STATE SYMBOL    remove the instruction
+    list                   print the program
+    load FILE              load the program from the file
+    save FILE              save the program to the file
+    tape [SYMBOLS]         set the tape (the blank tape without symbols)
+    blank SYMBOL           set the blank symbol (the first alphabet symbol by default)
+    start nrm|std          set the start configuration
+    step [N]               make N steps (1 by default)
+    run [LIMIT]            run until the halt or the step limit (10000 by default)
+    reset                  return to the start configuration
+    h, help                print this message
+    q, quit                exit";
+
+/// REPL session: the program under construction, the tape settings
+/// and the current configuration (when the machine is started).
+struct Session {
+    program: Program<char>,
+    blank: Option<char>,
+    tape: String,
+    std: bool,
+    conf: Option<(Configuration<char>, usize)>,
+}
+
+impl Session {
+    fn new() -> Self {
+        Session {
+            program: Program::new(Vec::new(), State(1)),
+            blank: None,
+            tape: String::new(),
+            std: false,
+            conf: None,
+        }
+    }
+
+    /// Returns the blank symbol which must be in the alphabet.
+    fn blank(&self) -> Result<char, String> {
+        let blank = match self.blank {
+            Some(blank) => blank,
+            None => match self.program.alphabet().first() {
+                Some(blank) => *blank,
+                None => return Err(String::from("alphabet is empty")),
+            },
+        };
+        match self.program.alphabet().contains(&blank) {
+            true => Ok(blank),
+            false => Err(format!(
+                "blank {:?} is not in alphabet {:?}",
+                blank,
+                self.program.alphabet()
+            )),
+        }
+    }
+
+    /// Rebuilds the program with another alphabet or last state.
+    /// The program is not changed when any instruction cannot be inserted.
+    fn rebuild(&mut self, alphabet: Alphabet<char>, l_state: State) -> Result<String, String> {
+        let mut program = Program::from_alphabet(alphabet, l_state);
+        for inst in self.program.instructions() {
+            program.insert(inst.clone())?;
+        }
+        self.program = program;
+        Ok(format!("program: {}", self.program))
+    }
+
+    fn insert(&mut self, inst: Instruction<char>) -> Result<String, String> {
+        match self.program.insert(inst.clone())? {
+            Some(old) => Ok(format!("replaced: {}", old)),
+            None => Ok(format!("added: {}", inst)),
+        }
+    }
+
+    fn remove(&mut self, rest: &str) -> Result<String, String> {
+        let (state, symbol) = rest
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| String::from("usage: remove STATE SYMBOL"))?;
+        let state = state
+            .parse()
+            .map_err(|_| format!("cannot parse state {:?}", state))?;
+        let head = Head::new(State(state), load::symbol(symbol.trim())?);
+        match self.program.remove(&head)? {
+            Some(inst) => Ok(format!("removed: {}", inst)),
+            None => Err(format!("instruction for head ({}) is not in program", head)),
+        }
+    }
+
+    fn list(&self) -> String {
+        let mut list = self.program.to_source();
+        list.pop();
+        list
+    }
+
+    /// Returns the current configuration and the steps count, the machine
+    /// is started from the tape when it's not started yet.
+    fn start(&mut self) -> Result<(Configuration<char>, usize), String> {
+        match self.conf.take() {
+            Some(conf) => Ok(conf),
+            None => {
+                let conf = load::configuration(&self.tape, &self.program, self.blank()?, self.std)?;
+                Ok((conf, 0))
+            }
+        }
+    }
+
+    /// Executes steps by the [`Classic`] machine built from the current
+    /// program, so the program can be changed between steps.
+    fn execute(&mut self, limit: usize, trace: bool) -> Result<String, String> {
+        let blank = self.blank()?;
+        let machine = Classic::new(self.program.clone(), blank)?;
+        let (mut conf, mut steps) = self.start()?;
+        let mut renderer = Renderer::new(Style::Textbook);
+        renderer.set_blank(blank);
+
+        let mut output = Vec::new();
+        let mut reason = None;
+        for _ in 0..limit {
+            if conf.state == State(0) {
+                break;
+            }
+            match machine.execute_once(conf.clone()) {
+                Ok(next) => conf = next,
+                Err(msg) => {
+                    reason = Some(msg);
+                    break;
+                }
+            }
+            steps += 1;
+            if trace {
+                output.push(format!("{} {}", steps, renderer.render(&conf)));
+            }
+        }
+        if !trace {
+            output.push(format!("tape: {}", conf.tape()));
+            output.push(format!("steps: {}", steps));
+        }
+        match reason {
+            Some(msg) => output.push(format!("error: {}", msg)),
+            None if conf.state == State(0) => output.push(String::from("halted")),
+            None if !trace => output.push(format!("step limit {} reached", limit)),
+            None => {}
+        }
+        self.conf = Some((conf, steps));
+        Ok(output.join("\n"))
+    }
+
+    /// Executes the command. Returns [`None`] when the session is over,
+    /// otherwise the command output or the error.
+    fn command(&mut self, line: &str) -> Option<Result<String, String>> {
+        let line = line.trim();
+        let (command, rest) = match line.split_once(char::is_whitespace) {
+            Some((command, rest)) => (command, rest.trim()),
+            None => (line, ""),
+        };
+        let result = match command {
+            "" => Ok(String::new()),
+            "alphabet" => format!("alphabet: {}", rest)
+                .parse::<Program<char>>()
+                .and_then(|parsed| {
+                    let l_state = self.program.l_state();
+                    self.rebuild(parsed.symbols().clone(), l_state)
+                }),
+            "l_state" => rest
+                .parse()
+                .map_err(|_| format!("cannot parse state {:?}", rest))
+                .and_then(|l_state| {
+                    let alphabet = self.program.symbols().clone();
+                    self.rebuild(alphabet, State(l_state))
+                }),
+            "add" => rest.parse().and_then(|inst| self.insert(inst)),
+            "remove" => self.remove(rest),
+            "list" => Ok(self.list()),
+            "load" => load::program(rest).map(|program| {
+                self.program = program;
+                self.conf = None;
+                format!("program: {}", self.program)
+            }),
+            "save" => fs::write(rest, self.program.to_source())
+                .map(|_| format!("saved: {}", rest))
+                .map_err(|err| format!("cannot write file {}: {}", rest, err)),
+            "tape" => {
+                self.tape = rest.to_string();
+                self.conf = None;
+                Ok(format!("tape: {}", self.tape))
+            }
+            "blank" => load::symbol(rest).map(|blank| {
+                self.blank = Some(blank);
+                format!("blank: {}", blank)
+            }),
+            "start" => match rest {
+                "nrm" | "std" => {
+                    self.std = rest == "std";
+                    self.conf = None;
+                    Ok(format!("start: {}", rest))
+                }
+                _ => Err(String::from("usage: start nrm|std")),
+            },
+            "step" => match rest {
+                "" => Ok(1),
+                rest => rest
+                    .parse()
+                    .map_err(|_| format!("cannot parse count {:?}", rest)),
+            }
+            .and_then(|count| self.execute(count, true)),
+            "run" => match rest {
+                "" => Ok(10_000),
+                rest => rest
+                    .parse()
+                    .map_err(|_| format!("cannot parse limit {:?}", rest)),
+            }
+            .and_then(|limit| self.execute(limit, false)),
+            "reset" => {
+                self.conf = None;
+                Ok(String::new())
+            }
+            "h" | "help" => Ok(String::from(COMMANDS)),
+            "q" | "quit" => return None,
+            // Instructions can be written without the `add` command
+            _ if line.contains("->") => line.parse().and_then(|inst| self.insert(inst)),
+            other => Err(format!("unknown command {:?} (try help)", other)),
+        };
+        Some(result)
+    }
+}
+
+/// Executes the `repl` subcommand.
+pub fn repl(args: impl IntoIterator<Item = String>) -> Result<bool, String> {
+    let args = Args::parse(args, &[], &[])?;
+    if args.positional_len() > 1 {
+        return Err(format!("usage: {}", USAGE));
+    }
+    let mut session = Session::new();
+    if let Some(path) = args.positional(0) {
+        session.program = load::program(path)?;
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let error = |err: io::Error| format!("cannot write stdout: {}", err);
+    write!(out, "(tm) ")
+        .and_then(|_| out.flush())
+        .map_err(error)?;
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|err| format!("cannot read stdin: {}", err))?;
+        match session.command(&line) {
+            Some(Ok(output)) if output.is_empty() => {}
+            Some(Ok(output)) => writeln!(out, "{}", output).map_err(error)?,
+            Some(Err(msg)) => writeln!(out, "error: {}", msg).map_err(error)?,
+            None => break,
+        }
+        write!(out, "(tm) ")
+            .and_then(|_| out.flush())
+            .map_err(error)?;
+    }
+    writeln!(out).map_err(error)?;
+    Ok(true)
+}
@@ -0,0 +1,73 @@
+use turing_machine_rs::instruction::State;
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::state::{Renderer, Style};
+use turing_machine_rs::TuringMachine;
+
+use crate::args::Args;
+use crate::load;
+
+pub const USAGE: &str = "\
+tm run PROGRAM [TAPE] [OPTIONS]
+
+Runs the program on the tape (or the first line of stdin when TAPE is
+missing or `-`) and prints the final tape, the step count and the halt reason.
+
+Options:
+    --std            start at the last cell (standard configuration)
+    --blank SYMBOL   blank symbol (the first alphabet symbol by default)
+    --limit STEPS    step limit (10000 by default)
+    --trace          print every configuration";
+
+/// Executes the `run` subcommand. Returns `true` when the machine halts.
+pub fn run(args: impl IntoIterator<Item = String>) -> Result<bool, String> {
+    let args = Args::parse(args, &["blank", "limit"], &["std", "trace"])?;
+    if args.positional_len() == 0 || args.positional_len() > 2 {
+        return Err(format!("usage: {}", USAGE));
+    }
+    let program = load::program(args.positional(0).unwrap_or_default())?;
+    let blank = load::blank(&program, &args)?;
+    let limit: usize = args.parsed("limit", 10_000)?;
+    let input = load::input(args.positional(1))?;
+    let mut conf = load::configuration(&input, &program, blank, args.flag("std"))?;
+    let machine = Classic::new(program, blank)?;
+
+    let mut renderer = Renderer::new(Style::Textbook);
+    renderer.set_blank(blank);
+    let width = limit.to_string().len();
+
+    let mut steps = 0;
+    let reason = loop {
+        if args.flag("trace") {
+            println!("{:>w$} {}", steps, renderer.render(&conf), w = width);
+        }
+        if conf.state == State(0) {
+            break Ok(String::from("halted"));
+        }
+        if steps == limit {
+            break Err(format!("step limit {} reached", limit));
+        }
+        match machine.execute_once(conf.clone()) {
+            Ok(next) => conf = next,
+            Err(msg) => break Err(msg),
+        }
+        steps += 1;
+    };
+
+    println!("tape: {}", conf.tape());
+    println!("steps: {}", steps);
+    match reason {
+        Ok(reason) => {
+            println!("halt: {}", reason);
+            Ok(true)
+        }
+        Err(reason) => {
+            println!(
+                "halt: {} (state {}, index {})",
+                reason,
+                conf.state,
+                conf.index()
+            );
+            Ok(false)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::fs;
+
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::spec::Spec;
+
+use crate::args::Args;
+use crate::load;
+
+pub const USAGE: &str = "\
+tm test PROGRAM SPEC [OPTIONS]
+
+Runs all cases of the spec file against the program and prints
+the diff-style report of failures.
+
+Options:
+    --blank SYMBOL   blank symbol (the first alphabet symbol by default)
+    --all            print passed cases too";
+
+/// Executes the `test` subcommand. Returns `true` when all cases are passed.
+pub fn test(args: impl IntoIterator<Item = String>) -> Result<bool, String> {
+    let args = Args::parse(args, &["blank"], &["all"])?;
+    if args.positional_len() != 2 {
+        return Err(format!("usage: {}", USAGE));
+    }
+    let program = load::program(args.positional(0).unwrap_or_default())?;
+    let blank = load::blank(&program, &args)?;
+    let machine = Classic::new(program, blank)?;
+
+    let path = args.positional(1).unwrap_or_default();
+    let text =
+        fs::read_to_string(path).map_err(|err| format!("cannot read file {}: {}", path, err))?;
+    let spec: Spec<char> = text.parse().map_err(|msg| format!("{}: {}", path, msg))?;
+
+    let report = spec.run(&machine);
+    match args.flag("all") {
+        true => println!("{:#}", report),
+        false => println!("{}", report),
+    }
+    Ok(report.is_success())
+}
@@ -0,0 +1,347 @@
+use std::cell::{Cell, RefCell};
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+use turing_machine_rs::instruction::{Head, Instruction, State};
+use turing_machine_rs::machines::{Classic, Debugger};
+use turing_machine_rs::program::Program;
+use turing_machine_rs::state::{Configuration, Renderer, Style, Tape};
+use turing_machine_rs::TuringMachine;
+
+use crate::args::Args;
+use crate::load;
+
+pub const USAGE: &str = "\
+tm step PROGRAM [TAPE] [OPTIONS]
+
+Steps the program on the tape (the blank tape when TAPE is missing)
+interactively. Commands are read line by line from stdin.
+
+Options:
+    --std            start at the last cell (standard configuration)
+    --blank SYMBOL   blank symbol (the first alphabet symbol by default)
+    --limit STEPS    step limit for the continue command (10000 by default)
+    --plain          don't clear the screen and don't use colors";
+
+const COMMANDS: &str = "\
+Commands:
+    s, step [N]              make N steps forward (1 by default, also empty line)
+    b, back [N]              make N steps back (edits are also undone)
+    c, continue              run until a breakpoint, the halt or the step limit
+    break [STATE [SYMBOL]]   add a breakpoint (list breakpoints without arguments)
+    delete [STATE [SYMBOL]]  delete the breakpoint (all breakpoints without arguments)
+    set INDEX SYMBOL         write the symbol on the tape
+    reset                    return to the initial configuration
+    h, help                  print this message
+    q, quit                  exit";
+
+/// Breakpoint: the state and the optional symbol under the head.
+type Breakpoint = (State, Option<char>);
+
+/// Previous configurations with their step numbers.
+type History = Rc<RefCell<Vec<(Configuration<char>, usize)>>>;
+
+/// Interactive stepper state. Steps are made by the [`Debugger`] which
+/// handler records the history, the last fired instruction is taken
+/// from the step event.
+struct Stepper {
+    debugger: Debugger<Classic<char>, char>,
+    program: Program<char>,
+    conf: Configuration<char>,
+    history: History,
+    steps: Rc<Cell<usize>>,
+    last: Option<Instruction<char>>,
+    breakpoints: Vec<Breakpoint>,
+    renderer: Renderer<char>,
+    limit: usize,
+    plain: bool,
+    message: String,
+}
+
+impl Stepper {
+    fn new(machine: Classic<char>, conf: Configuration<char>, limit: usize, plain: bool) -> Self {
+        let program = machine.program().clone();
+        let blank = *machine.default();
+        let mut debugger = Debugger::new(machine);
+
+        let history = Rc::new(RefCell::new(Vec::new()));
+        let steps = Rc::new(Cell::new(0));
+        let c_history = history.clone();
+        let c_steps = steps.clone();
+        debugger.set_c_handler(move |conf: &Configuration<char>| {
+            c_history.borrow_mut().push((conf.clone(), c_steps.get()));
+            c_steps.set(c_steps.get() + 1);
+        });
+
+        let mut renderer = Renderer::new(Style::Caret);
+        renderer.set_blank(blank);
+        renderer.set_colored(!plain);
+        Stepper {
+            debugger,
+            program,
+            conf,
+            history,
+            steps,
+            last: None,
+            breakpoints: Vec::new(),
+            renderer,
+            limit,
+            plain,
+            message: String::new(),
+        }
+    }
+
+    /// Returns the instruction which fires at the next step.
+    fn next(&self) -> Option<&Instruction<char>> {
+        let head = Head::new(self.conf.state, *self.conf.get_symbol());
+        self.program.get(&head).ok().flatten()
+    }
+
+    /// Makes one step. Returns `false` when the machine is halted or fails.
+    fn step(&mut self) -> bool {
+        if self.conf.state == State(0) {
+            self.message = String::from("machine is halted");
+            return false;
+        }
+        match self.debugger.execute_event(self.conf.clone()) {
+            Ok((next, event)) => {
+                self.conf = next;
+                self.last = event.instruction().cloned();
+                true
+            }
+            Err(msg) => {
+                self.message = msg;
+                false
+            }
+        }
+    }
+
+    /// Makes one step back. Returns `false` when the history is empty.
+    fn back(&mut self) -> bool {
+        match self.history.borrow_mut().pop() {
+            Some((conf, steps)) => {
+                self.conf = conf;
+                self.steps.set(steps);
+                self.last = None;
+                true
+            }
+            None => {
+                self.message = String::from("history is empty");
+                false
+            }
+        }
+    }
+
+    fn is_breakpoint(&self) -> bool {
+        let symbol = *self.conf.get_symbol();
+        self.breakpoints.iter().any(|(state, expected)| {
+            *state == self.conf.state && expected.map_or(true, |expected| expected == symbol)
+        })
+    }
+
+    /// Runs until a breakpoint, the halt or the step limit.
+    fn resume(&mut self) {
+        for _ in 0..self.limit {
+            if !self.step() {
+                return;
+            }
+            if self.is_breakpoint() {
+                self.message = format!("breakpoint at state {}", self.conf.state);
+                return;
+            }
+            if self.conf.state == State(0) {
+                self.message = String::from("machine is halted");
+                return;
+            }
+        }
+        self.message = format!("step limit {} reached", self.limit);
+    }
+
+    /// Writes the symbol on the tape. The edit can be undone by the back command.
+    fn set(&mut self, index: usize, symbol: char) -> Result<(), String> {
+        if !self.program.alphabet().contains(&symbol) {
+            return Err(format!(
+                "symbol {:?} is not in alphabet {:?}",
+                symbol,
+                self.program.alphabet()
+            ));
+        }
+        let (mut tape, head, state): (Tape<char>, usize, State) = self.conf.clone().destruct();
+        if index >= tape.len() {
+            return Err(format!(
+                "index {} is out of the tape with len {}",
+                index,
+                tape.len()
+            ));
+        }
+        tape.set(index, symbol);
+        let conf = Configuration::new(tape, head, state)?;
+        let previous = std::mem::replace(&mut self.conf, conf);
+        self.history.borrow_mut().push((previous, self.steps.get()));
+        Ok(())
+    }
+
+    /// Executes the command. Returns `false` when the session is over.
+    fn command(&mut self, line: &str) -> bool {
+        let line = line.trim();
+        let (command, rest) = match line.split_once(char::is_whitespace) {
+            Some((command, rest)) => (command, rest.trim()),
+            None => (line, ""),
+        };
+        let result = match command {
+            "" | "s" | "step" => count(rest).map(|count| {
+                (0..count).all(|_| self.step());
+            }),
+            "b" | "back" => count(rest).map(|count| {
+                (0..count).all(|_| self.back());
+            }),
+            "c" | "continue" => {
+                self.resume();
+                Ok(())
+            }
+            "break" if rest.is_empty() => {
+                let list: Vec<String> = self.breakpoints.iter().map(breakpoint).collect();
+                self.message = format!("breakpoints: {}", list.join(", "));
+                Ok(())
+            }
+            "break" => parse_breakpoint(rest).map(|point| {
+                if !self.breakpoints.contains(&point) {
+                    self.breakpoints.push(point);
+                }
+            }),
+            "delete" if rest.is_empty() => {
+                self.breakpoints.clear();
+                Ok(())
+            }
+            "delete" => parse_breakpoint(rest).and_then(|point| {
+                match self.breakpoints.iter().position(|known| known == &point) {
+                    Some(index) => {
+                        self.breakpoints.remove(index);
+                        Ok(())
+                    }
+                    None => Err(format!("breakpoint {} is not set", breakpoint(&point))),
+                }
+            }),
+            "set" => match rest.split_once(char::is_whitespace) {
+                Some((index, symbol)) => index
+                    .parse()
+                    .map_err(|_| format!("cannot parse index {:?}", index))
+                    .and_then(|index| Ok((index, load::symbol(symbol.trim())?)))
+                    .and_then(|(index, symbol)| self.set(index, symbol)),
+                None => Err(String::from("usage: set INDEX SYMBOL")),
+            },
+            "reset" => {
+                while !self.history.borrow().is_empty() {
+                    self.back();
+                }
+                Ok(())
+            }
+            "h" | "help" => {
+                self.message = String::from(COMMANDS);
+                Ok(())
+            }
+            "q" | "quit" => return false,
+            other => Err(format!("unknown command {:?} (try help)", other)),
+        };
+        if let Err(msg) = result {
+            self.message = msg;
+        }
+        true
+    }
+
+    /// Draws the configuration, the last and the next instructions,
+    /// the program table and the message.
+    fn draw(&mut self, out: &mut impl Write) -> io::Result<()> {
+        if !self.plain {
+            // Clears the screen and moves the cursor to the top left corner
+            write!(out, "\x1b[2J\x1b[H")?;
+        }
+        writeln!(
+            out,
+            "step {} | state {} | index {}",
+            self.steps.get(),
+            self.conf.state,
+            self.conf.index()
+        )?;
+        writeln!(out, "{}", self.renderer.render(&self.conf))?;
+        if let Some(ref last) = self.last {
+            writeln!(out, "last: {}", last)?;
+        }
+        let next = self.next().cloned();
+        match next {
+            Some(ref next) => writeln!(out, "next: {}", next)?,
+            None if self.conf.state == State(0) => writeln!(out, "next: halted")?,
+            None => writeln!(out, "next: uncovered")?,
+        }
+        writeln!(out, "program:")?;
+        for inst in self.program.instructions() {
+            match Some(inst) == next.as_ref() {
+                true if self.plain => writeln!(out, "  > {}", inst)?,
+                true => writeln!(out, "  > \x1b[7m{}\x1b[0m", inst)?,
+                false => writeln!(out, "    {}", inst)?,
+            }
+        }
+        if !self.message.is_empty() {
+            writeln!(out, "{}", self.message)?;
+            self.message.clear();
+        }
+        write!(out, "(tm) ")?;
+        out.flush()
+    }
+}
+
+fn count(rest: &str) -> Result<usize, String> {
+    match rest {
+        "" => Ok(1),
+        rest => rest
+            .parse()
+            .map_err(|_| format!("cannot parse count {:?}", rest)),
+    }
+}
+
+fn parse_breakpoint(rest: &str) -> Result<Breakpoint, String> {
+    let (state, symbol) = match rest.split_once(char::is_whitespace) {
+        Some((state, symbol)) => (state, Some(load::symbol(symbol.trim())?)),
+        None => (rest, None),
+    };
+    let state = state
+        .parse()
+        .map_err(|_| format!("cannot parse state {:?}", state))?;
+    Ok((State(state), symbol))
+}
+
+fn breakpoint((state, symbol): &Breakpoint) -> String {
+    match symbol {
+        Some(symbol) => format!("({}, {})", state, symbol),
+        None => format!("({})", state),
+    }
+}
+
+/// Executes the `step` subcommand.
+pub fn step(args: impl IntoIterator<Item = String>) -> Result<bool, String> {
+    let args = Args::parse(args, &["blank", "limit"], &["std", "plain"])?;
+    if args.positional_len() == 0 || args.positional_len() > 2 {
+        return Err(format!("usage: {}", USAGE));
+    }
+    let program = load::program(args.positional(0).unwrap_or_default())?;
+    let blank = load::blank(&program, &args)?;
+    let limit: usize = args.parsed("limit", 10_000)?;
+    let input = args.positional(1).unwrap_or_default();
+    let conf = load::configuration(input, &program, blank, args.flag("std"))?;
+    let machine = Classic::new(program, blank)?;
+
+    let mut stepper = Stepper::new(machine, conf, limit, args.flag("plain"));
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let error = |err: io::Error| format!("cannot write stdout: {}", err);
+    stepper.draw(&mut out).map_err(error)?;
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|err| format!("cannot read stdin: {}", err))?;
+        if !stepper.command(&line) {
+            break;
+        }
+        stepper.draw(&mut out).map_err(error)?;
+    }
+    writeln!(out).map_err(error)?;
+    Ok(true)
+}
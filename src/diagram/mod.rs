@@ -0,0 +1,284 @@
+//! Provides [`Diagram`] realization which renders space-time diagrams
+//! of Turing machine executions to SVG and PNG.
+//!
+//! A space-time diagram contains one row per execution step and one cell
+//! per tape symbol. Each symbol has its own color, the head cell is marked
+//! by the color of the current state.
+//!
+//! # Examples
+//! ```rust
+//! use turing_machine_rs::diagram::Diagram;
+//! use turing_machine_rs::instruction::{Move, State};
+//! use turing_machine_rs::machines::Classic;
+//! use turing_machine_rs::program::{Extend, Program};
+//! use turing_machine_rs::state::{Configuration, Tape};
+//!
+//! fn main() -> Result<(), String> {
+//!     let mut program = Program::new(vec!['0', '1'], State(2));
+//!     program.extend([
+//!         (1, '0', 2, '1', Move::Left),
+//!         (1, '1', 1, '0', Move::Right),
+//!         (2, '0', 0, '1', Move::None),
+//!     ])?;
+//!     let machine = Classic::new(program, '0')?;
+//!
+//!     let conf = Configuration::new_nrm(Tape::from("1101"))?;
+//!     let mut diagram = Diagram::record(&machine, conf, 100)?;
+//!     diagram.set_cell_size(8);
+//!
+//!     let svg = diagram.to_svg();
+//!     let png = diagram.to_png();
+//!     assert!(svg.starts_with("<svg"));
+//!     assert_eq!(&png[1..4], b"PNG");
+//!     Ok(())
+//! }
+//! ```
+
+mod png;
+mod svg;
+
+use crate::instruction::State;
+use crate::state::{Configuration, TapeStorage};
+use crate::trace::infer_movement;
+use crate::{Symbol, TuringMachine};
+
+/// RGB color which is used by the [`Diagram`].
+pub type Color = [u8; 3];
+
+/// Default symbols colors. Symbols get colors by their first appearance.
+const PALETTE: [Color; 8] = [
+    [255, 255, 255],
+    [32, 32, 32],
+    [66, 133, 244],
+    [219, 68, 55],
+    [244, 180, 0],
+    [15, 157, 88],
+    [171, 71, 188],
+    [0, 172, 193],
+];
+
+/// Row of the [`Diagram`]: the position of the first cell, cells,
+/// the head index and the state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Row<S: Symbol> {
+    left: isize,
+    cells: Vec<S>,
+    index: usize,
+    state: State,
+}
+
+/// [`Diagram`] collects [`Configuration`]s of any [`TuringMachine`] and
+/// renders them as a space-time diagram to SVG ([`Diagram::to_svg`])
+/// or PNG ([`Diagram::to_png`]).
+///
+/// Rows are aligned by tape positions: when the tape grows to the left,
+/// the previous rows are not shifted.
+///
+/// [`Diagram`] can be configured by:
+/// - [`Diagram::set_sampling`] - renders only every n-th row;
+/// - [`Diagram::set_crop`] - renders only the positions range;
+/// - [`Diagram::set_cell_size`] - sets the cell size in pixels;
+/// - [`Diagram::set_color`] - sets the symbol color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagram<S: Symbol> {
+    rows: Vec<Row<S>>,
+    colors: Vec<(S, Color)>,
+    sampling: usize,
+    crop: Option<(isize, isize)>,
+    cell_size: usize,
+}
+
+impl<S: Symbol> Diagram<S> {
+    /// Constructs a new empty [`Diagram`] with the cell size `1` pixel
+    /// and without sampling and cropping.
+    pub fn new() -> Self {
+        Diagram {
+            rows: Vec::new(),
+            colors: Vec::new(),
+            sampling: 1,
+            crop: None,
+            cell_size: 1,
+        }
+    }
+
+    /// Executes the [`Configuration`] by the [`TuringMachine`] step by step
+    /// (by the [`TuringMachine::execute_once`] method) and collects all
+    /// [`Configuration`]s until the machine halts or makes `max_steps` steps.
+    ///
+    /// Returns [`Ok(Diagram)`] or [`Err(String)`] when the machine fails.
+    pub fn record<T: TapeStorage<S>>(
+        machine: &impl TuringMachine<S, T>,
+        mut conf: Configuration<S, T>,
+        max_steps: usize,
+    ) -> Result<Self, String> {
+        let mut diagram = Diagram::new();
+        diagram.push(&conf);
+        for _ in 0..max_steps {
+            if conf.state == State(0) {
+                break;
+            }
+            conf = machine.execute_once(conf)?;
+            diagram.push(&conf);
+        }
+        Ok(diagram)
+    }
+
+    /// Pushes the [`Configuration`] as the next row. Configurations must be
+    /// pushed after every step, otherwise rows cannot be aligned.
+    pub fn push<T: TapeStorage<S>>(&mut self, conf: &Configuration<S, T>) {
+        let left = match self.rows.last() {
+            Some(row) => match infer_movement(row.index, row.cells.len(), conf.index(), conf.len())
+            {
+                (_, true) => row.left - 1,
+                (_, false) => row.left,
+            },
+            None => 0,
+        };
+        let cells: Vec<S> = conf.tape().iter().cloned().collect();
+        for symbol in &cells {
+            if !self.colors.iter().any(|(known, _)| known == symbol) {
+                let color = PALETTE[self.colors.len() % PALETTE.len()];
+                self.colors.push((symbol.clone(), color));
+            }
+        }
+        self.rows.push(Row {
+            left,
+            cells,
+            index: conf.index(),
+            state: conf.state,
+        });
+    }
+
+    /// Returns `true` if the [`Diagram`] has no rows, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the count of collected rows (before sampling).
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Sets the cell size in pixels (at least `1`).
+    ///
+    /// This function is not permanent so size can be changed.
+    pub fn set_cell_size(&mut self, size: usize) {
+        self.cell_size = size.max(1);
+    }
+
+    /// Sets the [`Color`] of the [`Symbol`].
+    ///
+    /// This function is not permanent so color can be changed.
+    pub fn set_color(&mut self, symbol: S, color: Color) {
+        match self.colors.iter_mut().find(|(known, _)| known == &symbol) {
+            Some((_, known)) => *known = color,
+            None => self.colors.push((symbol, color)),
+        }
+    }
+
+    /// Sets the horizontal crop: only positions from `left` to `right`
+    /// (inclusive) are rendered. The position `0` is the first cell
+    /// of the first row. [`Option::None`] means that all cells are rendered.
+    ///
+    /// This function is not permanent so crop can be changed.
+    pub fn set_crop(&mut self, crop: Option<(isize, isize)>) {
+        self.crop = crop;
+    }
+
+    /// Sets the sampling: only every `step`-th row is rendered (the first
+    /// and the last rows are always rendered).
+    ///
+    /// This function is not permanent so sampling can be changed.
+    pub fn set_sampling(&mut self, step: usize) {
+        self.sampling = step.max(1);
+    }
+
+    /// Returns the rendered pixels size `(width, height)`.
+    pub fn size(&self) -> (usize, usize) {
+        let (left, right) = self.columns();
+        let width = (right - left + 1).max(0) as usize;
+        let height = self.sampled().count();
+        (width * self.cell_size, height * self.cell_size)
+    }
+
+    /// Returns the rendered positions range `(left, right)` (inclusive).
+    fn columns(&self) -> (isize, isize) {
+        if let Some(crop) = self.crop {
+            return crop;
+        }
+        let left = self.rows.iter().map(|row| row.left).min().unwrap_or(0);
+        let right = self
+            .rows
+            .iter()
+            .map(|row| row.left + row.cells.len() as isize - 1);
+        (left, right.max().unwrap_or(-1))
+    }
+
+    /// Returns rows according to the sampling.
+    fn sampled(&self) -> impl Iterator<Item = &Row<S>> {
+        let last = self.rows.len().saturating_sub(1);
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(move |(number, _)| number % self.sampling == 0 || *number == last)
+            .map(|(_, row)| row)
+    }
+
+    /// Returns cells colors of rendered rows: [`Option::None`] for positions
+    /// out of the row, the symbol color and the state color for the head.
+    fn grid(&self) -> Vec<Vec<(Option<Color>, Option<Color>)>> {
+        let (left, right) = self.columns();
+        self.sampled()
+            .map(|row| {
+                (left..=right)
+                    .map(|position| {
+                        let index = position - row.left;
+                        let symbol = match index >= 0 {
+                            true => row.cells.get(index as usize),
+                            false => None,
+                        };
+                        let color = symbol.map(|symbol| self.color(symbol));
+                        let head = match index == row.index as isize {
+                            true => Some(state_color(row.state)),
+                            false => None,
+                        };
+                        (color, head)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn color(&self, symbol: &S) -> Color {
+        self.colors
+            .iter()
+            .find(|(known, _)| known == symbol)
+            .map_or(PALETTE[0], |(_, color)| *color)
+    }
+}
+
+impl<S: Symbol> Default for Diagram<S> {
+    fn default() -> Self {
+        Diagram::new()
+    }
+}
+
+/// Returns the state color: the halt state is red, others get hues
+/// spread by the golden angle.
+fn state_color(state: State) -> Color {
+    if state == State(0) {
+        return [255, 0, 0];
+    }
+    let hue = (state.0 as f64 * 137.507_764) % 360.0;
+    let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+    let (r, g, b) = match (hue / 60.0) as u8 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    let channel = |value: f64| (64.0 + value * 191.0) as u8;
+    [channel(r), channel(g), channel(b)]
+}
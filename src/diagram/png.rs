@@ -0,0 +1,119 @@
+use crate::diagram::Diagram;
+use crate::Symbol;
+
+/// Maximal length of the deflate stored block.
+const BLOCK: usize = 65_535;
+
+impl<S: Symbol> Diagram<S> {
+    /// Renders the [`Diagram`] to the RGBA PNG image. Cells out of the tape
+    /// are transparent, the head cell is framed by the state color
+    /// (or filled when the cell size is less than `3` pixels).
+    ///
+    /// The image is not compressed (deflate stored blocks are used),
+    /// so no additional dependencies are needed.
+    ///
+    /// PNG images cannot be empty, so the empty [`Diagram`] (or the inverted
+    /// crop) is rendered to the single transparent pixel.
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height) = self.size();
+        if width == 0 || height == 0 {
+            return encode(1, 1, &[0, 0, 0, 0, 0]);
+        }
+        let size = self.cell_size;
+        let inset = (size / 4).max(1);
+
+        let mut pixels = Vec::with_capacity((width * 4 + 1) * height);
+        for row in self.grid() {
+            for y in 0..size {
+                // Filter type `None` for each scanline
+                pixels.push(0);
+                for (color, head) in &row {
+                    for x in 0..size {
+                        let frame =
+                            x < inset || y < inset || x >= size - inset || y >= size - inset;
+                        let pixel = match (color, head) {
+                            (Some(color), Some(head)) if size >= 3 => match frame {
+                                true => Some(head),
+                                false => Some(color),
+                            },
+                            (_, Some(head)) => Some(head),
+                            (color, None) => color.as_ref(),
+                        };
+                        match pixel {
+                            Some(rgb) => pixels.extend_from_slice(&[rgb[0], rgb[1], rgb[2], 255]),
+                            None => pixels.extend_from_slice(&[0, 0, 0, 0]),
+                        }
+                    }
+                }
+            }
+        }
+
+        encode(width, height, &pixels)
+    }
+}
+
+/// Encodes filtered RGBA scanlines to the PNG image.
+fn encode(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // Bit depth 8, color type 6 (RGBA), default compression, filter and interlace
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib(pixels));
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Writes the PNG chunk with the length and the CRC.
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(kind.iter().chain(data));
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Returns zlib stream of deflate stored (not compressed) blocks.
+fn zlib(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none() as u8;
+        let len = block.len() as u16;
+        stream.push(last);
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,45 @@
+use crate::diagram::{Color, Diagram};
+use crate::Symbol;
+
+impl<S: Symbol> Diagram<S> {
+    /// Renders the [`Diagram`] to the SVG document. Cells out of the tape
+    /// are transparent, the head cell is framed by the state color.
+    pub fn to_svg(&self) -> String {
+        let (width, height) = self.size();
+        let size = self.cell_size;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" shape-rendering=\"crispEdges\">\n",
+            w = width,
+            h = height
+        );
+        for (y, row) in self.grid().iter().enumerate() {
+            for (x, (color, head)) in row.iter().enumerate() {
+                let (x, y) = (x * size, y * size);
+                match (color, head) {
+                    (Some(color), Some(head)) if size >= 3 => {
+                        let inset = (size / 4).max(1);
+                        svg.push_str(&rect(x, y, size, *head));
+                        svg.push_str(&rect(x + inset, y + inset, size - 2 * inset, *color));
+                    }
+                    (_, Some(head)) => svg.push_str(&rect(x, y, size, *head)),
+                    (Some(color), None) => svg.push_str(&rect(x, y, size, *color)),
+                    (None, None) => {}
+                }
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn rect(x: usize, y: usize, size: usize, color: Color) -> String {
+    format!(
+        "<rect x=\"{}\" y=\"{}\" width=\"{s}\" height=\"{s}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+        x,
+        y,
+        color[0],
+        color[1],
+        color[2],
+        s = size
+    )
+}
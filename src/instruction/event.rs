@@ -0,0 +1,105 @@
+use std::fmt::{Display, Error, Formatter};
+
+use crate::instruction::{Head, Instruction, Move, State};
+use crate::Symbol;
+
+/// [`Event`] is what a [`crate::TuringMachine`] actually did in a single step
+/// (see [`crate::TuringMachine::execute_event`]): applied the [`Instruction`]
+/// of the program or resolved the head without it.
+///
+/// [`Event`] fields doesn't needs in control or protection so they are public.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event<S: Symbol> {
+    /// The [`Instruction`] of the program is applied. The `movement` is
+    /// the actual movement of the head which differs from the instruction
+    /// movement only when the head stays on the first cell of the one-way
+    /// tape (see [`crate::state::Boundary::Stay`]).
+    Applied {
+        /// The applied [`Instruction`].
+        instruction: Instruction<S>,
+        /// The actual [`Move`] of the head.
+        movement: Move,
+    },
+    /// The [`Head`] has no [`Instruction`] in the program and the machine
+    /// policy (see [`crate::machines::Uncovered`]) sets the [`State`].
+    /// The symbol and the head position are not changed.
+    Uncovered {
+        /// The uncovered [`Head`].
+        head: Head<S>,
+        /// The [`State`] which is set by the policy.
+        state: State,
+    },
+}
+
+impl<S: Symbol> Event<S> {
+    /// Returns the [`Head`] reference: the state and the symbol before the step.
+    ///
+    /// Zero cost method.
+    pub fn head(&self) -> &Head<S> {
+        match self {
+            Event::Applied { instruction, .. } => &instruction.head,
+            Event::Uncovered { head, .. } => head,
+        }
+    }
+
+    /// Returns the [`State`] after the step.
+    ///
+    /// Zero cost method.
+    pub fn state(&self) -> State {
+        match self {
+            Event::Applied { instruction, .. } => instruction.tail.state,
+            Event::Uncovered { state, .. } => *state,
+        }
+    }
+
+    /// Returns the [`Symbol`] reference under the head cell after the step
+    /// (the written symbol).
+    ///
+    /// Zero cost method.
+    pub fn symbol(&self) -> &S {
+        match self {
+            Event::Applied { instruction, .. } => &instruction.tail.symbol,
+            Event::Uncovered { head, .. } => &head.symbol,
+        }
+    }
+
+    /// Returns the actual [`Move`] of the head ([`Move::None`] for
+    /// [`Event::Uncovered`]).
+    ///
+    /// Zero cost method.
+    pub fn movement(&self) -> Move {
+        match self {
+            Event::Applied { movement, .. } => *movement,
+            Event::Uncovered { .. } => Move::None,
+        }
+    }
+
+    /// Returns the applied [`Instruction`] reference or [`Option::None`]
+    /// for [`Event::Uncovered`].
+    ///
+    /// Zero cost method.
+    pub fn instruction(&self) -> Option<&Instruction<S>> {
+        match self {
+            Event::Applied { instruction, .. } => Some(instruction),
+            Event::Uncovered { .. } => None,
+        }
+    }
+}
+
+impl<S: Symbol> Display for Event<S> {
+    /// Writes the applied [`Instruction`] (with the actual movement when
+    /// it differs), e.g. `(1, 0) -> (2, 1, <) [-]`, or the uncovered [`Head`]
+    /// with the next [`State`], e.g. `(1, 0) -> 0`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            Event::Applied {
+                instruction,
+                movement,
+            } if *movement != instruction.tail.movement => {
+                write!(f, "{} [{}]", instruction, movement)
+            }
+            Event::Applied { instruction, .. } => write!(f, "{}", instruction),
+            Event::Uncovered { head, state } => write!(f, "({}) -> {}", head, state),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 //! Provides [`Instruction`] and it's components: [`Head`], [`Move`],
-//! [`State`], [`Tail`].
+//! [`State`], [`Tail`], and the [`Event`] of the machine step.
 //!
 //! This module provides a unit struct named [`Instruction`] for implementing
 //! this type for any type that implements [`Symbol`] trait.
@@ -23,11 +23,13 @@
 //! let inst = Instruction::build(State(1), '0', State(0), '0', Move::Right);
 //! ```
 
+mod event;
 mod head;
 mod movement;
 mod state;
 mod tail;
 
+pub use event::Event;
 pub use head::Head;
 pub use movement::Move;
 pub use state::State;
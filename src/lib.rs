@@ -4,19 +4,24 @@
 //! A library for implementing any Turing machine with minimal limitations
 //! for the Rust programming language. It is:
 //! * **Low-cost**: Turing Machine RS designed to simulate execution.
-//!     That's why it cannot be simple, flexible and zero-cost at the same time.
+//!   That's why it cannot be simple, flexible and zero-cost at the same time.
 //! * **Flexible**: Turing Machine RS works with not the specific types nor even
-//!     copy-only types! Instead, the library supports any struct or object that
-//!     implements `Clone + Debug + Display + Eq + PartialEq` trait.
+//!   copy-only types! Instead, the library supports any struct or object that
+//!   implements `Clone + Debug + Display + Eq + PartialEq` trait.
 //!
 //! For futher details use `cargo doc --open` (or online docs) or proceed
 //! to the repository on [Github](https://github.com/Helltraitor/turing-machine-rs).
 
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 mod core;
+pub mod diagram;
 pub mod instruction;
 pub mod machines;
 pub mod program;
+pub mod spec;
 pub mod state;
+pub mod trace;
 mod turing;
 
 pub use crate::core::Symbol;
@@ -1,16 +1,30 @@
+use std::cell::Cell;
 use std::fmt;
+use std::marker::PhantomData;
 
-use crate::instruction::Head;
+use crate::instruction::{Event, Head, Instruction, Move, State};
+use crate::machines::Uncovered;
 use crate::program::Program;
-use crate::state::Configuration;
+use crate::state::{Boundary, Configuration, Tape, TapeStorage};
 use crate::{Symbol, TuringMachine, With};
 
 /// [`Classic`] is a common [`TuringMachine`] realization that can be used
 /// freely for program execution.
+///
+/// The tape type `T` can be any [`TapeStorage`] realization and is [`Tape`]
+/// by default. Use [`Classic::for_storage`] to create the machine
+/// for another storage.
+///
+/// Heads without instructions are errors by default, see
+/// [`Classic::set_uncovered`] for other policies. The tape is two-way
+/// infinite by default, see [`Classic::set_boundary`] for the one-way tape.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Classic<S: Symbol> {
+pub struct Classic<S: Symbol, T: TapeStorage<S> = Tape<S>> {
+    boundary: Boundary,
     default: S,
     program: Program<S>,
+    storage: PhantomData<T>,
+    uncovered: Uncovered,
 }
 
 impl<S: Symbol> Classic<S> {
@@ -45,8 +59,39 @@ impl<S: Symbol> Classic<S> {
     /// assert!(machine.is_ok());
     /// ```
     pub fn new(program: Program<S>, default: S) -> Result<Self, String> {
+        Classic::for_storage(program, default)
+    }
+}
+
+impl<S: Symbol, T: TapeStorage<S>> Classic<S, T> {
+    /// Constructs a new [`Classic`] Turing machine from the program
+    /// [`Program`] and the default symbol [`Symbol`] which works with
+    /// the tape storage `T` instead of [`Tape`].
+    ///
+    /// Returns [`Ok(Classic)`] when the default symbol is in the program
+    /// alphabet otherwise [`Err(String)`] with diagnostic information.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use turing_machine_rs::instruction::State;
+    /// use turing_machine_rs::machines::Classic;
+    /// use turing_machine_rs::program::Program;
+    /// use turing_machine_rs::state::Tape;
+    ///
+    /// let program = Program::new(vec!['0', '1'], State(1));
+    /// let machine = Classic::<char, Tape<char>>::for_storage(program, '0');
+    ///
+    /// assert!(machine.is_ok());
+    /// ```
+    pub fn for_storage(program: Program<S>, default: S) -> Result<Self, String> {
         match program.alphabet().contains(&default) {
-            true => Ok(Classic { program, default }),
+            true => Ok(Classic {
+                boundary: Boundary::default(),
+                program,
+                default,
+                storage: PhantomData,
+                uncovered: Uncovered::default(),
+            }),
             false => Err(format!(
                 "new error: default symbol {} is not in alphabet {:?}",
                 default,
@@ -54,62 +99,343 @@ impl<S: Symbol> Classic<S> {
             )),
         }
     }
-}
 
-impl<S: Symbol> TuringMachine<S> for Classic<S> {
-    /// Executes [`Configuration`] once by mutation.
+    /// Returns the [`Boundary`] behaviour of the head which moves left
+    /// from the first cell.
     ///
-    /// Returns [`Ok(Configuration)`] when an [`crate::instruction::Instruction`]
-    /// exists for the current [`Configuration`] symbol and state.
-    /// And otherwise returns [`Err(String)`] with diagnostic information.
-    fn execute_once(&self, mut conf: Configuration<S>) -> Result<Configuration<S>, String> {
-        let head = Head::new(conf.state, conf.get_symbol().clone());
+    /// Zero cost method.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Sets the [`Boundary`] behaviour of the head which moves left
+    /// from the first cell (e.g. [`Boundary::Stay`] for the textbook
+    /// semi-infinite tape). Composed machines get the behaviour
+    /// of this machine.
+    ///
+    /// This function is not permanent so the behaviour can be changed.
+    pub fn set_boundary(&mut self, boundary: Boundary) {
+        self.boundary = boundary;
+    }
+
+    /// Returns the default [`Symbol`] reference.
+    ///
+    /// Zero cost method.
+    pub fn default(&self) -> &S {
+        &self.default
+    }
+
+    /// Returns the [`Program`] reference.
+    ///
+    /// Zero cost method.
+    pub fn program(&self) -> &Program<S> {
+        &self.program
+    }
+
+    /// Returns the [`Uncovered`] policy for heads which have no
+    /// [`crate::instruction::Instruction`] in the program.
+    ///
+    /// Zero cost method.
+    pub fn uncovered(&self) -> Uncovered {
+        self.uncovered
+    }
+
+    /// Sets the [`Uncovered`] policy for heads which have no
+    /// [`crate::instruction::Instruction`] in the program.
+    /// Composed machines (e.g. by [`crate::With::with`]) get the policy
+    /// of this machine and apply it to the whole program, so the implicit
+    /// halt stops the composed machine.
+    ///
+    /// This function is not permanent so the policy can be changed.
+    pub fn set_uncovered(&mut self, uncovered: Uncovered) {
+        self.uncovered = uncovered;
+    }
+
+    /// Returns a new [`Classic`] machine which executes this machine (the test)
+    /// and then the `then` machine when the symbol under the head at the halt
+    /// is one of `symbols`, otherwise the `otherwise` machine
+    /// (see [`Program::branch_on_symbol`]).
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when machines have
+    /// different default symbols or programs cannot be composed.
+    pub fn branch_on_symbol(
+        &self,
+        symbols: &[S],
+        then: &Classic<S, T>,
+        otherwise: &Classic<S, T>,
+    ) -> Result<Classic<S, T>, String> {
+        self.defaults("branch", &[then, otherwise])?;
+        let program = self
+            .program
+            .branch_on_symbol(symbols, &then.program, &otherwise.program)?;
+        self.derive(program)
+    }
+
+    /// Returns a new [`Classic`] machine which executes this machine (the test)
+    /// and then the `then` machine when the test reaches the `accept` state,
+    /// otherwise (when the test halts) the `otherwise` machine
+    /// (see [`Program::branch_on_state`]).
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when machines have
+    /// different default symbols or programs cannot be composed.
+    pub fn branch_on_state(
+        &self,
+        accept: State,
+        then: &Classic<S, T>,
+        otherwise: &Classic<S, T>,
+    ) -> Result<Classic<S, T>, String> {
+        self.defaults("branch", &[then, otherwise])?;
+        let program = self
+            .program
+            .branch_on_state(accept, &then.program, &otherwise.program)?;
+        self.derive(program)
+    }
+
+    /// Returns a new [`Classic`] machine which repeats this machine (the body)
+    /// while the symbol under the head is one of `symbols`
+    /// (see [`Program::repeat_while`]).
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when the program
+    /// cannot be composed.
+    pub fn repeat_while(&self, symbols: &[S]) -> Result<Classic<S, T>, String> {
+        let program = self.program.repeat_while(symbols)?;
+        self.derive(program)
+    }
+
+    /// Returns a new [`Classic`] machine like [`crate::With::with`] but for
+    /// different alphabets (see [`Program::with_union`]).
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when machines
+    /// have different default symbols or cannot be concatenated.
+    pub fn with_union(&self, other: &Classic<S, T>) -> Result<Classic<S, T>, String> {
+        self.defaults("with", &[other])?;
+        let program = self.program.with_union(&other.program)?;
+        self.derive(program)
+    }
+
+    /// Returns a new [`Classic`] machine like [`Classic::with_union`] but
+    /// symbols of another machine are replaced by the `mapping` pairs
+    /// `(from, to)` (see [`Program::with_mapping`]). The default symbol
+    /// is not mapped.
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when machines
+    /// have different default symbols or cannot be concatenated.
+    pub fn with_mapping(
+        &self,
+        other: &Classic<S, T>,
+        mapping: &[(S, S)],
+    ) -> Result<Classic<S, T>, String> {
+        self.defaults("with", &[other])?;
+        let program = self.program.with_mapping(&other.program, mapping)?;
+        self.derive(program)
+    }
+
+    /// Returns a new [`Classic`] machine without [`crate::instruction::Move::None`]
+    /// movements (see [`Program::without_stay`]). The new machine is
+    /// equivalent to this one, see [`Classic::equivalent`], for any
+    /// [`Boundary`]: it grows the tape to the right when the head stays
+    /// on the last cell.
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when the program
+    /// cannot be transformed.
+    pub fn without_stay(&self) -> Result<Classic<S, T>, String> {
+        let program = self.program.without_stay()?;
+        self.derive(program)
+    }
+
+    /// Checks that this machine and another machine have the same results
+    /// for each [`Configuration`] of the corpus: both machines halt
+    /// with the same state, index and tape (default symbols which are grown
+    /// at the right end of the tape are ignored) or fail with the same error.
+    ///
+    /// The execution of each machine is limited by `limit` steps, machines
+    /// which don't halt in time have the same `limit` error. The limit
+    /// should be large enough for both machines (e.g. the machine made by
+    /// [`Classic::without_stay`] takes up to twice as many steps).
+    ///
+    /// Returns [`Err(String)`] with the first different [`Configuration`]
+    /// or when machines have different default symbols.
+    pub fn equivalent(
+        &self,
+        other: &Classic<S, T>,
+        corpus: impl IntoIterator<Item = Configuration<S, T>>,
+        limit: usize,
+    ) -> Result<(), String> {
+        self.defaults("equivalence", &[other])?;
+        for conf in corpus {
+            let lhs = self.bounded(conf.clone(), limit);
+            let rhs = other.bounded(conf.clone(), limit);
+            let same = match (&lhs, &rhs) {
+                (Ok(lhs), Ok(rhs)) => {
+                    lhs.state == rhs.state
+                        && lhs.index() == rhs.index()
+                        && self.cells(lhs) == self.cells(rhs)
+                }
+                (Err(lhs), Err(rhs)) => lhs == rhs,
+                _ => false,
+            };
+            if !same {
+                let outcome = |result: &Result<Configuration<S, T>, String>| match result {
+                    Ok(conf) => conf.to_string(),
+                    Err(msg) => msg.clone(),
+                };
+                return Err(format!(
+                    "equivalence error: machines differ on {}: {} and {}",
+                    conf,
+                    outcome(&lhs),
+                    outcome(&rhs)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes [`Configuration`] until it halts but no more than `limit` steps.
+    fn bounded(
+        &self,
+        conf: Configuration<S, T>,
+        limit: usize,
+    ) -> Result<Configuration<S, T>, String> {
+        let steps = Cell::new(0usize);
+        let conf = self.execute_until(conf, |conf| {
+            steps.set(steps.get() + 1);
+            conf.state == State(0) || limit < steps.get()
+        })?;
+        match conf.state {
+            State(0) => Ok(conf),
+            _ => Err(format!("limit: machine doesn't halt in {} steps", limit)),
+        }
+    }
+
+    /// Returns [`Tape`] symbols without default symbols at the right end
+    /// (but with the symbol under the head).
+    fn cells(&self, conf: &Configuration<S, T>) -> Vec<S> {
+        let mut cells: Vec<S> = conf.tape().iter().cloned().collect();
+        while cells.len() > conf.index() + 1 && cells.last() == Some(&self.default) {
+            cells.pop();
+        }
+        cells
+    }
+
+    /// Returns a new [`Classic`] machine for the composed program
+    /// with the default symbol, the boundary and the policy of this machine.
+    fn derive(&self, program: Program<S>) -> Result<Classic<S, T>, String> {
+        let mut machine = Classic::for_storage(program, self.default.clone())?;
+        machine.boundary = self.boundary;
+        machine.uncovered = self.uncovered;
+        Ok(machine)
+    }
+
+    /// Executes the single step by mutation and returns the applied
+    /// [`Instruction`] with the actual [`Move`] or [`Option::None`] when
+    /// the head is resolved by the [`Uncovered`] policy.
+    fn step(
+        &self,
+        conf: &mut Configuration<S, T>,
+    ) -> Result<Option<(&Instruction<S>, Move)>, String> {
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
         let inst = match self.program.get(&head)? {
             Some(inst) => inst,
             None => {
-                return Err(format!(
-                    "uncovered case: have no tail for head ({}) in program",
-                    head
-                ))
+                conf.state = self.uncovered.resolve(&head)?;
+                return Ok(None);
             }
         };
         conf.state = inst.tail.state;
         conf.set_symbol(inst.tail.symbol.clone());
-        conf.shift(inst.tail.movement, self.default.clone());
+        let movement =
+            conf.shift_bounded(inst.tail.movement, self.default.clone(), self.boundary)?;
+        Ok(Some((inst, movement)))
+    }
+
+    /// Checks that other machines have the same default symbol.
+    fn defaults(&self, name: &str, others: &[&Classic<S, T>]) -> Result<(), String> {
+        match others.iter().find(|other| other.default != self.default) {
+            Some(other) => Err(format!(
+                "{} error: classic machines have different default symbols: {} and {}",
+                name, self.default, other.default
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<S: Symbol, T: TapeStorage<S>> TuringMachine<S, T> for Classic<S, T> {
+    /// Returns the default [`Symbol`] reference as the blank symbol.
+    fn blank(&self) -> Option<&S> {
+        Some(&self.default)
+    }
+
+    /// Executes [`Configuration`] by mutation until it halts (reaches
+    /// the state `0`).
+    ///
+    /// Returns [`Err(String)`] like [`TuringMachine::execute_until`] and when
+    /// the [`Uncovered`] policy never halts ([`Uncovered::Stay`]).
+    fn execute(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        while conf.state != State(0) {
+            if self.step(&mut conf)?.is_none() && conf.state != State(0) {
+                // Only the Stay policy leaves uncovered heads unchanged
+                let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+                self.uncovered.resolve_halting(&head)?;
+            }
+        }
         Ok(conf)
     }
 
+    /// Executes [`Configuration`] once by mutation.
+    ///
+    /// Returns [`Ok(Configuration)`] when an [`Instruction`] exists for
+    /// the current [`Configuration`] symbol and state or the [`Uncovered`]
+    /// policy allows the head without it.
+    /// And otherwise (or when the [`Boundary`] doesn't allow the move)
+    /// returns [`Err(String)`] with diagnostic information.
+    fn execute_once(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        self.step(&mut conf)?;
+        Ok(conf)
+    }
+
+    /// Executes [`Configuration`] once by mutation like
+    /// [`Classic::execute_once`] and returns the [`Event`]: the applied
+    /// [`Instruction`] or the head resolved by the [`Uncovered`] policy.
+    fn execute_event(
+        &self,
+        mut conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+        let event = match self.step(&mut conf)? {
+            Some((inst, movement)) => Event::Applied {
+                instruction: inst.clone(),
+                movement,
+            },
+            None => Event::Uncovered {
+                head,
+                state: conf.state,
+            },
+        };
+        Ok((conf, event))
+    }
+
     /// Executes [`Configuration`] until predicate is `false` by mutation.
     ///
     /// Returns [`Ok(Configuration)`] when an [`crate::instruction::Instruction`]
-    /// exists for the current [`Configuration`] symbol and state.
-    /// And otherwise returns [`Err(String)`] with diagnostic information.
+    /// exists for the current [`Configuration`] symbol and state or
+    /// the [`Uncovered`] policy allows the head without it.
+    /// And otherwise (or when the [`Boundary`] doesn't allow the move)
+    /// returns [`Err(String)`] with diagnostic information.
     fn execute_until(
         &self,
-        mut conf: Configuration<S>,
-        until: impl Fn(&Configuration<S>) -> bool,
-    ) -> Result<Configuration<S>, String> {
+        mut conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String> {
         while !until(&conf) {
-            let head = Head::new(conf.state, conf.get_symbol().clone());
-            let inst = match self.program.get(&head)? {
-                Some(inst) => inst,
-                None => {
-                    return Err(format!(
-                        "uncovered case: have no tail for head ({}) in program",
-                        head
-                    ))
-                }
-            };
-            conf.state = inst.tail.state;
-            conf.set_symbol(inst.tail.symbol.clone());
-            conf.shift(inst.tail.movement, self.default.clone());
+            self.step(&mut conf)?;
         }
         Ok(conf)
     }
 }
 
-impl<S: Symbol> With<Classic<S>> for Classic<S> {
-    type Output = Result<Classic<S>, String>;
+impl<S: Symbol, T: TapeStorage<S>> With<Classic<S, T>> for Classic<S, T> {
+    type Output = Result<Classic<S, T>, String>;
 
     /// Makes superposition with two or more [`Classic`] machines by chain.
     /// This method accept only [`Classic`] struct and can be used only for
@@ -118,22 +444,17 @@ impl<S: Symbol> With<Classic<S>> for Classic<S> {
     /// Returns a new [`Ok(Classic)`] when machines can be concatenated
     /// and [`Err(String)`] with diagnostic information when machines
     /// have different alphabets or default symbols.
-    fn with(&self, other: &Classic<S>) -> Self::Output {
-        if self.default != other.default {
-            return Err(format!(
-                "with error: classic machines have different default symbols: {} and {}",
-                self.default, other.default,
-            ));
-        }
+    fn with(&self, other: &Classic<S, T>) -> Self::Output {
+        self.defaults("with", &[other])?;
         // `Program::with` implementation guarantees that program can
         // be concatenated only with the same alphabet
         let program = self.program.with(&other.program)?;
-        Classic::new(program, self.default.clone())
+        self.derive(program)
     }
 }
 
-impl<S: Symbol> With<Classic<S>> for Result<Classic<S>, String> {
-    type Output = Result<Classic<S>, String>;
+impl<S: Symbol, T: TapeStorage<S>> With<Classic<S, T>> for Result<Classic<S, T>, String> {
+    type Output = Result<Classic<S, T>, String>;
 
     /// Makes superposition with two or more [`Classic`] machines by chain.
     /// This method accept only [`Classic`] struct and can be used only for
@@ -144,7 +465,7 @@ impl<S: Symbol> With<Classic<S>> for Result<Classic<S>, String> {
     /// but machines have different alphabets or default symbols.
     ///
     /// And Returns a copy of [`Err(String)`] when `self` is [`Result::Err`].
-    fn with(&self, other: &Classic<S>) -> Self::Output {
+    fn with(&self, other: &Classic<S, T>) -> Self::Output {
         match self {
             Ok(machine) => machine.with(other),
             Err(msg) => Err(msg.clone()),
@@ -152,7 +473,7 @@ impl<S: Symbol> With<Classic<S>> for Result<Classic<S>, String> {
     }
 }
 
-impl<S: Symbol> fmt::Display for Classic<S> {
+impl<S: Symbol, T: TapeStorage<S>> fmt::Display for Classic<S, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         use std::any::type_name;
 
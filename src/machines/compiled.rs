@@ -0,0 +1,405 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use crate::instruction::{Event, Head, Instruction, Move, State, Tail};
+use crate::machines::{Classic, Uncovered};
+use crate::program::{Alphabet, Program};
+use crate::state::{Boundary, Configuration, TapeStorage};
+use crate::{Symbol, TuringMachine};
+
+/// The largest count of transitions in the [`Compiled`] machine table
+/// (a transition takes up to 16 bytes, so the table takes up to 16 MiB).
+const TABLE_LIMIT: usize = 1 << 20;
+
+/// Unsigned integer type which is used by the [`Compiled`] machine
+/// for interned symbols: [`u8`] or [`u16`].
+pub trait SymbolIndex: Copy + Debug + Eq + Into<usize> + TryFrom<usize> {}
+
+impl SymbolIndex for u8 {}
+
+impl SymbolIndex for u16 {}
+
+/// Interned [`crate::instruction::Tail`].
+#[derive(Clone, Copy, Debug)]
+struct Transition<I: SymbolIndex> {
+    state: usize,
+    symbol: I,
+    movement: Move,
+}
+
+/// [`Compiled`] is a [`TuringMachine`] which interns the alphabet into
+/// indexes of the type `I` ([`u16`] by default or [`u8`]) and executes
+/// the flat transition table on the index tape. Symbols are converted back
+/// only when the execution stops, so symbols are not cloned on every step
+/// (which is expensive for symbols like [`Box<char>`] or machines).
+///
+/// The fast path is the [`TuringMachine::execute`] method. The
+/// [`TuringMachine::execute_until`] method executes steps on
+/// the [`Configuration`] symbols (the predicate requires them) but looks
+/// symbols up in the interned tape. Single steps
+/// ([`TuringMachine::execute_once`] and [`TuringMachine::execute_event`])
+/// look the current symbol up in the alphabet (see [`Alphabet::index`]).
+///
+/// Note: this machine is not implementing [`crate::With`].
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, Compiled};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::Tape;
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+///     program.extend([
+///         (1, Box::new('0'), 0, Box::new('0'), Move::None),
+///         (1, Box::new('1'), 1, Box::new('0'), Move::Right),
+///     ])?;
+///     let classic = Classic::new(program, Box::new('0'))?;
+///     let compiled: Compiled<Box<char>> = Compiled::from_classic(&classic)?;
+///
+///     let tape = Tape::new(['1', '1', '1'].map(Box::new));
+///     assert_eq!(compiled.translate_nrm(tape.clone())?, classic.translate_nrm(tape)?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Compiled<S: Symbol, I: SymbolIndex = u16> {
+    alphabet: Alphabet<S>,
+    boundary: Boundary,
+    default: I,
+    l_state: usize,
+    table: Vec<Option<Transition<I>>>,
+    uncovered: Uncovered,
+}
+
+impl<S: Symbol, I: SymbolIndex> Compiled<S, I> {
+    /// Constructs a new [`Compiled`] machine from the [`Program`]
+    /// and the default symbol (like [`Classic::new`]).
+    ///
+    /// Returns [`Err(String)`] when the default symbol is not in the alphabet,
+    /// the alphabet doesn't fit into the index type or the transition table
+    /// is too large (the last state is too large).
+    pub fn new(program: &Program<S>, default: S) -> Result<Self, String> {
+        let alphabet = program.symbols().clone();
+        if let Some(last) = alphabet.len().checked_sub(1) {
+            index::<I, S>(last, &alphabet)?;
+        }
+        let default = match alphabet.index(&default) {
+            Some(default) => index(default, &alphabet)?,
+            None => {
+                return Err(format!(
+                    "compile error: default symbol {} is not in alphabet {:?}",
+                    default, alphabet
+                ))
+            }
+        };
+        let width = alphabet.len();
+        let size = match program.l_state().0.checked_mul(width) {
+            Some(size) if size <= TABLE_LIMIT => size,
+            _ => {
+                return Err(format!(
+                    "compile error: table for {} states and {} symbols is too large",
+                    program.l_state(),
+                    width
+                ))
+            }
+        };
+        let mut table = vec![None; size];
+        for inst in program.instructions() {
+            // Symbols and states are checked by the program
+            let head = alphabet.index(&inst.head.symbol).unwrap_or_default();
+            let tail = alphabet.index(&inst.tail.symbol).unwrap_or_default();
+            table[(inst.head.state.0 - 1) * width + head] = Some(Transition {
+                state: inst.tail.state.0,
+                symbol: index(tail, &alphabet)?,
+                movement: inst.tail.movement,
+            });
+        }
+        Ok(Compiled {
+            alphabet,
+            boundary: Boundary::default(),
+            default,
+            l_state: program.l_state().0,
+            table,
+            uncovered: Uncovered::default(),
+        })
+    }
+
+    /// Constructs a new [`Compiled`] machine from the [`Classic`] machine
+    /// program, default symbol, [`Boundary`] and [`Uncovered`] policy.
+    ///
+    /// Returns [`Err(String)`] when the machine cannot be compiled
+    /// (see [`Compiled::new`]).
+    pub fn from_classic<T: TapeStorage<S>>(machine: &Classic<S, T>) -> Result<Self, String> {
+        let mut compiled = Compiled::new(machine.program(), machine.default().clone())?;
+        compiled.boundary = machine.boundary();
+        compiled.uncovered = machine.uncovered();
+        Ok(compiled)
+    }
+
+    /// Returns the [`Alphabet`] reference which is used for interning.
+    ///
+    /// Zero cost method.
+    pub fn alphabet(&self) -> &Alphabet<S> {
+        &self.alphabet
+    }
+
+    /// Returns the [`Boundary`] behaviour (see [`Classic::boundary`]).
+    ///
+    /// Zero cost method.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// Sets the [`Boundary`] behaviour (see [`Classic::set_boundary`]).
+    ///
+    /// This function is not permanent so the behaviour can be changed.
+    pub fn set_boundary(&mut self, boundary: Boundary) {
+        self.boundary = boundary;
+    }
+
+    /// Returns the [`Uncovered`] policy (see [`Classic::uncovered`]).
+    ///
+    /// Zero cost method.
+    pub fn uncovered(&self) -> Uncovered {
+        self.uncovered
+    }
+
+    /// Sets the [`Uncovered`] policy (see [`Classic::set_uncovered`]).
+    ///
+    /// This function is not permanent so the policy can be changed.
+    pub fn set_uncovered(&mut self, uncovered: Uncovered) {
+        self.uncovered = uncovered;
+    }
+
+    /// Executes the [`Configuration`] on the index tape until it halts
+    /// and converts the result back.
+    fn run<T: TapeStorage<S>>(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<Configuration<S, T>, String> {
+        let (mut tape, mut index, state) = conf.destruct();
+        if self.l_state < state.0 {
+            return Err(format!(
+                "get error: required state {} is large then largest {}",
+                state, self.l_state
+            ));
+        }
+        let origin = self.intern(&tape)?;
+        let mut cells = origin.clone();
+        let mut state = state.0;
+        let mut grown = 0;
+        let width = self.alphabet.len();
+
+        while state != 0 {
+            let symbol = cells[index];
+            let transition = match state.checked_sub(1) {
+                Some(row) => self.table[row * width + symbol.into()],
+                None => None,
+            };
+            match transition {
+                Some(transition) => {
+                    state = transition.state;
+                    cells[index] = transition.symbol;
+                    match transition.movement {
+                        Move::Left if index == 0 => {
+                            if self.boundary.resolve(State(state))? {
+                                cells.push_front(self.default);
+                                grown += 1;
+                            }
+                        }
+                        Move::Left => index -= 1,
+                        Move::None => {}
+                        Move::Right => {
+                            index += 1;
+                            if index == cells.len() {
+                                cells.push_back(self.default);
+                            }
+                        }
+                    }
+                }
+                None => {
+                    let head = Head::new(State(state), self.symbol(symbol).clone());
+                    state = self.uncovered.resolve_halting(&head)?.0;
+                }
+            }
+        }
+
+        // The origin tape is only extended and changed
+        for &symbol in cells.iter().take(grown).rev() {
+            tape.grow_left(self.symbol(symbol).clone());
+        }
+        for &symbol in cells.iter().skip(grown + origin.len()) {
+            tape.grow_right(self.symbol(symbol).clone());
+        }
+        for (position, symbol) in origin.iter().enumerate() {
+            if cells[grown + position] != *symbol {
+                tape.set(
+                    grown + position,
+                    self.symbol(cells[grown + position]).clone(),
+                );
+            }
+        }
+        Configuration::new(tape, index, State(state))
+    }
+
+    /// Executes the single step on the [`Configuration`] symbols by mutation
+    /// and returns the [`Event`].
+    fn step<T: TapeStorage<S>>(&self, conf: &mut Configuration<S, T>) -> Result<Event<S>, String> {
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+        let symbol = match self.alphabet.index(&head.symbol) {
+            Some(symbol) => symbol,
+            None => {
+                return Err(format!(
+                    "execute error: tape symbol {} is not in alphabet {:?}",
+                    head.symbol, self.alphabet
+                ))
+            }
+        };
+        let event = match self.apply(conf, symbol)? {
+            Some((transition, movement)) => {
+                let written = self.symbol(transition.symbol).clone();
+                let tail = Tail::new(State(transition.state), written, transition.movement);
+                Event::Applied {
+                    instruction: Instruction::new(head, tail),
+                    movement,
+                }
+            }
+            None => Event::Uncovered {
+                head,
+                state: conf.state,
+            },
+        };
+        Ok(event)
+    }
+
+    /// Executes the single step on the [`Configuration`] symbols by mutation
+    /// for the alphabet index of the current symbol. Returns the applied
+    /// [`Transition`] with the actual [`Move`] or [`Option::None`] when
+    /// the head is resolved by the [`Uncovered`] policy.
+    fn apply<T: TapeStorage<S>>(
+        &self,
+        conf: &mut Configuration<S, T>,
+        symbol: usize,
+    ) -> Result<Option<(Transition<I>, Move)>, String> {
+        let transition = match conf.state.0.checked_sub(1) {
+            Some(row) if row < self.l_state => self.table[row * self.alphabet.len() + symbol],
+            Some(_) => {
+                return Err(format!(
+                    "get error: required state {} is large then largest {}",
+                    conf.state, self.l_state
+                ))
+            }
+            None => None,
+        };
+        let transition = match transition {
+            Some(transition) => transition,
+            None => {
+                let head = Head::new(conf.state, self.alphabet.as_vec()[symbol].clone());
+                conf.state = self.uncovered.resolve(&head)?;
+                return Ok(None);
+            }
+        };
+        conf.state = State(transition.state);
+        conf.set_symbol(self.symbol(transition.symbol).clone());
+        let movement = conf.shift_bounded(
+            transition.movement,
+            self.symbol(self.default).clone(),
+            self.boundary,
+        )?;
+        Ok(Some((transition, movement)))
+    }
+
+    /// Converts the [`TapeStorage`] into the index tape.
+    fn intern<T: TapeStorage<S>>(&self, tape: &T) -> Result<VecDeque<I>, String> {
+        tape.iter()
+            .map(|symbol| match self.alphabet.index(symbol) {
+                // The alphabet fits into the index type
+                Some(position) => index(position, &self.alphabet),
+                None => Err(format!(
+                    "execute error: tape symbol {} is not in alphabet {:?}",
+                    symbol, self.alphabet
+                )),
+            })
+            .collect()
+    }
+
+    /// Returns the [`Symbol`] reference by the interned index.
+    fn symbol(&self, index: I) -> &S {
+        &self.alphabet.as_vec()[index.into()]
+    }
+}
+
+impl<S: Symbol, I: SymbolIndex, T: TapeStorage<S>> TuringMachine<S, T> for Compiled<S, I> {
+    /// Returns the default [`Symbol`] reference as the blank symbol.
+    fn blank(&self) -> Option<&S> {
+        Some(self.symbol(self.default))
+    }
+
+    /// Executes the [`Configuration`] on the index tape until it halts
+    /// (reaches the state `0`). This is the fast path of the machine.
+    ///
+    /// Returns [`Err(String)`] when a tape symbol is not in the alphabet
+    /// or an [`crate::instruction::Instruction`] doesn't exist for
+    /// the current symbol and state and the [`Uncovered`] policy
+    /// doesn't allow it or never halts ([`Uncovered::Stay`]).
+    fn execute(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        self.run(conf)
+    }
+
+    /// Executes [`Configuration`] once by mutation.
+    fn execute_once(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        self.step(&mut conf)?;
+        Ok(conf)
+    }
+
+    /// Executes [`Configuration`] once by mutation and returns the [`Event`].
+    fn execute_event(
+        &self,
+        mut conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        let event = self.step(&mut conf)?;
+        Ok((conf, event))
+    }
+
+    /// Executes [`Configuration`] until predicate is `false` by mutation.
+    /// The predicate requires the [`Configuration`] so steps are executed
+    /// on the [`Configuration`] symbols, but the tape is interned once
+    /// (like by the [`Compiled::execute`] method) so the current symbol
+    /// index is known without the alphabet lookup.
+    ///
+    /// Returns [`Err(String)`] when a tape symbol is not in the alphabet.
+    fn execute_until(
+        &self,
+        mut conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String> {
+        let mut cells = self.intern(conf.tape())?;
+        while !until(&conf) {
+            let index = conf.index();
+            if let Some((transition, movement)) = self.apply(&mut conf, cells[index].into())? {
+                cells[index] = transition.symbol;
+                match movement {
+                    Move::Left if index == 0 => cells.push_front(self.default),
+                    Move::Right if conf.index() == cells.len() => cells.push_back(self.default),
+                    _ => {}
+                }
+            }
+        }
+        Ok(conf)
+    }
+}
+
+/// Converts the alphabet position into the index type.
+fn index<I: SymbolIndex, S: Symbol>(position: usize, alphabet: &Alphabet<S>) -> Result<I, String> {
+    I::try_from(position).map_err(|_| {
+        format!(
+            "compile error: alphabet of {} symbols doesn't fit into {}",
+            alphabet.len(),
+            std::any::type_name::<I>()
+        )
+    })
+}
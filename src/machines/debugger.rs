@@ -1,8 +1,10 @@
-use crate::instruction::{Head, Move, Tail};
-use crate::state::Configuration;
+use crate::instruction::{Event, Head, State, Tail};
+use crate::machines::check_halting;
+use crate::state::{Configuration, Tape, TapeStorage};
+use crate::trace::infer_movement;
 use crate::{Symbol, TuringMachine};
 
-type CHandler<S> = Box<dyn Fn(&Configuration<S>)>;
+type CHandler<S, T> = Box<dyn Fn(&Configuration<S, T>)>;
 type IHandler<S> = Box<dyn Fn(&Head<S>, &Tail<S>)>;
 
 /// [`Debugger`] is an super useful [`TuringMachine`] for debugging another
@@ -46,18 +48,18 @@ type IHandler<S> = Box<dyn Fn(&Head<S>, &Tail<S>)>;
 ///     Ok(())
 /// }
 /// ```
-pub struct Debugger<Machine, S: Symbol>
+pub struct Debugger<Machine, S: Symbol, T: TapeStorage<S> = Tape<S>>
 where
-    Machine: TuringMachine<S>,
+    Machine: TuringMachine<S, T>,
 {
     machine: Machine,
-    c_handler: Option<CHandler<S>>,
+    c_handler: Option<CHandler<S, T>>,
     i_handler: Option<IHandler<S>>,
 }
 
-impl<Machine, S: Symbol> Debugger<Machine, S>
+impl<Machine, S: Symbol, T: TapeStorage<S>> Debugger<Machine, S, T>
 where
-    Machine: TuringMachine<S>,
+    Machine: TuringMachine<S, T>,
 {
     /// Constructs a new [`Debugger`] with a [`TuringMachine`] and no handlers.
     ///
@@ -75,7 +77,7 @@ where
     /// [`Fn(&Configuration<Symbol>)`] trait.
     ///
     /// This function is not permanent so handler can be changed.
-    pub fn set_c_handler(&mut self, c_handler: impl Fn(&Configuration<S>) + 'static) {
+    pub fn set_c_handler(&mut self, c_handler: impl Fn(&Configuration<S, T>) + 'static) {
         self.c_handler = Some(Box::new(c_handler));
     }
 
@@ -88,41 +90,67 @@ where
     }
 }
 
-impl<Machine, S: Symbol> TuringMachine<S> for Debugger<Machine, S>
+impl<Machine, S: Symbol, T: TapeStorage<S>> TuringMachine<S, T> for Debugger<Machine, S, T>
 where
-    Machine: TuringMachine<S>,
+    Machine: TuringMachine<S, T>,
 {
+    /// Returns the blank [`Symbol`] reference of the inner machine.
+    fn blank(&self) -> Option<&S> {
+        self.machine.blank()
+    }
+
+    /// Executes [`Configuration`] until it halts (reaches the state `0`).
+    ///
+    /// Uses the [`Debugger::execute_event`] method in the loop. Works quickly
+    /// when no handler set: the inner machine executes [`Configuration`].
+    ///
+    /// Returns [`Err(String)`] like the inner machine and when the inner
+    /// machine keeps the state of the uncovered head (see
+    /// [`crate::machines::Uncovered::Stay`]), so it never halts.
+    fn execute(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        if self.c_handler.is_none() && self.i_handler.is_none() {
+            return self.machine.execute(conf);
+        }
+        while conf.state != State(0) {
+            let (next, event) = self.execute_event(conf)?;
+            check_halting(&event)?;
+            conf = next;
+        }
+        Ok(conf)
+    }
+
     /// Executes [`Configuration`] once by mutation.
     ///
     /// Works quickly when no handler is set (but you probably don't want to
     /// use the debugger without the debugging).
     ///
-    /// # Panics
-    /// [`Debugger`] could panic only if source code is broken - this would be a bug.
-    /// All match cases must and are covered.
-    ///
-    /// So you could open an issue on [GitHub](https://github.com/Helltraitor/turing-machine-rs).
-    fn execute_once(&self, conf: Configuration<S>) -> Result<Configuration<S>, String> {
-        let next = self.machine.execute_once(conf.clone())?;
+    /// The instruction handler receives the [`Tail`] restored by
+    /// the configurations difference: the next state, the symbol under
+    /// the head and the movement.
+    fn execute_once(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        self.execute_event(conf).map(|(conf, _)| conf)
+    }
+
+    /// Executes [`Configuration`] once like [`Debugger::execute_once`]
+    /// and returns the [`Event`] of the inner machine.
+    fn execute_event(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        if self.c_handler.is_none() && self.i_handler.is_none() {
+            return self.machine.execute_event(conf);
+        }
+        let (next, event) = self.machine.execute_event(conf.clone())?;
         if let Some(ref c_handler) = self.c_handler {
             c_handler(&conf);
         }
         if let Some(ref i_handler) = self.i_handler {
             let head = Head::new(conf.state, conf.get_symbol().clone());
-            let movement = match (conf.index(), next.index()) {
-                (old, new) if old < new => Move::Right,
-                (old, new) if old == new => Move::None,
-                (old, new) if old > new => Move::Right,
-                (old, new) => panic!(
-                    "execute_once error: not all compare cases are covered for old {} and new {} indexes",
-                    old,
-                    new
-                )
-            };
+            let (movement, _) = infer_movement(conf.index(), conf.len(), next.index(), next.len());
             let tail = Tail::new(next.state, next.get_symbol().clone(), movement);
             i_handler(&head, &tail);
         }
-        Ok(next)
+        Ok((next, event))
     }
 
     /// Executes [`Configuration`] until predicate is `false` by mutation.
@@ -131,9 +159,9 @@ where
     /// when no handler set (but probably you don't wnat to use the debugger without tools).
     fn execute_until(
         &self,
-        mut conf: Configuration<S>,
-        until: impl Fn(&Configuration<S>) -> bool,
-    ) -> Result<Configuration<S>, String> {
+        mut conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String> {
         if self.c_handler.is_none() && self.i_handler.is_none() {
             return self.machine.execute_until(conf, until);
         }
@@ -0,0 +1,339 @@
+use crate::instruction::{Event, Head, Instruction, Move, State};
+use crate::program::Program;
+use crate::state::{Configuration, Tape};
+use crate::{Symbol, TuringMachine};
+
+/// [`Decision`] is a result of the [`Lba::decide`] method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Decision {
+    /// The machine reaches the accept state after the count of steps.
+    Accept(usize),
+    /// The machine halts (reaches the state `0`) or has no instruction
+    /// for the head after the count of steps.
+    Reject(usize),
+    /// The machine repeats configurations: the cycle starts after `start`
+    /// steps and has the `period` length. The input is not accepted.
+    Loop {
+        /// Count of steps before the first configuration of the cycle.
+        start: usize,
+        /// Count of configurations in the cycle.
+        period: usize,
+    },
+}
+
+/// [`Lba`] is a linear bounded automaton: a [`TuringMachine`] whose tape
+/// is fixed to the input length between the left and the right end markers.
+/// Markers cannot be overwritten or passed (this is checked for the
+/// [`Program`] by [`Lba::new`]) and the input is accepted by the accept
+/// state.
+///
+/// The count of configurations for the input of the length `n` is finite
+/// (`states * (n + 2) * symbols ^ n`), so the acceptance is decidable
+/// by [`Lba::decide`].
+///
+/// [`TuringMachine`] methods work with configurations which are made
+/// by [`Lba::configuration`] (the tape with markers) and stop at the accept
+/// state as well as at the state `0`.
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Decision, Lba};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::Tape;
+///
+/// fn main() -> Result<(), String> {
+///     // Accepts words with the even count of `a`s
+///     let mut program = Program::new(vec!['<', '>', 'a'], State(3));
+///     program.extend([
+///         (1, 'a', 2, 'a', Move::Right),
+///         (1, '>', 3, '>', Move::None),
+///         (2, 'a', 1, 'a', Move::Right),
+///     ])?;
+///     let lba = Lba::new(program, '<', '>', State(3))?;
+///
+///     assert_eq!(lba.decide(Tape::from("aa"))?, Decision::Accept(3));
+///     assert_eq!(lba.decide(Tape::from("aaa"))?, Decision::Reject(3));
+///     assert!(lba.accepts(Tape::from(""))?);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lba<S: Symbol> {
+    accept: State,
+    left: S,
+    program: Program<S>,
+    right: S,
+}
+
+impl<S: Symbol> Lba<S> {
+    /// Constructs a new [`Lba`] from the [`Program`], the left and the right
+    /// end markers and the accept [`State`].
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when markers
+    /// are not in the alphabet or equal, the accept state is not in the
+    /// program states or has instructions, or an instruction overwrites,
+    /// writes or passes the end marker.
+    pub fn new(program: Program<S>, left: S, right: S, accept: State) -> Result<Self, String> {
+        for marker in [&left, &right] {
+            if !program.alphabet().contains(marker) {
+                return Err(format!(
+                    "lba error: end marker {} is not in alphabet {:?}",
+                    marker,
+                    program.alphabet()
+                ));
+            }
+        }
+        if left == right {
+            return Err(format!(
+                "lba error: end markers must be different but both are {}",
+                left
+            ));
+        }
+        if accept == State(0) || program.l_state() < accept {
+            return Err(format!(
+                "lba error: accept state {} is not in program states 1..={}",
+                accept,
+                program.l_state()
+            ));
+        }
+        for inst in program.instructions() {
+            let marker = inst.head.symbol == left || inst.head.symbol == right;
+            if inst.head.state == accept {
+                return Err(format!(
+                    "lba error: accept state {} cannot have instruction {}",
+                    accept, inst
+                ));
+            }
+            if marker && inst.tail.symbol != inst.head.symbol {
+                return Err(format!(
+                    "lba error: instruction {} overwrites the end marker {}",
+                    inst, inst.head.symbol
+                ));
+            }
+            if !marker && (inst.tail.symbol == left || inst.tail.symbol == right) {
+                return Err(format!(
+                    "lba error: instruction {} writes the end marker {}",
+                    inst, inst.tail.symbol
+                ));
+            }
+            if (inst.head.symbol == left && inst.tail.movement == Move::Left)
+                || (inst.head.symbol == right && inst.tail.movement == Move::Right)
+            {
+                return Err(format!(
+                    "lba error: instruction {} passes the end marker {}",
+                    inst, inst.head.symbol
+                ));
+            }
+        }
+        Ok(Lba {
+            accept,
+            left,
+            program,
+            right,
+        })
+    }
+
+    /// Returns the accept [`State`].
+    ///
+    /// Zero cost method.
+    pub fn accept(&self) -> State {
+        self.accept
+    }
+
+    /// Returns the left and the right end markers references.
+    ///
+    /// Zero cost method.
+    pub fn markers(&self) -> (&S, &S) {
+        (&self.left, &self.right)
+    }
+
+    /// Returns the [`Program`] reference.
+    ///
+    /// Zero cost method.
+    pub fn program(&self) -> &Program<S> {
+        &self.program
+    }
+
+    /// Returns the initial [`Configuration`] for the input: the input
+    /// between end markers, the head on the first input symbol (or on
+    /// the right marker for the empty input) and the state `1`.
+    ///
+    /// Returns [`Err(String)`] when the input contains the end marker
+    /// or a symbol which is not in the alphabet.
+    pub fn configuration(&self, input: Tape<S>) -> Result<Configuration<S>, String> {
+        let mut tape = Vec::with_capacity(input.len() + 2);
+        tape.push(self.left.clone());
+        for symbol in input.as_vec() {
+            if *symbol == self.left || *symbol == self.right {
+                return Err(format!(
+                    "lba error: input contains the end marker {}",
+                    symbol
+                ));
+            }
+            if !self.program.alphabet().contains(symbol) {
+                return Err(format!(
+                    "lba error: input symbol {} is not in alphabet {:?}",
+                    symbol,
+                    self.program.alphabet()
+                ));
+            }
+            tape.push(symbol.clone());
+        }
+        tape.push(self.right.clone());
+        Configuration::new(Tape::new(tape), 1, State(1))
+    }
+
+    /// Decides the acceptance of the input by the exhaustive search
+    /// of the configuration space: the run either stops (see [`Decision`])
+    /// or repeats a configuration which is detected by the Brent's cycle
+    /// detection (without storing visited configurations).
+    ///
+    /// Unlike [`TuringMachine`] methods, heads without instructions reject
+    /// the input. Returns [`Err(String)`] when the input is invalid
+    /// (see [`Lba::configuration`]).
+    pub fn decide(&self, input: Tape<S>) -> Result<Decision, String> {
+        let start = self.configuration(input)?;
+
+        // The tortoise waits for the hare at powers of two
+        let mut power = 1;
+        let mut period = 1;
+        let mut steps = 0;
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        loop {
+            hare = match self.next(&hare)? {
+                Some(next) => next,
+                None if hare.state == self.accept => return Ok(Decision::Accept(steps)),
+                None => return Ok(Decision::Reject(steps)),
+            };
+            steps += 1;
+            if tortoise == hare {
+                break;
+            }
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            period += 1;
+        }
+
+        // The hare is ahead of the tortoise by the period
+        let mut tortoise = start.clone();
+        let mut hare = start;
+        for _ in 0..period {
+            hare = self.cycled(&hare)?;
+        }
+        let mut start = 0;
+        while tortoise != hare {
+            tortoise = self.cycled(&tortoise)?;
+            hare = self.cycled(&hare)?;
+            start += 1;
+        }
+        Ok(Decision::Loop { start, period })
+    }
+
+    /// Returns `true` when the input is accepted (see [`Lba::decide`]),
+    /// otherwise `false`.
+    pub fn accepts(&self, input: Tape<S>) -> Result<bool, String> {
+        Ok(matches!(self.decide(input)?, Decision::Accept(_)))
+    }
+
+    /// Returns the next [`Configuration`] or [`Option::None`] when the run
+    /// stops (the accept state, the state `0` or the uncovered head).
+    fn next(&self, conf: &Configuration<S>) -> Result<Option<Configuration<S>>, String> {
+        if conf.state == self.accept || conf.state == State(0) {
+            return Ok(None);
+        }
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+        match self.program.get(&head)? {
+            Some(_) => self.step(conf.clone()).map(|(conf, _)| Some(conf)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the next [`Configuration`] of the cycle.
+    ///
+    /// # Panics
+    /// [`Lba`] could panic only if source code is broken - this would be a bug.
+    /// Configurations of the cycle always have next configurations.
+    fn cycled(&self, conf: &Configuration<S>) -> Result<Configuration<S>, String> {
+        Ok(self
+            .next(conf)?
+            .expect("decide error: configuration of the cycle must have the next configuration"))
+    }
+
+    /// Executes the single step and returns the applied [`Instruction`],
+    /// the head cannot leave the tape.
+    fn step(
+        &self,
+        mut conf: Configuration<S>,
+    ) -> Result<(Configuration<S>, &Instruction<S>), String> {
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+        let inst = match self.program.get(&head)? {
+            Some(inst) => inst,
+            None => {
+                return Err(format!(
+                    "uncovered case: have no tail for head ({}) in program",
+                    head
+                ))
+            }
+        };
+        let leaves = match inst.tail.movement {
+            Move::Left => conf.index() == 0,
+            Move::None => false,
+            Move::Right => conf.index() + 1 == conf.len(),
+        };
+        if leaves {
+            return Err(format!(
+                "lba error: head ({}) leaves the bounded tape of length {}",
+                head,
+                conf.len()
+            ));
+        }
+        conf.state = inst.tail.state;
+        conf.set_symbol(inst.tail.symbol.clone());
+        conf.shift(inst.tail.movement, self.right.clone());
+        Ok((conf, inst))
+    }
+}
+
+impl<S: Symbol> TuringMachine<S> for Lba<S> {
+    /// Executes [`Configuration`] once by mutation.
+    ///
+    /// Returns [`Err(String)`] with diagnostic information when
+    /// an [`crate::instruction::Instruction`] doesn't exist for the current
+    /// [`Configuration`] symbol and state or the head leaves the tape
+    /// (the [`Configuration`] is not made by [`Lba::configuration`]).
+    fn execute_once(&self, conf: Configuration<S>) -> Result<Configuration<S>, String> {
+        Ok(self.step(conf)?.0)
+    }
+
+    /// Executes [`Configuration`] once by mutation and returns the applied
+    /// [`crate::instruction::Instruction`] (see [`Lba::execute_once`]).
+    fn execute_event(
+        &self,
+        conf: Configuration<S>,
+    ) -> Result<(Configuration<S>, Event<S>), String> {
+        let (conf, inst) = self.step(conf)?;
+        let event = Event::Applied {
+            instruction: inst.clone(),
+            movement: inst.tail.movement,
+        };
+        Ok((conf, event))
+    }
+
+    /// Executes [`Configuration`] until predicate is `false` or the machine
+    /// reaches the accept state by mutation.
+    fn execute_until(
+        &self,
+        mut conf: Configuration<S>,
+        until: impl Fn(&Configuration<S>) -> bool,
+    ) -> Result<Configuration<S>, String> {
+        while conf.state != self.accept && !until(&conf) {
+            conf = self.step(conf)?.0;
+        }
+        Ok(conf)
+    }
+}
@@ -0,0 +1,165 @@
+use std::marker::PhantomData;
+
+use crate::instruction::State;
+use crate::machines::Sequence;
+use crate::state::{Configuration, Tape, TapeStorage};
+use crate::{Symbol, TuringMachine};
+
+/// [`Report`] is a result of the single [`MetaMachine`] stage: the index
+/// of the machine in the [`Tape`], the count of executed steps and
+/// the [`Configuration`] when the machine halts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report<S: Symbol, T: TapeStorage<S> = Tape<S>> {
+    /// Index of the machine in the [`MetaMachine`] [`Tape`].
+    pub index: usize,
+    /// Count of steps executed by the machine.
+    pub steps: usize,
+    /// [`Configuration`] when the machine halts.
+    pub configuration: Configuration<S, T>,
+}
+
+/// [`MetaMachine`] is an executor for the [`Tape`] whose symbols are
+/// Turing machines (e.g. the output [`Tape`] of a [`crate::machines::Classic`]
+/// machine of [`crate::machines::Classic`] machines). This allows machines
+/// to transform machines and then execute the result.
+///
+/// Machines are executed in the [`Tape`] order on the same [`Configuration`],
+/// the state is reset (to the state `1` by default, see
+/// [`MetaMachine::set_reset`]) before each next machine. The [`Tape`] can be
+/// produced by a control machine (see [`MetaMachine::from_control`]).
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, MetaMachine};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::{Configuration, Tape};
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '0', 0, '1', Move::Right), (1, '1', 1, '1', Move::Right)])?;
+///     let fill = Classic::new(program, '0')?;
+///
+///     let meta = MetaMachine::new(Tape::new([fill.clone(), fill]));
+///     let reports = meta.run(Configuration::new_nrm(Tape::from("1"))?, 100)?;
+///
+///     assert_eq!(reports.len(), 2);
+///     assert_eq!(reports[0].steps, 2);
+///     assert_eq!(reports[1].configuration.tape(), &Tape::from("1110"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MetaMachine<M, S: Symbol, T: TapeStorage<S> = Tape<S>>
+where
+    M: Symbol + TuringMachine<S, T>,
+{
+    machines: Tape<M>,
+    reset: State,
+    storage: PhantomData<(S, T)>,
+}
+
+impl<M, S: Symbol, T: TapeStorage<S>> MetaMachine<M, S, T>
+where
+    M: Symbol + TuringMachine<S, T>,
+{
+    /// Constructs a new [`MetaMachine`] with the [`Tape`] of machines
+    /// and the reset state `1`.
+    pub fn new(machines: Tape<M>) -> Self {
+        MetaMachine {
+            machines,
+            reset: State(1),
+            storage: PhantomData,
+        }
+    }
+
+    /// Constructs a new [`MetaMachine`] with the [`Tape`] of machines
+    /// translated by the control machine
+    /// (see [`TuringMachine::translate_nrm`]).
+    ///
+    /// Returns [`Err(String)`] when the control machine cannot translate
+    /// the [`Tape`].
+    pub fn from_control(
+        control: &impl TuringMachine<M>,
+        machines: Tape<M>,
+    ) -> Result<Self, String> {
+        Ok(MetaMachine::new(control.translate_nrm(machines)?))
+    }
+
+    /// Returns the [`Tape`] of machines reference.
+    ///
+    /// Zero cost method.
+    pub fn machines(&self) -> &Tape<M> {
+        &self.machines
+    }
+
+    /// Returns the state which is set to the [`Configuration`] before
+    /// the next machine starts.
+    ///
+    /// Zero cost method.
+    pub fn reset(&self) -> State {
+        self.reset
+    }
+
+    /// Sets the state which is set to the [`Configuration`] before
+    /// the next machine starts.
+    ///
+    /// This function is not permanent so the reset state can be changed.
+    pub fn set_reset(&mut self, reset: State) {
+        self.reset = reset;
+    }
+
+    /// Executes machines one after another and returns the [`Report`]
+    /// for each machine. The last [`Report`] contains the result
+    /// [`Configuration`], the empty [`Tape`] produces no [`Report`]s.
+    ///
+    /// Returns [`Err(String)`] with the machine index when a machine fails
+    /// or doesn't halt within `max_steps` steps.
+    pub fn run(
+        &self,
+        mut conf: Configuration<S, T>,
+        max_steps: usize,
+    ) -> Result<Vec<Report<S, T>>, String> {
+        let mut reports = Vec::with_capacity(self.machines.len());
+        for (index, machine) in self.machines.iter().enumerate() {
+            if index > 0 {
+                conf.state = self.reset;
+            }
+            let mut steps = 0;
+            while conf.state != State(0) {
+                if steps == max_steps {
+                    return Err(format!(
+                        "meta error: machine {} exceeds {} steps",
+                        index, max_steps
+                    ));
+                }
+                conf = machine
+                    .execute_once(conf)
+                    .map_err(|error| format!("meta error: machine {} failed: {}", index, error))?;
+                steps += 1;
+            }
+            reports.push(Report {
+                index,
+                steps,
+                configuration: conf.clone(),
+            });
+        }
+        Ok(reports)
+    }
+}
+
+impl<M, S: Symbol, T: TapeStorage<S>> MetaMachine<M, S, T>
+where
+    M: Symbol + TuringMachine<S, T> + 'static,
+{
+    /// Returns the [`Sequence`] of machine copies which implements
+    /// [`TuringMachine`] (so it can be debugged or traced).
+    pub fn to_sequence(&self) -> Sequence<S, T> {
+        let mut sequence = Sequence::new();
+        sequence.set_reset(self.reset);
+        for machine in self.machines.iter() {
+            sequence.push(machine.clone());
+        }
+        sequence
+    }
+}
@@ -1,20 +1,51 @@
-//! Provides two Turing machines: [`Classic`] and [`Debugger`].
+//! Provides Turing machines: [`Classic`], [`Compiled`], [`Debugger`], [`Lba`],
+//! [`Sequence`] and [`Tracer`], and the [`MetaMachine`] executor.
 //! - [`Classic`] is a Turing machine general realization. If you need to only
-//!     execute a program for a configuration, then you could use this machine.
+//!   execute a program for a configuration, then you could use this machine.
+//! - [`Compiled`] is a Turing machine which is compiled from the program into
+//!   the table of symbol indexes. Executes programs without symbol cloning
+//!   (by [`crate::TuringMachine::execute`]).
 //! - [`Debugger`] is another Turing machine that is created by using an existing
-//!     machine. Provides an ability to set [`crate::state::Configuration`] and
-//!     ([`crate::instruction::Head`], [`crate::instruction::Tail`]) handlers
-//!     (e.g. you can print output in the string buffer).
+//!   machine. Provides an ability to set [`crate::state::Configuration`] and
+//!   ([`crate::instruction::Head`], [`crate::instruction::Tail`]) handlers
+//!   (e.g. you can print output in the string buffer).
+//! - [`Lba`] is a linear bounded automaton: the tape is fixed between end
+//!   markers, so the acceptance of the input is decidable.
+//! - [`Tracer`] is another Turing machine that is created by using an existing
+//!   machine. Records every step into the [`crate::trace::Trace`] which can be
+//!   exported and verified.
+//! - [`Sequence`] is another Turing machine that is created by using existing
+//!   machines of any types. Executes them one after another on the same
+//!   [`crate::state::Configuration`].
+//! - [`MetaMachine`] executes the [`crate::state::Tape`] of machines (which
+//!   can be produced by another machine) and reports the result of each one.
 //!
 //! [`crate::TuringMachine`] for debugging [`crate::TuringMachine`] implementations.
 //!
+//! [`Classic`] and [`Compiled`] machines apply the [`Uncovered`] policy
+//! to heads which have no instructions in the program.
+//!
 //! # Warning
 //! [`Debugger`] could panic only if source code is broken - this would be a bug.
 //!
 //! So you could open an issue on [GitHub](https://github.com/Helltraitor/turing-machine-rs).
 
 mod classic;
+mod compiled;
 mod debugger;
+mod lba;
+mod meta;
+mod sequence;
+mod tracer;
+mod uncovered;
 
 pub use classic::Classic;
+pub use compiled::{Compiled, SymbolIndex};
 pub use debugger::Debugger;
+pub use lba::{Decision, Lba};
+pub use meta::{MetaMachine, Report};
+pub use sequence::Sequence;
+pub use tracer::Tracer;
+pub use uncovered::Uncovered;
+
+pub(crate) use uncovered::check_halting;
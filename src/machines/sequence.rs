@@ -0,0 +1,281 @@
+use crate::instruction::{Event, Head, State};
+use crate::state::{Configuration, Tape, TapeStorage};
+use crate::{Symbol, TuringMachine};
+
+/// Object safe part of the [`TuringMachine`] which allows to store
+/// different machines in the [`Sequence`].
+trait Stage<S: Symbol, T: TapeStorage<S>> {
+    fn blank(&self) -> Option<&S>;
+
+    fn execute(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String>;
+
+    fn execute_event(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String>;
+}
+
+impl<Machine, S: Symbol, T: TapeStorage<S>> Stage<S, T> for Machine
+where
+    Machine: TuringMachine<S, T>,
+{
+    fn blank(&self) -> Option<&S> {
+        TuringMachine::blank(self)
+    }
+
+    fn execute(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        TuringMachine::execute(self, conf)
+    }
+
+    fn execute_event(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        TuringMachine::execute_event(self, conf)
+    }
+}
+
+/// [`Sequence`] is a [`TuringMachine`] which executes other machines one
+/// after another on the same [`Configuration`]. Machines can be of different
+/// types (e.g. [`crate::machines::Classic`] machines with different alphabets
+/// or [`crate::machines::Tracer`]s), they must only use the same [`Symbol`]
+/// and [`TapeStorage`] types.
+///
+/// When a machine halts (reaches the state `0`) and it's not the last machine,
+/// the [`Configuration`] state is reset (to the state `1` by default, see
+/// [`Sequence::set_reset`]) and the next machine starts. The [`Sequence`]
+/// halts when the last machine halts, the empty [`Sequence`] halts
+/// immediately.
+///
+/// The [`Sequence`] holds no execution state: the current machine (the stage)
+/// is encoded in the [`Configuration`] state. The state `q` of the stage `k`
+/// of `n` machines is `(q - 1) * n + k + 1` and the state `0` is the halt,
+/// so the initial state `1` is the state `1` of the first machine and
+/// the single machine [`Sequence`] has the machine states (see
+/// [`Sequence::stage`] and [`Sequence::state`]).
+///
+/// Note: this machine is not implementing [`crate::With`].
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, Sequence, Tracer};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::Tape;
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '0', 0, '1', Move::Right), (1, '1', 1, '1', Move::Right)])?;
+///     let fill = Classic::new(program, '0')?;
+///
+///     let mut program = Program::new(vec!['0', '1', '2'], State(1));
+///     program.extend([(1, '0', 0, '2', Move::None), (1, '1', 0, '2', Move::None)])?;
+///     let mark = Classic::new(program, '0')?;
+///
+///     let mut sequence = Sequence::new();
+///     sequence.push(fill);
+///     sequence.push(Tracer::new(mark));
+///
+///     let result = sequence.translate_nrm(Tape::from("10"))?;
+///     assert_eq!(Tape::from("112"), result);
+///     Ok(())
+/// }
+/// ```
+pub struct Sequence<S: Symbol, T: TapeStorage<S> = Tape<S>> {
+    machines: Vec<Box<dyn Stage<S, T>>>,
+    reset: State,
+}
+
+impl<S: Symbol, T: TapeStorage<S>> Sequence<S, T> {
+    /// Constructs a new empty [`Sequence`] with the reset state `1`.
+    pub fn new() -> Self {
+        Sequence {
+            machines: Vec::new(),
+            reset: State(1),
+        }
+    }
+
+    /// Appends a [`TuringMachine`] to the end of the [`Sequence`].
+    pub fn push(&mut self, machine: impl TuringMachine<S, T> + 'static) {
+        self.machines.push(Box::new(machine));
+    }
+
+    /// Returns the state which is set to the [`Configuration`] before
+    /// the next machine starts.
+    ///
+    /// Zero cost method.
+    pub fn reset(&self) -> State {
+        self.reset
+    }
+
+    /// Sets the state which is set to the [`Configuration`] before
+    /// the next machine starts.
+    ///
+    /// This function is not permanent so the reset state can be changed.
+    pub fn set_reset(&mut self, reset: State) {
+        self.reset = reset;
+    }
+
+    /// Returns `true` if the [`Sequence`] has no machines, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    /// Returns the count of machines in the [`Sequence`].
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    /// Returns the machine index and its [`State`] for the [`Sequence`]
+    /// state or [`Option::None`] when the state is `0` (the halt) or
+    /// the [`Sequence`] is empty.
+    pub fn stage(&self, state: State) -> Option<(usize, State)> {
+        match (state.0.checked_sub(1), self.machines.len()) {
+            (Some(q), n) if n > 0 => Some((q % n, State(q / n + 1))),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Sequence`] state for the machine index and its [`State`]
+    /// (the state `0` is the halt for any machine).
+    ///
+    /// Returns an [`Err`] when the machine index is out of the [`Sequence`]
+    /// or the state is too large.
+    pub fn state(&self, stage: usize, state: State) -> Result<State, String> {
+        let n = self.machines.len();
+        if stage >= n {
+            return Err(format!(
+                "sequence error: machine {} is out of {} machines",
+                stage, n
+            ));
+        }
+        match state.0.checked_sub(1) {
+            Some(q) => q
+                .checked_mul(n)
+                .and_then(|q| q.checked_add(stage + 1))
+                .map(State)
+                .ok_or_else(|| {
+                    format!(
+                        "sequence error: state {} of machine {} is too large",
+                        state, stage
+                    )
+                }),
+            None => Ok(State(0)),
+        }
+    }
+
+    /// Starts the next machines while the current one halts.
+    fn advance(&self, mut stage: usize, mut state: State) -> (usize, State) {
+        while state == State(0) && stage + 1 < self.machines.len() {
+            stage += 1;
+            state = self.reset;
+        }
+        (stage, state)
+    }
+}
+
+impl<S: Symbol, T: TapeStorage<S>> Default for Sequence<S, T> {
+    fn default() -> Self {
+        Sequence::new()
+    }
+}
+
+impl<S: Symbol, T: TapeStorage<S>> TuringMachine<S, T> for Sequence<S, T> {
+    /// Returns the blank [`Symbol`] reference of the first machine.
+    fn blank(&self) -> Option<&S> {
+        self.machines.first().and_then(|machine| machine.blank())
+    }
+
+    /// Executes [`Configuration`] by machines of the [`Sequence`] starting
+    /// from the machine of the [`Configuration`] state until the last machine
+    /// halts. Uses [`TuringMachine::execute`] of each machine.
+    fn execute(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        let (mut stage, mut state) = match self.stage(conf.state) {
+            Some(stage) => stage,
+            None => {
+                conf.state = State(0);
+                return Ok(conf);
+            }
+        };
+        loop {
+            conf.state = state;
+            conf = self.machines[stage].execute(conf)?;
+            let (next, reset) = self.advance(stage, conf.state);
+            if next == stage {
+                conf.state = self.state(stage, reset)?;
+                return Ok(conf);
+            }
+            stage = next;
+            state = reset;
+        }
+    }
+
+    /// Executes [`Configuration`] once (see [`Sequence::execute_event`]).
+    fn execute_once(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        TuringMachine::execute_event(self, conf).map(|(conf, _)| conf)
+    }
+
+    /// Executes [`Configuration`] once by the machine of the [`Configuration`]
+    /// state and starts the next machine when the current one halts.
+    /// [`Event`] states are the [`Sequence`] states. The empty [`Sequence`]
+    /// and the halted [`Configuration`] have no instructions so they halt
+    /// by [`Event::Uncovered`].
+    fn execute_event(
+        &self,
+        mut conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        let global = conf.state;
+        let (stage, state) = match self.stage(global) {
+            Some(stage) => stage,
+            None => {
+                let head = Head::new(global, conf.try_get_symbol()?.clone());
+                conf.state = State(0);
+                let event = Event::Uncovered {
+                    head,
+                    state: conf.state,
+                };
+                return Ok((conf, event));
+            }
+        };
+        conf.state = state;
+        let (mut conf, event) = self.machines[stage].execute_event(conf)?;
+        let (stage, state) = self.advance(stage, conf.state);
+        conf.state = self.state(stage, state)?;
+        let event = match event {
+            Event::Applied {
+                mut instruction,
+                movement,
+            } => {
+                instruction.head.state = global;
+                instruction.tail.state = conf.state;
+                Event::Applied {
+                    instruction,
+                    movement,
+                }
+            }
+            Event::Uncovered { mut head, .. } => {
+                head.state = global;
+                Event::Uncovered {
+                    head,
+                    state: conf.state,
+                }
+            }
+        };
+        Ok((conf, event))
+    }
+
+    /// Executes [`Configuration`] until predicate is `false` or the last
+    /// machine halts. The predicate gets [`Sequence`] states and intermediate
+    /// halts are not passed to it: the next machine starts in the same step.
+    fn execute_until(
+        &self,
+        mut conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String> {
+        while !until(&conf) && conf.state != State(0) {
+            conf = TuringMachine::execute_event(self, conf)?.0;
+        }
+        Ok(conf)
+    }
+}
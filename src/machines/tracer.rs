@@ -0,0 +1,133 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use crate::instruction::{Event, State};
+use crate::machines::check_halting;
+use crate::state::{Configuration, Tape, TapeStorage};
+use crate::trace::Trace;
+use crate::{Symbol, TuringMachine};
+
+/// [`Tracer`] is a [`TuringMachine`] wrapper which records every step
+/// of another machine into the [`Trace`]. The [`Trace`] can be exported
+/// to JSON Lines, CSV or a space-time diagram (see [`crate::trace`]).
+///
+/// The [`Trace`] starts with the first executed [`Configuration`]
+/// and continues until it is taken by the [`Tracer::take_trace`] method.
+///
+/// Note: this machine is not implementing [`crate::With`].
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, Tracer};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::Tape;
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Right)])?;
+///     let tracer = Tracer::new(Classic::new(program, '0')?);
+///
+///     tracer.translate_nrm(Tape::from("1"))?;
+///
+///     let trace = tracer.take_trace().unwrap();
+///     assert_eq!("0 1 |[1] 0 |\n1 1 | 0 [0]|\n2 0 | 0 [0]|\n", trace.to_diagram());
+///     Ok(())
+/// }
+/// ```
+pub struct Tracer<Machine, S: Symbol, T: TapeStorage<S> = Tape<S>>
+where
+    Machine: TuringMachine<S, T>,
+{
+    machine: Machine,
+    trace: RefCell<Option<Trace<S>>>,
+    storage: PhantomData<T>,
+}
+
+impl<Machine, S: Symbol, T: TapeStorage<S>> Tracer<Machine, S, T>
+where
+    Machine: TuringMachine<S, T>,
+{
+    /// Constructs a new [`Tracer`] with a [`TuringMachine`] and no [`Trace`].
+    pub fn new(machine: Machine) -> Self {
+        Tracer {
+            machine,
+            trace: RefCell::new(None),
+            storage: PhantomData,
+        }
+    }
+
+    /// Returns a copy of the current [`Trace`] if it exists.
+    pub fn trace(&self) -> Option<Trace<S>> {
+        self.trace.borrow().clone()
+    }
+
+    /// Takes the current [`Trace`] if it exists. The next execution
+    /// starts a new [`Trace`].
+    pub fn take_trace(&self) -> Option<Trace<S>> {
+        self.trace.borrow_mut().take()
+    }
+}
+
+impl<Machine, S: Symbol, T: TapeStorage<S>> TuringMachine<S, T> for Tracer<Machine, S, T>
+where
+    Machine: TuringMachine<S, T>,
+{
+    /// Returns the blank [`Symbol`] reference of the inner machine.
+    fn blank(&self) -> Option<&S> {
+        self.machine.blank()
+    }
+
+    /// Executes [`Configuration`] until it halts (reaches the state `0`)
+    /// by the [`Tracer::execute_event`] method in the loop.
+    ///
+    /// Returns [`Err(String)`] like the inner machine and when the inner
+    /// machine keeps the state of the uncovered head (see
+    /// [`crate::machines::Uncovered::Stay`]), so it never halts.
+    fn execute(&self, mut conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        while conf.state != State(0) {
+            let (next, event) = self.execute_event(conf)?;
+            check_halting(&event)?;
+            conf = next;
+        }
+        Ok(conf)
+    }
+
+    /// Executes [`Configuration`] once by mutation and records the [`Event`]
+    /// of the inner machine (see [`Tracer::execute_event`]).
+    fn execute_once(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
+        self.execute_event(conf).map(|(conf, _)| conf)
+    }
+
+    /// Executes [`Configuration`] once by mutation and records the [`Event`]
+    /// of the inner machine.
+    fn execute_event(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        let index = conf.index();
+        if self.trace.borrow().is_none() {
+            *self.trace.borrow_mut() = Some(Trace::new(&conf));
+        }
+        let (next, event) = self.machine.execute_event(conf)?;
+        if let Some(ref mut trace) = *self.trace.borrow_mut() {
+            trace.record(event.clone(), index);
+        }
+        Ok((next, event))
+    }
+
+    /// Executes [`Configuration`] until predicate is `false` by mutation.
+    ///
+    /// Uses the [`Tracer::execute_event`] method in the loop.
+    fn execute_until(
+        &self,
+        mut conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String> {
+        while !until(&conf) {
+            conf = self.execute_once(conf)?;
+        }
+        Ok(conf)
+    }
+}
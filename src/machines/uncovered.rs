@@ -0,0 +1,98 @@
+use crate::instruction::{Event, Head, State};
+use crate::Symbol;
+
+/// [`Uncovered`] is a policy for heads which have no
+/// [`crate::instruction::Instruction`] in the program (uncovered heads).
+/// The policy allows partially specified programs to run without explicit
+/// `(q, s) -> (0, s, None)` instructions.
+///
+/// The default policy is [`Uncovered::Error`].
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, Uncovered};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::Tape;
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '1', 1, '0', Move::Right)])?;
+///     let mut machine = Classic::new(program, '0')?;
+///     assert!(machine.translate_nrm(Tape::from("110")).is_err());
+///
+///     machine.set_uncovered(Uncovered::Halt);
+///     assert_eq!(machine.translate_nrm(Tape::from("110"))?, Tape::from("000"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Uncovered {
+    /// Returns [`Err(String)`] with the `uncovered case` diagnostic
+    /// information (the program is broken).
+    Error,
+    /// Halts the machine: the state becomes `0`, the symbol and the head
+    /// position are not changed (the implicit halt).
+    Halt,
+    /// Stops the machine with [`Err(String)`] which starts with `reject`
+    /// (the implicit reject: the input is not accepted).
+    Reject,
+    /// Leaves the configuration unchanged. The machine never halts
+    /// on such heads so this policy is useful with the
+    /// [`crate::TuringMachine::execute_until`] method, the
+    /// [`crate::TuringMachine::execute`] method of [`crate::machines::Classic`]
+    /// and [`crate::machines::Compiled`] machines returns [`Err(String)`]
+    /// instead of the endless loop.
+    Stay,
+}
+
+impl Uncovered {
+    /// Returns the next [`State`] for the uncovered head by the policy
+    /// or [`Err(String)`] with diagnostic information.
+    pub(crate) fn resolve<S: Symbol>(self, head: &Head<S>) -> Result<State, String> {
+        match self {
+            Uncovered::Error => Err(format!(
+                "uncovered case: have no tail for head ({}) in program",
+                head
+            )),
+            Uncovered::Halt => Ok(State(0)),
+            Uncovered::Reject => Err(format!(
+                "reject: have no tail for head ({}) in program",
+                head
+            )),
+            Uncovered::Stay => Ok(head.state),
+        }
+    }
+
+    /// Returns the next [`State`] like [`Uncovered::resolve`] when
+    /// the execution continues until the halt: [`Uncovered::Stay`] never
+    /// halts so it returns [`Err(String)`].
+    pub(crate) fn resolve_halting<S: Symbol>(self, head: &Head<S>) -> Result<State, String> {
+        match self {
+            Uncovered::Stay => Err(format!(
+                "execute error: uncovered head ({}) stays forever by the Stay policy",
+                head
+            )),
+            _ => self.resolve(head),
+        }
+    }
+}
+
+/// Returns [`Err(String)`] like [`Uncovered::resolve_halting`] when
+/// the [`Event`] is the uncovered head which keeps the state: the machine
+/// executes it forever, so the execution until the halt never ends.
+pub(crate) fn check_halting<S: Symbol>(event: &Event<S>) -> Result<(), String> {
+    match event {
+        Event::Uncovered { head, state } if *state == head.state => {
+            Uncovered::Stay.resolve_halting(head).map(|_| ())
+        }
+        _ => Ok(()),
+    }
+}
+
+impl Default for Uncovered {
+    fn default() -> Self {
+        Uncovered::Error
+    }
+}
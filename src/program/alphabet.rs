@@ -0,0 +1,165 @@
+use std::fmt::{Debug, Error, Formatter};
+
+use crate::Symbol;
+
+/// [`Alphabet`] is a vector-based set of [`Symbol`]s without duplicates.
+/// Each symbol has the dense index (its position in the [`Alphabet`])
+/// which can be used for table-driven lookups.
+///
+/// [`Symbol`] requires only the equality, so the symbol lookup
+/// ([`Alphabet::contains`], [`Alphabet::index`]) is the linear scan: `O(n)`
+/// for `n` symbols, set operations are `O(n * m)`. The index lookup
+/// ([`Alphabet::get`]) is `O(1)`. Hot paths should look symbols up once
+/// and keep indexes (as [`crate::machines::Compiled`] interns the tape
+/// before the execution, so its steps look symbols up in `O(1)`).
+///
+/// [`Alphabet`]s are equal when they contain the same symbols
+/// in any order (the set equality).
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::program::Alphabet;
+///
+/// fn main() -> Result<(), String> {
+///     let binary = Alphabet::new(['0', '1'])?;
+///     let marked = Alphabet::new(['1', '0', '#'])?;
+///
+///     assert_eq!(marked.index(&'#'), Some(2));
+///     assert!(binary.is_subset(&marked));
+///     assert_eq!(binary.union(&marked), marked);
+///     assert!(Alphabet::new(['0', '0']).is_err());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Alphabet<S: Symbol> {
+    symbols: Vec<S>,
+}
+
+impl<S: Symbol> Alphabet<S> {
+    /// Constructs a new [`Alphabet`] from symbols in their order.
+    /// The check of duplicates is `O(n^2)`.
+    ///
+    /// Returns [`Err(String)`] when a symbol is duplicated.
+    pub fn new(symbols: impl IntoIterator<Item = S>) -> Result<Self, String> {
+        let mut alphabet = Alphabet {
+            symbols: Vec::new(),
+        };
+        for symbol in symbols {
+            if alphabet.contains(&symbol) {
+                return Err(format!("alphabet error: symbol {} is duplicated", symbol));
+            }
+            alphabet.symbols.push(symbol);
+        }
+        Ok(alphabet)
+    }
+
+    /// Returns the [`Vec`] of symbols reference in the [`Alphabet`] order.
+    ///
+    /// Zero cost method.
+    pub fn as_vec(&self) -> &Vec<S> {
+        &self.symbols
+    }
+
+    /// Returns `true` if the [`Alphabet`] contains the symbol,
+    /// otherwise `false`.
+    ///
+    /// Linear scan: `O(n)`.
+    pub fn contains(&self, symbol: &S) -> bool {
+        self.symbols.contains(symbol)
+    }
+
+    /// Returns the [`Symbol`] reference by the index if it exists.
+    ///
+    /// Zero cost method.
+    pub fn get(&self, index: usize) -> Option<&S> {
+        self.symbols.get(index)
+    }
+
+    /// Returns the dense index of the [`Symbol`] if the [`Alphabet`]
+    /// contains it.
+    ///
+    /// Linear scan: `O(n)`.
+    pub fn index(&self, symbol: &S) -> Option<usize> {
+        self.symbols.iter().position(|s| s == symbol)
+    }
+
+    /// Returns `true` if the [`Alphabet`] has no symbols, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns `true` if all symbols are in another [`Alphabet`],
+    /// otherwise `false`.
+    ///
+    /// Quadratic: `O(n * m)`.
+    pub fn is_subset(&self, other: &Alphabet<S>) -> bool {
+        self.symbols.iter().all(|symbol| other.contains(symbol))
+    }
+
+    /// Returns an iterator over symbols in the [`Alphabet`] order.
+    pub fn iter(&self) -> std::slice::Iter<'_, S> {
+        self.symbols.iter()
+    }
+
+    /// Returns the count of symbols.
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Returns a new [`Alphabet`] with symbols of this [`Alphabet`]
+    /// and then new symbols of another [`Alphabet`] in their order.
+    ///
+    /// Quadratic: `O(n * m)`.
+    pub fn union(&self, other: &Alphabet<S>) -> Alphabet<S> {
+        let mut alphabet = self.clone();
+        for symbol in other {
+            if !alphabet.contains(symbol) {
+                alphabet.symbols.push(symbol.clone());
+            }
+        }
+        alphabet
+    }
+}
+
+impl<S: Symbol> From<Vec<S>> for Alphabet<S> {
+    /// Constructs a new [`Alphabet`] from the [`Vec`] of symbols.
+    /// Unlike [`Alphabet::new`] duplicated symbols are skipped.
+    fn from(symbols: Vec<S>) -> Self {
+        let mut alphabet = Alphabet {
+            symbols: Vec::with_capacity(symbols.len()),
+        };
+        for symbol in symbols {
+            if !alphabet.contains(&symbol) {
+                alphabet.symbols.push(symbol);
+            }
+        }
+        alphabet
+    }
+}
+
+impl<'a, S: Symbol> IntoIterator for &'a Alphabet<S> {
+    type Item = &'a S;
+    type IntoIter = std::slice::Iter<'a, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.symbols.iter()
+    }
+}
+
+impl<S: Symbol> PartialEq for Alphabet<S> {
+    /// Returns `true` when [`Alphabet`]s contain the same symbols
+    /// in any order.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<S: Symbol> Eq for Alphabet<S> {}
+
+impl<S: Symbol> Debug for Alphabet<S> {
+    /// Writes symbols like the [`Vec`] of symbols (e.g. `['0', '1']`).
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_list().entries(&self.symbols).finish()
+    }
+}
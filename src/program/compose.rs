@@ -0,0 +1,326 @@
+use crate::instruction::{Instruction, Move, State};
+use crate::program::{Alphabet, Program};
+use crate::{Symbol, With};
+
+impl<S: Symbol> Program<S> {
+    /// Returns a new [`Program`] which executes this [`Program`] (the test)
+    /// and, when the test halts, reads the symbol under the head: when it is
+    /// one of `symbols` then executes the `then` [`Program`], otherwise
+    /// the `otherwise` [`Program`].
+    ///
+    /// States of the new [`Program`] are: test states, the dispatch state
+    /// `self.l_state + 1` which doesn't move the head (so the branching takes
+    /// one extra step), `then` states and `otherwise` states. The branch
+    /// [`Program`] with the last state `0` halts immediately.
+    ///
+    /// Returns [`Err(String)`] when alphabets are not equal, symbols are not
+    /// in the alphabet or states are too large to be composed.
+    pub fn branch_on_symbol(
+        &self,
+        symbols: &[S],
+        then: &Program<S>,
+        otherwise: &Program<S>,
+    ) -> Result<Program<S>, String> {
+        alphabets("branch", &[self, then, otherwise])?;
+        self.contains("branch", symbols)?;
+        let l_state = l_state(
+            "branch",
+            &[
+                self.l_state(),
+                State(1),
+                then.l_state(),
+                otherwise.l_state(),
+            ],
+        )?;
+        let dispatch = self.l_state() + State(1);
+        let then_shift = dispatch;
+        let otherwise_shift = dispatch + then.l_state();
+
+        let mut program = Program::from_alphabet(self.symbols().clone(), l_state);
+        for inst in self.shifted(State(0), dispatch) {
+            program.insert(inst)?;
+        }
+        for symbol in self.alphabet() {
+            let start = match symbols.contains(symbol) {
+                true => start(then, then_shift),
+                false => start(otherwise, otherwise_shift),
+            };
+            program.insert(Instruction::build(
+                dispatch,
+                symbol.clone(),
+                start,
+                symbol.clone(),
+                Move::None,
+            ))?;
+        }
+        for inst in then.shifted(then_shift, State(0)) {
+            program.insert(inst)?;
+        }
+        for inst in otherwise.shifted(otherwise_shift, State(0)) {
+            program.insert(inst)?;
+        }
+        Ok(program)
+    }
+
+    /// Returns a new [`Program`] which executes this [`Program`] (the test)
+    /// and then executes the `then` [`Program`] when the test reaches
+    /// the `accept` state or the `otherwise` [`Program`] when the test halts
+    /// (reaches the state `0`). So the `accept` state is the second halting
+    /// state of the test and must have no [`Instruction`]s.
+    ///
+    /// States of the new [`Program`] are: test states, `then` states
+    /// and `otherwise` states. The branch [`Program`] with the last state `0`
+    /// halts immediately.
+    ///
+    /// Returns [`Err(String)`] when alphabets are not equal, the `accept` state
+    /// is not a free state of the test or states are too large to be composed.
+    pub fn branch_on_state(
+        &self,
+        accept: State,
+        then: &Program<S>,
+        otherwise: &Program<S>,
+    ) -> Result<Program<S>, String> {
+        alphabets("branch", &[self, then, otherwise])?;
+        let used = self
+            .instructions()
+            .iter()
+            .any(|inst| inst.head.state == accept);
+        if accept == State(0) || self.l_state() < accept || used {
+            return Err(format!(
+                "branch error: accept state {} must be in 1..={} and have no instructions",
+                accept,
+                self.l_state()
+            ));
+        }
+        let l_state = l_state(
+            "branch",
+            &[self.l_state(), then.l_state(), otherwise.l_state()],
+        )?;
+        let then_shift = self.l_state();
+        let otherwise_shift = self.l_state() + then.l_state();
+        let then_start = start(then, then_shift);
+
+        let mut program = Program::from_alphabet(self.symbols().clone(), l_state);
+        for mut inst in self.shifted(State(0), start(otherwise, otherwise_shift)) {
+            // Tails to `accept` jump to `then`, halting tails (`0`) jump to `otherwise`
+            if inst.tail.state == accept {
+                inst.tail.state = then_start;
+            }
+            program.insert(inst)?;
+        }
+        for inst in then.shifted(then_shift, State(0)) {
+            program.insert(inst)?;
+        }
+        for inst in otherwise.shifted(otherwise_shift, State(0)) {
+            program.insert(inst)?;
+        }
+        Ok(program)
+    }
+
+    /// Returns a new [`Program`] which repeats this [`Program`] (the body)
+    /// while the symbol under the head is one of `symbols`. The guard state
+    /// `1` checks the symbol without moving the head and starts the body
+    /// or halts, the body returns to the guard when it halts.
+    ///
+    /// States of the new [`Program`] are: the guard state and body states
+    /// (shifted by `1`), so each iteration takes one extra step. The body
+    /// [`Program`] with the last state `0` halts immediately.
+    ///
+    /// Returns [`Err(String)`] when symbols are not in the alphabet
+    /// or states are too large to be composed.
+    pub fn repeat_while(&self, symbols: &[S]) -> Result<Program<S>, String> {
+        self.contains("while", symbols)?;
+        let l_state = l_state("while", &[State(1), self.l_state()])?;
+        let guard = State(1);
+
+        let mut program = Program::from_alphabet(self.symbols().clone(), l_state);
+        for symbol in self.alphabet() {
+            let next = match symbols.contains(symbol) {
+                true => start(self, guard),
+                false => State(0),
+            };
+            program.insert(Instruction::build(
+                guard,
+                symbol.clone(),
+                next,
+                symbol.clone(),
+                Move::None,
+            ))?;
+        }
+        for inst in self.shifted(guard, guard) {
+            program.insert(inst)?;
+        }
+        Ok(program)
+    }
+
+    /// Returns a new [`Program`] like [`crate::With::with`] but for different
+    /// alphabets: the new alphabet is the union of alphabets (symbols of this
+    /// [`Program`] then new symbols of another [`Program`] in their order).
+    ///
+    /// Each [`Program`] keeps its [`Instruction`]s, so heads with new symbols
+    /// stay uncovered: when the part of this [`Program`] reads a symbol which
+    /// is known only by another [`Program`] (or vice versa), the machine
+    /// returns the uncovered case error.
+    ///
+    /// Returns [`Err(String)`] when states are too large to be composed.
+    pub fn with_union(&self, other: &Program<S>) -> Result<Program<S>, String> {
+        self.with_mapping(other, &[])
+    }
+
+    /// Returns a new [`Program`] like [`Program::with_union`] but symbols
+    /// of another [`Program`] (its alphabet and [`Instruction`]s) are replaced
+    /// by the `mapping` pairs `(from, to)` before the union. Symbols without
+    /// pairs are not changed.
+    ///
+    /// Returns [`Err(String)`] when `from` symbols are not in another
+    /// alphabet, the mapping merges different symbols or states are too large
+    /// to be composed.
+    pub fn with_mapping(
+        &self,
+        other: &Program<S>,
+        mapping: &[(S, S)],
+    ) -> Result<Program<S>, String> {
+        let from: Vec<S> = mapping.iter().map(|(from, _)| from.clone()).collect();
+        other.contains("with", &from)?;
+        let map = |symbol: &S| match mapping.iter().find(|(from, _)| from == symbol) {
+            Some((_, to)) => to.clone(),
+            None => symbol.clone(),
+        };
+
+        let mapped: Vec<S> = other.alphabet().iter().map(map).collect();
+        for (index, symbol) in mapped.iter().enumerate() {
+            let first = mapped.iter().position(|s| s == symbol).unwrap_or(index);
+            let symbols = other.alphabet();
+            if symbols[first] != symbols[index] {
+                return Err(format!(
+                    "with error: mapping {:?} merges symbols {} and {} into {}",
+                    mapping, symbols[first], symbols[index], symbol
+                ));
+            }
+        }
+        let alphabet = self.symbols().union(&Alphabet::from(mapped));
+
+        let mut lhs = Program::from_alphabet(alphabet.clone(), self.l_state());
+        for inst in self.instructions() {
+            lhs.insert(inst.clone())?;
+        }
+        let mut rhs = Program::from_alphabet(alphabet, other.l_state());
+        for inst in other.instructions() {
+            let mut inst = inst.clone();
+            inst.head.symbol = map(&inst.head.symbol);
+            inst.tail.symbol = map(&inst.tail.symbol);
+            rhs.insert(inst)?;
+        }
+        lhs.with(&rhs)
+    }
+
+    /// Returns a new equivalent [`Program`] without [`Move::None`] movements
+    /// over the same alphabet. Each [`Instruction`] `(q, s) -> (p, t, None)`
+    /// is replaced by `(q, s) -> (a, t, Right)` and the auxiliary state `a`
+    /// moves the head back: `(a, x) -> (p, x, Left)` for every symbol `x`.
+    ///
+    /// Auxiliary states are `self.l_state + 1` and further, one for each
+    /// target state `p` (in order of states). The new [`Program`] takes
+    /// an extra step for each removed movement.
+    ///
+    /// The auxiliary state moves the head right first, so it reads the cell
+    /// which the original [`Program`] may never touch: the tape must grow
+    /// to the right by the default symbol when the head stays on the last
+    /// cell. [`crate::machines::Classic`] tapes always grow to the right
+    /// (the [`crate::state::Boundary`] bounds only the left edge), but fixed
+    /// tapes are not supported: [`crate::machines::Lba::new`] rejects the new
+    /// [`Program`] when it stays on the right end marker.
+    ///
+    /// Returns [`Err(String)`] when states are too large.
+    pub fn without_stay(&self) -> Result<Program<S>, String> {
+        let mut targets: Vec<State> = self
+            .instructions()
+            .iter()
+            .filter(|inst| inst.tail.movement == Move::None)
+            .map(|inst| inst.tail.state)
+            .collect();
+        targets.sort();
+        targets.dedup();
+        let l_state = l_state("stay", &[self.l_state(), State(targets.len())])?;
+        let aux = |target: &State| {
+            let position = targets.iter().position(|state| state == target);
+            self.l_state() + State(position.unwrap_or_default() + 1)
+        };
+
+        let mut program = Program::from_alphabet(self.symbols().clone(), l_state);
+        for inst in self.instructions() {
+            let mut inst = inst.clone();
+            if inst.tail.movement == Move::None {
+                inst.tail.state = aux(&inst.tail.state);
+                inst.tail.movement = Move::Right;
+            }
+            program.insert(inst)?;
+        }
+        for target in &targets {
+            for symbol in self.alphabet() {
+                program.insert(Instruction::build(
+                    aux(target),
+                    symbol.clone(),
+                    *target,
+                    symbol.clone(),
+                    Move::Left,
+                ))?;
+            }
+        }
+        Ok(program)
+    }
+
+    /// Checks that all symbols are in the [`Program`] alphabet.
+    fn contains(&self, name: &str, symbols: &[S]) -> Result<(), String> {
+        match symbols.iter().find(|s| !self.alphabet().contains(s)) {
+            Some(symbol) => Err(format!(
+                "{} error: symbol {} is not in alphabet {:?}",
+                name,
+                symbol,
+                self.alphabet()
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the start state of the shifted [`Program`] or `0` for the empty
+/// [`Program`] (with the last state `0`).
+fn start<S: Symbol>(program: &Program<S>, shift: State) -> State {
+    match program.l_state() {
+        State(0) => State(0),
+        _ => shift + State(1),
+    }
+}
+
+/// Checks that all [`Program`]s have the same alphabet.
+fn alphabets<S: Symbol>(name: &str, programs: &[&Program<S>]) -> Result<(), String> {
+    match programs
+        .iter()
+        .find(|program| program.symbols() != programs[0].symbols())
+    {
+        Some(program) => Err(format!(
+            "{} error: alphabet {:?} and {:?} must be equal",
+            name,
+            programs[0].alphabet(),
+            program.alphabet()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the sum of last states when it doesn't overflow.
+fn l_state(name: &str, states: &[State]) -> Result<State, String> {
+    states
+        .iter()
+        .try_fold(0usize, |sum, state| sum.checked_add(state.0))
+        .map(State)
+        .ok_or_else(|| {
+            let states: Vec<String> = states.iter().map(|state| state.to_string()).collect();
+            format!(
+                "{} error: last states {} are too large to be composed",
+                name,
+                states.join(", ")
+            )
+        })
+}
@@ -2,13 +2,16 @@ use std::fmt::{Display, Error, Formatter};
 use std::mem::replace;
 
 use crate::instruction::{Head, Instruction, Move, State};
-use crate::program::Extend;
+use crate::program::{Alphabet, Extend};
 use crate::{Symbol, With};
 
-/// [`Program`] is a vector-based struct with a limited API for changing
-/// and extending but no API for removing and shrinking. The [`Program`]
-/// for the Turing machine has a constant size that equals to
-/// `(STATES.count() - 1) * (ALPHABET.count())`.
+/// The largest count of [`Instruction`]s which is reserved by [`Program::new`].
+const CAPACITY_LIMIT: usize = 1 << 16;
+
+/// [`Program`] is a vector-based struct with a limited API for changing,
+/// extending and removing (by the [`Program::remove`] method) but no API
+/// for shrinking. The [`Program`] for the Turing machine has a constant size
+/// that equals to `(STATES.count() - 1) * (ALPHABET.count())`.
 ///
 /// If you want to extend the program, you can use the [`Extend::extend`] method,
 /// but you should be sure that this program can accept all these instructions.
@@ -23,7 +26,7 @@ use crate::{Symbol, With};
 /// use turing_machine_rs::TuringMachine;
 ///
 /// fn main() -> Result<(), String> {
-///    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', 'e', '_'];
+///    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', '_'];
 ///    let mut program = Program::new(alphabet, State(4));
 ///     program.extend([
 ///         (1, 't', 2, 'n', Move::Right),
@@ -51,7 +54,7 @@ use crate::{Symbol, With};
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Program<S: Symbol> {
     container: Vec<Instruction<S>>,
-    alphabet: Vec<S>,
+    alphabet: Alphabet<S>,
     l_state: State,
 }
 
@@ -61,8 +64,32 @@ impl<S: Symbol> Program<S> {
     /// and the last state [`State`].
     ///
     /// [`Program`] has a limited size by definition, so it can only hold `(STATES.count() - 1) * (ALPHABET.count())` [`Instruction`]s.
+    ///
+    /// # Panics
+    /// Panics when a symbol of the vector is duplicated, use
+    /// the [`Program::try_new`] method to get [`Err(String)`] instead.
     pub fn new(alphabet: Vec<S>, l_state: State) -> Self {
-        let capacity = alphabet.len() * l_state.0;
+        match Program::try_new(alphabet, l_state) {
+            Ok(program) => program,
+            Err(msg) => panic!("{}", msg),
+        }
+    }
+
+    /// Constructs a new [`Program`] with the vector [`Vec<S>`]
+    /// and the last state [`State`].
+    ///
+    /// Returns [`Err(String)`] when a symbol of the vector is duplicated
+    /// (see [`Alphabet::new`]).
+    pub fn try_new(alphabet: Vec<S>, l_state: State) -> Result<Self, String> {
+        Ok(Program::from_alphabet(Alphabet::new(alphabet)?, l_state))
+    }
+
+    #[rustfmt::skip]
+    /// Constructs a new [`Program`] with the [`Alphabet`]
+    /// and the last state [`State`].
+    pub fn from_alphabet(alphabet: Alphabet<S>, l_state: State) -> Self {
+        // The last state can be any `usize` so the capacity is limited
+        let capacity = alphabet.len().saturating_mul(l_state.0).min(CAPACITY_LIMIT);
         let container = Vec::with_capacity(capacity);
         Program { alphabet, container, l_state }
     }
@@ -71,6 +98,14 @@ impl<S: Symbol> Program<S> {
     ///
     /// Zero cost method.
     pub fn alphabet(&self) -> &Vec<S> {
+        self.alphabet.as_vec()
+    }
+
+    /// Returns an [`Alphabet`] reference: the alphabet with the set
+    /// operations and symbol indexes.
+    ///
+    /// Zero cost method.
+    pub fn symbols(&self) -> &Alphabet<S> {
         &self.alphabet
     }
 
@@ -91,6 +126,13 @@ impl<S: Symbol> Program<S> {
             .find(|inst: &&Instruction<S>| &inst.head == head))
     }
 
+    /// Returns an [`Vec`] instructions reference in order of insertion.
+    ///
+    /// Zero cost method.
+    pub fn instructions(&self) -> &Vec<Instruction<S>> {
+        &self.container
+    }
+
     /// Returns [`State`] the program last state.
     pub fn l_state(&self) -> State {
         self.l_state
@@ -140,6 +182,48 @@ impl<S: Symbol> Program<S> {
             }
         }
     }
+
+    /// Returns [`Instruction`]s with [`Head`] states and non-zero
+    /// [`crate::instruction::Tail`] states increased by `shift`.
+    /// [`crate::instruction::Tail`] states `0` (the halt) are replaced
+    /// by the `exit` state.
+    ///
+    /// This is the base of compositions: the caller must check that shifted
+    /// states don't overflow.
+    pub(super) fn shifted(
+        &self,
+        shift: State,
+        exit: State,
+    ) -> impl Iterator<Item = Instruction<S>> + '_ {
+        self.container.iter().map(move |inst| {
+            let mut inst = inst.clone();
+            inst.head.state += shift;
+            inst.tail.state = match inst.tail.state {
+                State(0) => exit,
+                state => state + shift,
+            };
+            inst
+        })
+    }
+
+    /// Removes the [`Instruction`] with the [`Head`] from the [`Program`].
+    ///
+    /// Returns [`Ok(Some(Instruction))`] with the removed [`Instruction`],
+    /// [`Ok(None)`] when [`Head`] is not in the program and [`Err(String)`]
+    /// when [`Head`] [`State`] is large then the [`Program`] last state.
+    pub fn remove(&mut self, head: &Head<S>) -> Result<Option<Instruction<S>>, String> {
+        if self.l_state < head.state {
+            return Err(format!(
+                "remove error: required state {} is large then largest {}",
+                head.state, self.l_state
+            ));
+        }
+        let position = self
+            .container
+            .iter()
+            .position(|inst: &Instruction<S>| &inst.head == head);
+        Ok(position.map(|index| self.container.remove(index)))
+    }
 }
 
 impl<S: Symbol> With<Program<S>> for Program<S> {
@@ -147,14 +231,18 @@ impl<S: Symbol> With<Program<S>> for Program<S> {
 
     /// Returns a new [`Program`] by merging this program with another according to these rules:
     /// 1. All [`crate::instruction::Tail`] parts of [`Instruction`]s for this [`Program`]
-    ///     will changes their [`State`]s to `self.l_state` if [`crate::instruction::Tail`]
-    ///     [`State`] equals to `0`.
+    ///    will changes their [`State`]s to `self.l_state` if [`crate::instruction::Tail`]
+    ///    [`State`] equals to `0`.
     /// 2. All [`Head`] parts of [`Instruction`]s for another [`Program`] will
-    ///     increase (or shift) their [`State`]s by `self.l_state`.
+    ///    increase (or shift) their [`State`]s by `self.l_state`.
     /// 3. All [`crate::instruction::Tail`] parts of [`Instruction`]s
-    ///     for another program will also increase (or shift) by `self.l_state`
-    ///     but only if [`crate::instruction::Tail`] [`State`] not equals to `0`.
+    ///    for another program will also increase (or shift) by `self.l_state`
+    ///    but only if [`crate::instruction::Tail`] [`State`] not equals to `0`.
     /// 4. A new [`Program`] `l_state` is set to `self.l_state + other.l_state`.
+    ///
+    /// Alphabets must contain the same symbols but the order doesn't matter
+    /// (the new [`Program`] uses the order of this [`Program`]). For different
+    /// alphabets use [`Program::with_union`] or [`Program::with_mapping`].
     fn with(&self, other: &Program<S>) -> Result<Program<S>, String> {
         if self.alphabet != other.alphabet {
             return Err(format!(
@@ -162,28 +250,26 @@ impl<S: Symbol> With<Program<S>> for Program<S> {
                 &self.alphabet, &other.alphabet
             ));
         }
-        let mut program = Program::new(self.alphabet.clone(), self.l_state + other.l_state);
-        // `self` and `other` are `Program` instances so it doesn't need to use insert method.
-        let extension = self.container.iter().map(|inst| match inst.tail.state {
-            State(0) => {
-                let mut inst = inst.clone();
-                inst.tail.state = self.l_state + State(1);
-                inst
+        let l_state = match (
+            self.l_state.0.checked_add(other.l_state.0),
+            self.l_state.0.checked_add(1),
+        ) {
+            (Some(l_state), Some(_)) => State(l_state),
+            _ => {
+                return Err(format!(
+                    "extend error: last states {} and {} are too large to be merged",
+                    self.l_state, other.l_state
+                ))
             }
-            _ => inst.clone(),
-        });
-        program.container.extend(extension);
-
-        let extension = other.container.iter().map(|inst| {
-            let mut inst = inst.clone();
-            inst.head.state += self.l_state;
-            inst.tail.state += match inst.tail.state {
-                State(0) => State(0),
-                _ => self.l_state,
-            };
-            inst
-        });
-        program.container.extend(extension);
+        };
+        let mut program = Program::from_alphabet(self.alphabet.clone(), l_state);
+        // `self` and `other` are `Program` instances so it doesn't need to use insert method.
+        program
+            .container
+            .extend(self.shifted(State(0), self.l_state + State(1)));
+        program
+            .container
+            .extend(other.shifted(self.l_state, State(0)));
 
         Ok(program)
     }
@@ -4,8 +4,26 @@
 //! a [`crate::TuringMachine`] and the [`Extend`] trait for the [`Program`]
 //! which allows to extend the [`Program`] by tuples of
 //! ([`usize`], [`crate::Symbol`], [`usize`], [`crate::Symbol`], [`crate::instruction::Move`]).
+//! The [`Program`] alphabet is the [`Alphabet`]: the set of symbols
+//! with dense indexes.
+//!
+//! [`Program`]s of [`char`] symbols can be parsed from the text format
+//! (see [`Program::from_str`](std::str::FromStr::from_str)) and written back
+//! by the [`Program::to_source`] method.
+//!
+//! Besides the sequential composition ([`crate::With`]), [`Program`]s can be
+//! composed by branching ([`Program::branch_on_symbol`] and
+//! [`Program::branch_on_state`]) and by the loop ([`Program::repeat_while`]).
+//! [`Program`]s with different alphabets can be composed by
+//! [`Program::with_union`] and [`Program::with_mapping`].
+//! [`Program::without_stay`] replaces [`crate::instruction::Move::None`]
+//! movements for formalisms which don't allow them.
 
+mod alphabet;
+mod compose;
 mod core;
+mod text;
+pub use self::alphabet::Alphabet;
 pub use self::core::Program;
 
 /// Helper trait which allows to implement extend method.
@@ -0,0 +1,226 @@
+use std::str::FromStr;
+
+use crate::instruction::{Instruction, Move, State};
+use crate::program::{Alphabet, Program};
+
+impl FromStr for Program<char> {
+    type Err = String;
+
+    /// Parses the [`Program`] from the text format. Each non-empty line is
+    /// a comment (starts with `#`), a header or an [`Instruction`]:
+    /// ```text
+    /// # Inverts the tape until the blank symbol
+    /// alphabet: _ 0 1
+    /// l_state: 1
+    /// 1, 0 -> 1, 1, >
+    /// 1, 1 -> 1, 0, >
+    /// 1, _ -> 0, _, -
+    /// ```
+    /// Tokens are separated by spaces or commas. Moves are written as
+    /// `<`, `-`, `>` (or `L`, `N`, `R`). A symbol can be quoted (e.g. `' '`
+    /// or `','`) when it's a space, a comma or a quote. Quoted symbols
+    /// support escapes `'\n'`, `'\r'`, `'\t'`, `'\\'` and `'\''`.
+    ///
+    /// Headers are optional: the alphabet is collected from instructions
+    /// in order of appearance and the last state is the largest state.
+    /// The alphabet header cannot contain duplicated symbols.
+    ///
+    /// Returns [`Ok(Program)`] when the text is valid, otherwise
+    /// [`Err(String)`] with diagnostic information.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut alphabet: Option<Alphabet<char>> = None;
+        let mut l_state: Option<State> = None;
+        let mut instructions = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |msg: String| format!("parse error: line {}: {}", number + 1, msg);
+            if let Some(rest) = line.strip_prefix("alphabet:") {
+                let symbols: Vec<char> = tokens(rest)
+                    .map_err(error)?
+                    .iter()
+                    .map(|token| symbol(token))
+                    .collect::<Result<_, _>>()
+                    .map_err(error)?;
+                alphabet = Some(Alphabet::new(symbols).map_err(error)?);
+            } else if let Some(rest) = line.strip_prefix("l_state:") {
+                l_state = Some(state(rest.trim()).map_err(error)?);
+            } else {
+                instructions.push((number, instruction(line).map_err(error)?));
+            }
+        }
+
+        let alphabet = alphabet.unwrap_or_else(|| {
+            // Duplicated symbols are skipped by the conversion
+            let symbols: Vec<char> = instructions
+                .iter()
+                .flat_map(|(_, inst)| [inst.head.symbol, inst.tail.symbol])
+                .collect();
+            Alphabet::from(symbols)
+        });
+        let l_state = l_state.unwrap_or_else(|| {
+            let states = instructions
+                .iter()
+                .flat_map(|(_, inst)| [inst.head.state, inst.tail.state]);
+            states.max().unwrap_or(State(1)).max(State(1))
+        });
+
+        let mut program = Program::from_alphabet(alphabet, l_state);
+        for (number, inst) in instructions {
+            let head = inst.head.clone();
+            let replaced = program
+                .insert(inst)
+                .map_err(|msg| format!("parse error: line {}: {}", number + 1, msg))?;
+            if replaced.is_some() {
+                return Err(format!(
+                    "parse error: line {}: instruction for head ({}) is already defined",
+                    number + 1,
+                    head
+                ));
+            }
+        }
+        Ok(program)
+    }
+}
+
+impl FromStr for Instruction<char> {
+    type Err = String;
+
+    /// Parses the [`Instruction`] from the line of the [`Program`] text format,
+    /// e.g. `1, 0 -> 2, 1, >`.
+    ///
+    /// Returns [`Ok(Instruction)`] when the line is valid, otherwise
+    /// [`Err(String)`] with diagnostic information.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        instruction(line.trim()).map_err(|msg| format!("parse error: {}", msg))
+    }
+}
+
+impl Program<char> {
+    /// Returns the text of the [`Program`] which can be parsed back
+    /// by the [`FromStr`] implementation. Headers are always written.
+    pub fn to_source(&self) -> String {
+        let alphabet: Vec<String> = self.alphabet().iter().map(|&ch| quote(ch)).collect();
+        let mut source = format!(
+            "alphabet: {}\nl_state: {}\n",
+            alphabet.join(" "),
+            self.l_state()
+        );
+        for inst in self.instructions() {
+            source.push_str(&format!(
+                "{}, {} -> {}, {}, {}\n",
+                inst.head.state,
+                quote(inst.head.symbol),
+                inst.tail.state,
+                quote(inst.tail.symbol),
+                inst.tail.movement
+            ));
+        }
+        source
+    }
+}
+
+/// Parses the [`Instruction`] line: `state symbol -> state symbol move`.
+fn instruction(line: &str) -> Result<Instruction<char>, String> {
+    let tokens = tokens(line)?;
+    match tokens.as_slice() {
+        [h_state, h_symbol, arrow, t_state, t_symbol, movement] if arrow == "->" => {
+            Ok(Instruction::build(
+                state(h_state)?,
+                symbol(h_symbol)?,
+                state(t_state)?,
+                symbol(t_symbol)?,
+                match movement.as_str() {
+                    "<" | "L" => Move::Left,
+                    "-" | "N" => Move::None,
+                    ">" | "R" => Move::Right,
+                    other => return Err(format!("unknown move {:?}", other)),
+                },
+            ))
+        }
+        _ => Err(format!(
+            "expected instruction `state, symbol -> state, symbol, move` but found {:?}",
+            line
+        )),
+    }
+}
+
+fn state(token: &str) -> Result<State, String> {
+    token
+        .parse()
+        .map(State)
+        .map_err(|_| format!("cannot parse state {:?}", token))
+}
+
+fn symbol(token: &str) -> Result<char, String> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Ok(ch),
+        _ => Err(format!("symbol {:?} must be a single character", token)),
+    }
+}
+
+/// Splits the line by spaces and commas. Quoted symbols are unquoted.
+fn tokens(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() || ch == ',' {
+            chars.next();
+            continue;
+        }
+        if ch == '\'' {
+            chars.next();
+            // Escapes are accepted only in the full form (e.g. `'\n'`), so `'\'` is a backslash
+            let mut ahead = chars.clone();
+            if let (Some('\\'), Some(escape), Some('\'')) =
+                (ahead.next(), ahead.next(), ahead.next())
+            {
+                if let Some(symbol) = unescape(escape) {
+                    tokens.push(symbol.to_string());
+                    chars = ahead;
+                    continue;
+                }
+            }
+            match (chars.next(), chars.next()) {
+                (Some(symbol), Some('\'')) => tokens.push(symbol.to_string()),
+                _ => return Err(String::from("unterminated quoted symbol")),
+            }
+            continue;
+        }
+        let mut token = String::new();
+        while let Some(ch) = chars.next_if(|ch| !ch.is_whitespace() && *ch != ',') {
+            token.push(ch);
+        }
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Quotes the symbol when it cannot be written as is. Line breaks and tabs
+/// are escaped.
+fn quote(symbol: char) -> String {
+    match symbol {
+        '\n' => String::from("'\\n'"),
+        '\r' => String::from("'\\r'"),
+        '\t' => String::from("'\\t'"),
+        symbol if symbol.is_whitespace() || symbol == ',' || symbol == '\'' => {
+            format!("'{}'", symbol)
+        }
+        symbol => symbol.to_string(),
+    }
+}
+
+/// Returns the symbol of the escape in the quoted symbol.
+fn unescape(escape: char) -> Option<char> {
+    match escape {
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        '\\' => Some('\\'),
+        '\'' => Some('\''),
+        _ => None,
+    }
+}
@@ -0,0 +1,216 @@
+//! Provides [`Spec`] realization and it's components: [`Case`], [`Start`],
+//! [`Report`] and [`Outcome`].
+//!
+//! [`Spec`] is the list of test [`Case`]s for the [`Classic`] machine:
+//! input tapes, start configurations and expected results. [`Spec::run`]
+//! runs all cases and returns the [`Report`] which `Display` is a diff-style
+//! report of failures. Specs of [`char`] symbols can be parsed from the text
+//! format (see [`Spec::from_str`](std::str::FromStr::from_str)).
+//!
+//! # Examples
+//! ```rust
+//! use turing_machine_rs::machines::Classic;
+//! use turing_machine_rs::program::Program;
+//! use turing_machine_rs::spec::Spec;
+//!
+//! fn main() -> Result<(), String> {
+//!     let program: Program<char> = "
+//!         alphabet: _ 0 1
+//!         1, 0 -> 1, 1, >
+//!         1, 1 -> 1, 0, >
+//!         1, _ -> 0, _, -
+//!     "
+//!     .parse()?;
+//!     let machine = Classic::new(program, '_')?;
+//!
+//!     let spec: Spec<char> = "
+//!         name: inverts
+//!         input: 0110
+//!         output: 1001_
+//!         index: 4
+//!
+//!         name: inverts from the last cell
+//!         input: 0110
+//!         start: std
+//!         output: 1001
+//!         trim: yes
+//!     "
+//!     .parse()?;
+//!
+//!     let report = spec.run(&machine);
+//!     assert_eq!(report.failed(), 1);
+//!     assert!(report.to_string().contains("- 1001\n    + 0111"));
+//!     Ok(())
+//! }
+//! ```
+
+mod parse;
+mod report;
+
+pub use self::report::{Mismatch, Outcome, Report};
+
+use crate::instruction::State;
+use crate::machines::Classic;
+use crate::state::{bounds, Configuration, Tape};
+use crate::{Symbol, TuringMachine};
+
+/// Default count of steps for [`Case`]s.
+pub const MAX_STEPS: usize = 10_000;
+
+/// [`Start`] is the start [`Configuration`] of the [`Case`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Start {
+    /// Normal configuration (see [`Configuration::new_nrm`]).
+    Nrm,
+    /// Standard configuration (see [`Configuration::new_std`]).
+    Std,
+    /// Configuration with the index and the state `1`.
+    Index(usize),
+}
+
+/// [`Case`] is the component of the [`Spec`]: the input tape, the start
+/// configuration, expectations and the steps limit.
+///
+/// Expectations are optional except the state which is `0` by default
+/// (the machine must halt).
+///
+/// [`Case`] fields doesn't needs in control or protection so they are public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Case<S: Symbol> {
+    /// The case name which is used in the [`Report`].
+    pub name: String,
+    /// The line of the case in the spec text (`0` for cases made by code).
+    pub line: usize,
+    /// The input tape symbols. The empty input starts on the single default
+    /// symbol cell (except [`Start::Index`]).
+    pub input: Vec<S>,
+    /// The start configuration.
+    pub start: Start,
+    /// The expected output tape.
+    pub output: Option<Vec<S>>,
+    /// The expected final index (of the untrimmed tape).
+    pub index: Option<usize>,
+    /// The expected final state.
+    pub state: State,
+    /// The steps limit: the machine is stopped after this count of steps.
+    pub max_steps: usize,
+    /// When `true`, default symbols at the edges of the output tape
+    /// are ignored.
+    pub trim: bool,
+}
+
+impl<S: Symbol> Case<S> {
+    /// Constructs a new [`Case`] with the name and the input tape which
+    /// starts from the normal configuration and expects only the halt.
+    pub fn new(name: impl Into<String>, input: Vec<S>) -> Self {
+        Case {
+            name: name.into(),
+            line: 0,
+            input,
+            start: Start::Nrm,
+            output: None,
+            index: None,
+            state: State(0),
+            max_steps: MAX_STEPS,
+            trim: false,
+        }
+    }
+
+    /// Runs the [`Case`] against the [`Classic`] machine.
+    pub fn run(&self, machine: &Classic<S>) -> Outcome {
+        let tape = Tape::new(self.input.iter().cloned());
+        let blank = machine.default().clone();
+        let conf = match self.start {
+            Start::Nrm => Ok(Configuration::with_blank(tape, blank, false)),
+            Start::Std => Ok(Configuration::with_blank(tape, blank, true)),
+            Start::Index(index) => Configuration::new(tape, index, State(1)),
+        };
+        let mut conf = match conf {
+            Ok(conf) => conf,
+            Err(msg) => return Outcome::error(self, 0, msg),
+        };
+        let mut steps = 0;
+        while conf.state != State(0) && steps < self.max_steps {
+            conf = match machine.execute_once(conf) {
+                Ok(conf) => conf,
+                Err(msg) => return Outcome::error(self, steps, msg),
+            };
+            steps += 1;
+        }
+
+        let mut mismatches = Vec::new();
+        if let Some(ref expected) = self.output {
+            let found = match self.trim {
+                true => trim(conf.tape().as_vec(), machine.default()),
+                false => conf.tape().as_vec(),
+            };
+            let expected = match self.trim {
+                true => trim(expected, machine.default()),
+                false => expected,
+            };
+            if found != expected {
+                mismatches.push(Mismatch::new("output", symbols(expected), symbols(found)));
+            }
+        }
+        if let Some(index) = self.index {
+            if index != conf.index() {
+                mismatches.push(Mismatch::new(
+                    "index",
+                    index.to_string(),
+                    conf.index().to_string(),
+                ));
+            }
+        }
+        if self.state != conf.state {
+            let mut found = conf.state.to_string();
+            if steps == self.max_steps {
+                found.push_str(&format!(" (step limit {} reached)", self.max_steps));
+            }
+            mismatches.push(Mismatch::new("state", self.state.to_string(), found));
+        }
+        Outcome::new(self, steps, mismatches)
+    }
+}
+
+/// [`Spec`] is the list of [`Case`]s for the [`Classic`] machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spec<S: Symbol> {
+    cases: Vec<Case<S>>,
+}
+
+impl<S: Symbol> Spec<S> {
+    /// Constructs a new [`Spec`] from [`Case`]s.
+    pub fn new(cases: Vec<Case<S>>) -> Self {
+        Spec { cases }
+    }
+
+    /// Returns [`Case`]s of the [`Spec`].
+    ///
+    /// Zero cost method.
+    pub fn cases(&self) -> &[Case<S>] {
+        &self.cases
+    }
+
+    /// Adds the [`Case`] to the [`Spec`].
+    pub fn push(&mut self, case: Case<S>) {
+        self.cases.push(case);
+    }
+
+    /// Runs all [`Case`]s against the [`Classic`] machine and returns
+    /// the [`Report`]. Failed cases don't interrupt the run.
+    pub fn run(&self, machine: &Classic<S>) -> Report {
+        Report::new(self.cases.iter().map(|case| case.run(machine)).collect())
+    }
+}
+
+/// Returns symbols without default symbols at the edges.
+fn trim<'a, S: Symbol>(symbols: &'a [S], default: &S) -> &'a [S] {
+    match bounds(symbols.iter(), default) {
+        Some((first, last)) => &symbols[first..=last],
+        None => &[],
+    }
+}
+
+fn symbols<S: Symbol>(symbols: &[S]) -> String {
+    symbols.iter().map(|s| s.to_string()).collect()
+}
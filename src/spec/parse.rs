@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use crate::instruction::State;
+use crate::spec::{Case, Spec, Start};
+
+impl FromStr for Spec<char> {
+    type Err = String;
+
+    /// Parses the [`Spec`] from the text format. [`Case`]s are blocks
+    /// of `key: value` lines separated by empty lines, lines started
+    /// with `#` are comments:
+    /// ```text
+    /// # Binary addition
+    /// name: one plus one
+    /// input: 1+1
+    /// start: std
+    /// output: " 10  "
+    /// index: 0
+    /// state: 0
+    /// steps: 100
+    /// trim: no
+    /// ```
+    /// Only `input` is required. Tapes can be quoted by `"` (e.g. when they
+    /// contain spaces at the edges). The `start` value is `nrm` (by default),
+    /// `std` or `index N`, the `trim` value is `yes` or `no` (by default).
+    ///
+    /// Returns [`Ok(Spec)`] when the text is valid, otherwise [`Err(String)`]
+    /// with diagnostic information.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut spec = Spec::new(Vec::new());
+        let mut case: Option<(Case<char>, bool)> = None;
+        for (number, line) in text.lines().enumerate().chain(Some((usize::MAX, ""))) {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            if line.is_empty() {
+                if let Some((case, input)) = case.take() {
+                    if !input {
+                        return Err(format!(
+                            "spec error: line {}: case {} has no input",
+                            case.line, case.name
+                        ));
+                    }
+                    spec.push(case);
+                }
+                continue;
+            }
+            let (case, input) = case.get_or_insert_with(|| {
+                let name = format!("case {}", spec.cases().len() + 1);
+                let mut case = Case::new(name, Vec::new());
+                case.line = number + 1;
+                (case, false)
+            });
+            let error = |msg: String| format!("spec error: line {}: {}", number + 1, msg);
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| error(format!("expected `key: value` but found {:?}", line)))?;
+            let value = value.trim();
+            match key.trim() {
+                "name" => case.name = value.to_string(),
+                "input" => {
+                    case.input = tape(value);
+                    *input = true;
+                }
+                "output" => case.output = Some(tape(value)),
+                "start" => case.start = start(value).map_err(error)?,
+                "index" => case.index = Some(number_value(value).map_err(error)?),
+                "state" => case.state = State(number_value(value).map_err(error)?),
+                "steps" => case.max_steps = number_value(value).map_err(error)?,
+                "trim" => {
+                    case.trim = match value {
+                        "yes" => true,
+                        "no" => false,
+                        other => {
+                            return Err(error(format!(
+                                "trim must be yes or no but found {:?}",
+                                other
+                            )))
+                        }
+                    }
+                }
+                other => return Err(error(format!("unknown key {:?}", other))),
+            }
+        }
+        Ok(spec)
+    }
+}
+
+/// Returns tape symbols, the tape can be quoted by `"`.
+fn tape(value: &str) -> Vec<char> {
+    let value = match value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        true => &value[1..value.len() - 1],
+        false => value,
+    };
+    value.chars().collect()
+}
+
+fn start(value: &str) -> Result<Start, String> {
+    match value {
+        "nrm" => Ok(Start::Nrm),
+        "std" => Ok(Start::Std),
+        value => match value.strip_prefix("index") {
+            Some(index) => Ok(Start::Index(number_value(index.trim())?)),
+            None => Err(format!(
+                "start must be nrm, std or index N but found {:?}",
+                value
+            )),
+        },
+    }
+}
+
+fn number_value(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("cannot parse number {:?}", value))
+}
@@ -0,0 +1,162 @@
+use std::fmt::{Display, Error, Formatter};
+
+use crate::spec::Case;
+use crate::Symbol;
+
+/// [`Mismatch`] is the difference between the expected and the found value
+/// of the [`Case`] field (`output`, `index` or `state`).
+///
+/// [`Mismatch`] fields doesn't needs in control or protection so they are public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The field name.
+    pub field: &'static str,
+    /// The expected value.
+    pub expected: String,
+    /// The found value.
+    pub found: String,
+}
+
+impl Mismatch {
+    /// Constructs a new [`Mismatch`] of the field.
+    pub fn new(field: &'static str, expected: String, found: String) -> Self {
+        Mismatch {
+            field,
+            expected,
+            found,
+        }
+    }
+}
+
+/// [`Outcome`] is the result of the [`Case`] run: mismatches or the error
+/// (when the machine or the configuration fails).
+///
+/// [`Outcome`] fields doesn't needs in control or protection so they are public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    /// The [`Case`] name.
+    pub name: String,
+    /// The [`Case`] line.
+    pub line: usize,
+    /// The count of made steps.
+    pub steps: usize,
+    /// Mismatches of expectations.
+    pub mismatches: Vec<Mismatch>,
+    /// The machine or the configuration error.
+    pub error: Option<String>,
+}
+
+impl Outcome {
+    pub(crate) fn new<S: Symbol>(case: &Case<S>, steps: usize, mismatches: Vec<Mismatch>) -> Self {
+        Outcome {
+            name: case.name.clone(),
+            line: case.line,
+            steps,
+            mismatches,
+            error: None,
+        }
+    }
+
+    pub(crate) fn error<S: Symbol>(case: &Case<S>, steps: usize, error: String) -> Self {
+        Outcome {
+            error: Some(error),
+            ..Outcome::new(case, steps, Vec::new())
+        }
+    }
+
+    /// Returns `true` if the [`Case`] has no mismatches and errors.
+    pub fn is_passed(&self) -> bool {
+        self.mismatches.is_empty() && self.error.is_none()
+    }
+}
+
+impl Display for Outcome {
+    /// Writes `PASS name` or `FAIL name` with the error or mismatches
+    /// in the diff form: the expected value is marked by `-`, the found
+    /// value is marked by `+` and the first different symbol by `^`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let status = match self.is_passed() {
+            true => "PASS",
+            false => "FAIL",
+        };
+        write!(f, "{} {}", status, self.name)?;
+        match self.line {
+            0 => writeln!(f, " ({} steps)", self.steps)?,
+            line => writeln!(f, " (line {}, {} steps)", line, self.steps)?,
+        }
+        if let Some(ref error) = self.error {
+            writeln!(f, "    error: {}", error)?;
+        }
+        for mismatch in &self.mismatches {
+            writeln!(f, "    {}:", mismatch.field)?;
+            writeln!(f, "    - {}", mismatch.expected)?;
+            writeln!(f, "    + {}", mismatch.found)?;
+            let same = mismatch
+                .expected
+                .chars()
+                .zip(mismatch.found.chars())
+                .take_while(|(expected, found)| expected == found)
+                .count();
+            writeln!(f, "      {:>w$}", "^", w = same + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// [`Report`] is the list of [`Outcome`]s of the [`crate::spec::Spec`] run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    outcomes: Vec<Outcome>,
+}
+
+impl Report {
+    /// Constructs a new [`Report`] from [`Outcome`]s.
+    pub fn new(outcomes: Vec<Outcome>) -> Self {
+        Report { outcomes }
+    }
+
+    /// Returns all [`Outcome`]s.
+    ///
+    /// Zero cost method.
+    pub fn outcomes(&self) -> &[Outcome] {
+        &self.outcomes
+    }
+
+    /// Returns the count of passed [`Case`]s.
+    pub fn passed(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.is_passed()).count()
+    }
+
+    /// Returns the count of failed [`Case`]s.
+    pub fn failed(&self) -> usize {
+        self.outcomes.len() - self.passed()
+    }
+
+    /// Returns `true` if all [`Case`]s are passed.
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+impl Display for Report {
+    /// Writes failed [`Outcome`]s (or all of them when the alternate flag
+    /// is used, `{:#}`) and the summary, e.g. `result: FAILED. 2 passed; 1 failed`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        for outcome in &self.outcomes {
+            if f.alternate() || !outcome.is_passed() {
+                write!(f, "{}", outcome)?;
+            }
+        }
+        let status = match self.is_success() {
+            true => "ok",
+            false => "FAILED",
+        };
+        write!(
+            f,
+            "result: {}. {} passed; {} failed",
+            status,
+            self.passed(),
+            self.failed()
+        )
+    }
+}
@@ -0,0 +1,40 @@
+use crate::instruction::State;
+
+/// [`Boundary`] is a behaviour of the head which moves left from the first
+/// cell of the [`crate::state::Tape`] (see [`crate::state::Configuration::shift_bounded`]).
+///
+/// The default behaviour is [`Boundary::Grow`] (the two-way infinite tape),
+/// other behaviours model the one-way (semi-infinite) tape.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// Extends the tape to the left by the default symbol
+    /// (the two-way infinite tape).
+    Grow,
+    /// Leaves the head on the first cell (the textbook semi-infinite tape).
+    Stay,
+    /// Returns [`Err(String)`] with diagnostic information (the run crashes:
+    /// the program is not made for the one-way tape).
+    Error,
+}
+
+impl Boundary {
+    /// Returns `true` when the tape must be extended to the left, `false`
+    /// when the head stays or [`Err(String)`] with diagnostic information
+    /// for the move from the first cell in the [`State`].
+    pub(crate) fn resolve(self, state: State) -> Result<bool, String> {
+        match self {
+            Boundary::Grow => Ok(true),
+            Boundary::Stay => Ok(false),
+            Boundary::Error => Err(format!(
+                "shift error: cannot move left from the first cell in state {}",
+                state
+            )),
+        }
+    }
+}
+
+impl Default for Boundary {
+    fn default() -> Self {
+        Boundary::Grow
+    }
+}
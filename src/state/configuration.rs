@@ -1,31 +1,37 @@
 use std::fmt::{Display, Error, Formatter};
+use std::marker::PhantomData;
 
 use crate::instruction::{Move, State};
-use crate::state::Tape;
+use crate::state::{Boundary, Renderer, Style, Tape, TapeStorage};
 use crate::Symbol;
 
 /// [`Configuration`] is a struct that represents the state of a Turing machine.
 /// Machines do not implement their state as a part of themselves;
 /// instead, machines mutate configurations according to their program.
+///
+/// The tape type `T` can be any [`TapeStorage`] realization
+/// and is [`Tape`] by default.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Configuration<S: Symbol> {
-    tape: Tape<S>,
+pub struct Configuration<S: Symbol, T: TapeStorage<S> = Tape<S>> {
+    tape: T,
     index: usize,
     /// [`Configuration`] [`State`] is used by [`crate::TuringMachine`]
     /// and cannot be changed by self-methods.
     pub state: State,
+    symbol: PhantomData<S>,
 }
 
-impl<S: Symbol> Configuration<S> {
+impl<S: Symbol, T: TapeStorage<S>> Configuration<S, T> {
     /// Constructs a new [`Configuration`] from the [`Tape`],
     /// the index [`usize`] and the [`State`].
     ///
     /// Returns a new [`Ok(Configuration)`] if the index is within
     /// the bounds of the [`Tape`], otherwise an [`Err(String)`]
     /// with diagnostic information.
-    pub fn new(tape: Tape<S>, index: usize, state: State) -> Result<Self, String> {
+    #[rustfmt::skip]
+    pub fn new(tape: T, index: usize, state: State) -> Result<Self, String> {
         match tape.len() > index {
-            true => Ok(Configuration { tape, index, state }),
+            true => Ok(Configuration { tape, index, state, symbol: PhantomData }),
             false => Err(format!(
                 "index out of bounds: the len is {} but the index is {}",
                 tape.len(),
@@ -40,7 +46,7 @@ impl<S: Symbol> Configuration<S> {
     ///
     /// Returns a new [`Ok(Configuration)`] if the [`Tape`] is not empty
     /// otherwise an [`Err(String)`] with diagnostic information.
-    pub fn new_nrm(tape: Tape<S>) -> Result<Self, String> {
+    pub fn new_nrm(tape: T) -> Result<Self, String> {
         Configuration::new(tape, 0, State(1))
     }
 
@@ -50,43 +56,105 @@ impl<S: Symbol> Configuration<S> {
     ///
     /// Returns a new [`Ok(Configuration)`] if the [`Tape`] is not empty
     /// otherwise an [`Err(String)`] with diagnostic information.
-    pub fn new_std(tape: Tape<S>) -> Result<Self, String> {
-        let last = tape.len() - 1;
-        Configuration::new(tape, last, State(1))
+    pub fn new_std(tape: T) -> Result<Self, String> {
+        match tape.len().checked_sub(1) {
+            Some(last) => Configuration::new(tape, last, State(1)),
+            None => Err(String::from(
+                "index out of bounds: the tape is empty so it has no last index",
+            )),
+        }
+    }
+
+    /// Constructs a new [`Configuration`] for the input word like
+    /// [`Configuration::new_std`] (when `std` is `true`) or
+    /// [`Configuration::new_nrm`] but the empty [`Tape`] (the empty word)
+    /// is extended by the single blank [`Symbol`] cell, so this constructor
+    /// never fails.
+    ///
+    /// The blank [`Symbol`] is set to the [`Tape`] when it's unknown
+    /// (see [`TapeStorage::set_blank`]), so [`Configuration::trimmed`]
+    /// returns the output word.
+    pub fn with_blank(mut tape: T, blank: S, std: bool) -> Self {
+        if tape.blank().is_none() {
+            tape.set_blank(blank.clone());
+        }
+        if tape.is_empty() {
+            tape.grow_right(blank);
+        }
+        let index = match std {
+            true => tape.len() - 1,
+            false => 0,
+        };
+        Configuration {
+            tape,
+            index,
+            state: State(1),
+            symbol: PhantomData,
+        }
     }
 
-    /// Destructs [`Configuration`] into `(Tape<S>, usize, State)`. May be used
+    /// Destructs [`Configuration`] into `(T, usize, State)`. May be used
     /// only with owned values.
-    pub fn destruct(self) -> (Tape<S>, usize, State) {
+    pub fn destruct(self) -> (T, usize, State) {
         (self.tape, self.index, self.state)
     }
 
     /// Returns the [`Tape`] reference of the [`Configuration`].
     ///
     /// Zero cost method.
-    pub fn tape(&self) -> &Tape<S> {
+    pub fn tape(&self) -> &T {
         &self.tape
     }
 
     /// Returns the [`Tape`] copy of the [`Configuration`].
-    pub fn into_tape(self) -> Tape<S> {
+    pub fn into_tape(self) -> T {
         self.tape
     }
 
-    /// Returns the current symbol reference. This reference is always exists.
+    /// Returns the current symbol reference. This reference is always exists
+    /// for correct [`TapeStorage`] realizations.
     ///
     /// # Panics
-    /// [`Configuration`] could panic only if source code is broken - this
-    /// would be a bug. [`Configuration`] controls its inner index so it always
-    /// valid.
-    ///
-    /// So you could open an issue on [GitHub](https://github.com/Helltraitor/turing-machine-rs).
+    /// [`Configuration`] controls its inner index so it always valid, but
+    /// [`Configuration`] panics when the [`TapeStorage`] realization returns
+    /// no symbol in its bounds. Use [`Configuration::try_get_symbol`]
+    /// for the fallible path (machines use it).
     pub fn get_symbol(&self) -> &S {
-        self.tape
-            .get(self.index)
+        self.try_get_symbol()
             .expect("get_symbol error: returned value must be Some because of bound checking")
     }
 
+    /// Returns the current symbol reference or [`Err(String)`] with
+    /// diagnostic information when the [`TapeStorage`] realization returns
+    /// no symbol at the index in its bounds.
+    pub fn try_get_symbol(&self) -> Result<&S, String> {
+        self.tape.get(self.index).ok_or_else(|| {
+            format!(
+                "get_symbol error: tape has no symbol at index {} but the len is {}",
+                self.index,
+                self.tape.len()
+            )
+        })
+    }
+
+    /// Returns the blank [`Symbol`] reference of the [`Tape`] if it's known
+    /// (see [`TapeStorage::blank`]).
+    pub fn blank(&self) -> Option<&S> {
+        self.tape.blank()
+    }
+
+    /// Returns indexes `(first, last)` (inclusive) of non-blank symbols
+    /// of the [`Tape`] (see [`TapeStorage::content_bounds`]).
+    pub fn content_bounds(&self) -> Option<(usize, usize)> {
+        self.tape.content_bounds()
+    }
+
+    /// Returns the output word: [`Tape`] symbols without blank symbols
+    /// at the edges (see [`TapeStorage::trimmed`]).
+    pub fn trimmed(&self) -> Vec<S> {
+        self.tape.trimmed()
+    }
+
     /// Returns the current [`Tape`] index. This value is always in tape bounds.
     pub fn index(&self) -> usize {
         self.index
@@ -120,25 +188,58 @@ impl<S: Symbol> Configuration<S> {
     /// Shifts the [`Tape`] to left or right if [`Move`] is [`Move::Left`]
     /// or [`Move::Right`], otherwise do nothing (when [`Move::None`]).
     /// If [`Configuration`] reachs the begin or the end of the [`Tape`]
-    /// then [`Tape`] extends by [`Tape::insert`] method, otherwise only
-    /// changes self index.
+    /// then [`Tape`] extends by [`TapeStorage::grow_left`] or
+    /// [`TapeStorage::grow_right`] methods, otherwise only changes self index.
     pub fn shift(&mut self, movement: Move, default: S) {
         match movement {
-            Move::Left if self.index == 0 => self.tape.insert(0, default),
+            Move::Left if self.index == 0 => self.tape.grow_left(default),
             Move::Left => self.index -= 1,
             Move::None => {}
             Move::Right => {
                 self.index += 1;
                 if self.index == self.tape.len() {
-                    self.tape.insert(self.index, default);
+                    self.tape.grow_right(default);
                 }
             }
         };
     }
+
+    /// Shifts the [`Tape`] like [`Configuration::shift`] but the [`Move::Left`]
+    /// from the first cell is resolved by the [`Boundary`] behaviour.
+    ///
+    /// Returns the actual [`Move`] of the head ([`Move::None`] when the head
+    /// stays on the first cell) or [`Err(String)`] with diagnostic information
+    /// when the head moves left from the first cell and the [`Boundary`] is
+    /// [`Boundary::Error`].
+    pub fn shift_bounded(
+        &mut self,
+        movement: Move,
+        default: S,
+        boundary: Boundary,
+    ) -> Result<Move, String> {
+        if movement != Move::Left || self.index != 0 {
+            self.shift(movement, default);
+            return Ok(movement);
+        }
+        match boundary.resolve(self.state)? {
+            true => {
+                self.tape.grow_left(default);
+                Ok(Move::Left)
+            }
+            false => Ok(Move::None),
+        }
+    }
 }
 
-impl<S: Symbol> Display for Configuration<S> {
+impl<S: Symbol, T: TapeStorage<S>> Display for Configuration<S, T> {
+    /// Writes the verbose [`Configuration`] form or the textbook form
+    /// (e.g. `01q₂101`) when the alternate flag is used (`{:#}`).
+    ///
+    /// For more rendering options use [`Renderer`].
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() {
+            return write!(f, "{}", Renderer::new(Style::Textbook).render(self));
+        }
         write!(
             f,
             "Configuration {{ Tape: \"{}\", Index: {}, State: {} }}",
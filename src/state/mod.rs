@@ -17,6 +17,18 @@
 //!
 //! So you could open an issue on [GitHub](https://github.com/Helltraitor/turing-machine-rs).
 //!
+//! [`Tape`] is the default [`TapeStorage`] realization. Any other storage
+//! can be used by [`Configuration`] and [`crate::machines::Classic`]
+//! when it implements the [`TapeStorage`] trait. For example, [`SparseTape`]
+//! stores only non-blank symbols.
+//!
+//! [`Configuration::shift`] grows the [`Tape`] to the left (the two-way
+//! infinite tape), [`Configuration::shift_bounded`] models the one-way tape
+//! by the [`Boundary`] behaviour.
+//!
+//! [`Renderer`] provides readable [`Configuration`] forms (e.g. textbook
+//! `01q₂101` form) for traces and debugging.
+//!
 //! [`Tape`] may panic in situations similar to those encountered by [`Vec`]
 //! (because the [`Tape`] type is based on [`Vec`]). It should be used only
 //! for creation and translation by a Turing machine.
@@ -34,7 +46,7 @@
 //! // For more comfortable coding, use Result<(), String>:
 //! // `?` postfix symbol is better then `.unwrap()` postfix method call.
 //! fn main() -> Result<(), String> {
-//!     let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', 'e', '_'];
+//!     let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', '_'];
 //!     let mut program = Program::new(alphabet, State(4));
 //!     program.extend([
 //!         (1, 't', 2, 'n', Move::Right),
@@ -61,8 +73,17 @@
 //!     Ok(())
 //! }
 //! ```
+mod boundary;
 mod configuration;
+mod render;
+mod sparse;
+mod storage;
 mod tape;
 
+pub use boundary::Boundary;
 pub use configuration::Configuration;
+pub use render::{Renderer, Style};
+pub use sparse::SparseTape;
+pub(crate) use storage::bounds;
+pub use storage::TapeStorage;
 pub use tape::Tape;
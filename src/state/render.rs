@@ -0,0 +1,179 @@
+use crate::state::{Configuration, TapeStorage};
+use crate::Symbol;
+
+const HEAD_COLOR: &str = "\x1b[7m";
+const STATE_COLOR: &str = "\x1b[1;33m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// [`Style`] is the layout of the [`Renderer`] output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
+    /// Classic textbook form where the state is written before the head
+    /// symbol, e.g. `01q₂101` for the state `2` and the index `2`.
+    Textbook,
+    /// Two lines form: the tape and the caret line which underlines
+    /// the head symbol and contains the state, e.g. `01101\n  ^ q₂`.
+    Caret,
+}
+
+/// [`Renderer`] is the configurable [`Configuration`] and [`TapeStorage`]
+/// formatter. It's useful for long traces, e.g. in [`crate::machines::Debugger`]
+/// handlers (the [`Configuration`] `Display` is too verbose for this).
+///
+/// [`Renderer`] can be configured by:
+/// - [`Renderer::set_window`] - shows only cells around the head;
+/// - [`Renderer::set_blank`] - trims blank symbols at the edges of the tape;
+/// - [`Renderer::set_colored`] - highlights the head and the state by ANSI colors.
+///
+/// # Examples
+/// ```rust
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::{Classic, Debugger};
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::{Renderer, Style, Tape};
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Right)])?;
+///     let mut debugger = Debugger::new(Classic::new(program, '0')?);
+///
+///     let mut renderer = Renderer::new(Style::Textbook);
+///     renderer.set_blank('0');
+///
+///     let buffer = Rc::new(RefCell::new(Vec::new()));
+///     let c_buffer = buffer.clone();
+///     debugger.set_c_handler(move |conf| c_buffer.borrow_mut().push(renderer.render(conf)));
+///
+///     debugger.translate_nrm(Tape::from("11"))?;
+///
+///     assert_eq!(vec!["q₁11", "q₁1", "q₁0"], *buffer.borrow());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Renderer<S: Symbol> {
+    style: Style,
+    window: Option<usize>,
+    blank: Option<S>,
+    colored: bool,
+}
+
+impl<S: Symbol> Renderer<S> {
+    /// Constructs a new [`Renderer`] with the [`Style`] which shows
+    /// the whole tape without colors.
+    pub fn new(style: Style) -> Self {
+        Renderer {
+            style,
+            window: None,
+            blank: None,
+            colored: false,
+        }
+    }
+
+    /// Sets the blank [`Symbol`]. Blank symbols at the edges of the tape
+    /// are not rendered (except the head symbol).
+    ///
+    /// This function is not permanent so the blank symbol can be changed.
+    pub fn set_blank(&mut self, blank: S) {
+        self.blank = Some(blank);
+    }
+
+    /// Sets the ANSI colors usage. When colors are used, the head symbol
+    /// is rendered inverted and the state is rendered bold.
+    ///
+    /// This function is not permanent so colors can be changed.
+    pub fn set_colored(&mut self, colored: bool) {
+        self.colored = colored;
+    }
+
+    /// Sets the [`Style`] of the output.
+    ///
+    /// This function is not permanent so style can be changed.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Sets the window radius: only `radius` cells to the left and to
+    /// the right of the head are rendered. [`Option::None`] means that
+    /// the whole tape is rendered.
+    ///
+    /// This function is not permanent so window can be changed.
+    pub fn set_window(&mut self, radius: Option<usize>) {
+        self.window = radius;
+    }
+
+    /// Renders the [`Configuration`] according to the [`Renderer`] settings.
+    pub fn render<T: TapeStorage<S>>(&self, conf: &Configuration<S, T>) -> String {
+        let cells: Vec<String> = conf.tape().iter().map(|s| s.to_string()).collect();
+        let index = conf.index();
+        let (begin, end) = self.bounds(conf.tape(), Some(index));
+
+        let mut left = cells[begin..index].concat();
+        let mut head = cells[index].clone();
+        let right = cells[index + 1..end].concat();
+        let mut state = format!("q{}", subscript(conf.state.0));
+
+        let caret = format!(
+            "{}{}",
+            " ".repeat(left.chars().count()),
+            "^".repeat(head.chars().count().max(1))
+        );
+        if self.colored {
+            head = format!("{}{}{}", HEAD_COLOR, head, RESET_COLOR);
+            state = format!("{}{}{}", STATE_COLOR, state, RESET_COLOR);
+        }
+        match self.style {
+            Style::Textbook => {
+                left.push_str(&state);
+                format!("{}{}{}", left, head, right)
+            }
+            Style::Caret => format!("{}{}{}\n{} {}", left, head, right, caret, state),
+        }
+    }
+
+    /// Renders the [`TapeStorage`] without the head according to
+    /// the [`Renderer`] settings (the window is ignored).
+    pub fn render_tape<T: TapeStorage<S>>(&self, tape: &T) -> String {
+        let (begin, end) = self.bounds(tape, None);
+        tape.iter()
+            .skip(begin)
+            .take(end.saturating_sub(begin))
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Returns the half-open range `(begin, end)` of rendered cells.
+    fn bounds<T: TapeStorage<S>>(&self, tape: &T, head: Option<usize>) -> (usize, usize) {
+        let (mut begin, mut end) = (0, tape.len());
+        if let Some(ref blank) = self.blank {
+            let filled: Vec<usize> = tape
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| *s != blank)
+                .map(|(index, _)| index)
+                .chain(head)
+                .collect();
+            begin = filled.iter().copied().min().unwrap_or(0);
+            end = filled.iter().copied().max().map_or(0, |last| last + 1);
+        }
+        if let (Some(radius), Some(index)) = (self.window, head) {
+            begin = begin.max(index.saturating_sub(radius));
+            end = end.min(index.saturating_add(radius).saturating_add(1));
+        }
+        (begin, end)
+    }
+}
+
+/// Returns the number written by subscript digits.
+fn subscript(number: usize) -> String {
+    const DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+    number
+        .to_string()
+        .chars()
+        .map(|digit| DIGITS[digit as usize - '0' as usize])
+        .collect()
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Error, Formatter};
+
+use crate::state::TapeStorage;
+use crate::Symbol;
+
+/// [`SparseTape`] is a [`TapeStorage`] realization which stores only
+/// non-blank symbols keyed by their signed position. The position `0`
+/// is the first symbol of the initial tape, so cells at the left side
+/// of the initial tape have negative positions.
+///
+/// Unlike the [`crate::state::Tape`], the gap between far-apart symbols
+/// is not filled by blank symbols in the memory, that's why this tape
+/// is useful for machines that write a few symbols far from the origin.
+///
+/// # Examples
+/// ```rust
+/// use turing_machine_rs::instruction::{Move, State};
+/// use turing_machine_rs::machines::Classic;
+/// use turing_machine_rs::program::{Extend, Program};
+/// use turing_machine_rs::state::SparseTape;
+/// use turing_machine_rs::TuringMachine;
+///
+/// fn main() -> Result<(), String> {
+///     let mut program = Program::new(vec!['0', '1'], State(1));
+///     program.extend([(1, '0', 0, '1', Move::None), (1, '1', 1, '1', Move::Left)])?;
+///     let machine: Classic<char, SparseTape<char>> = Classic::for_storage(program, '0')?;
+///
+///     let tape = SparseTape::from_symbols('0', "11".chars());
+///     let result = machine.translate_nrm(tape)?;
+///
+///     assert_eq!(Some((-1, 1)), result.bounds());
+///     assert_eq!("111", result.to_string());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SparseTape<S: Symbol> {
+    cells: HashMap<isize, S>,
+    blank: S,
+    left: isize,
+    len: usize,
+}
+
+impl<S: Symbol> SparseTape<S> {
+    /// Constructs a new empty [`SparseTape`] with the blank [`Symbol`].
+    pub fn new(blank: S) -> Self {
+        SparseTape {
+            cells: HashMap::new(),
+            blank,
+            left: 0,
+            len: 0,
+        }
+    }
+
+    /// Constructs a new [`SparseTape`] with the blank [`Symbol`] from
+    /// [`IntoIterator`] object. Symbols are placed from the position `0`.
+    pub fn from_symbols(blank: S, symbols: impl IntoIterator<Item = S>) -> Self {
+        let mut tape = SparseTape::new(blank);
+        for symbol in symbols {
+            tape.grow_right(symbol);
+        }
+        tape
+    }
+
+    /// Returns the blank [`Symbol`] reference.
+    ///
+    /// Zero cost method.
+    pub fn blank(&self) -> &S {
+        &self.blank
+    }
+
+    /// Returns the bounding box `(left, right)` (inclusive) of non-blank
+    /// symbols positions or [`Option::None`] when the [`SparseTape`]
+    /// contains only blank symbols.
+    pub fn bounds(&self) -> Option<(isize, isize)> {
+        let left = *self.cells.keys().min()?;
+        let right = *self.cells.keys().max()?;
+        Some((left, right))
+    }
+
+    /// Returns the [`Symbol`] reference at the position. Positions
+    /// out of the [`SparseTape`] range contain the blank symbol.
+    pub fn get_at(&self, position: isize) -> &S {
+        self.cells.get(&position).unwrap_or(&self.blank)
+    }
+
+    /// Returns the position of the index. The index `0` corresponds
+    /// to the leftmost visited position.
+    pub fn position(&self, index: usize) -> isize {
+        self.left + index as isize
+    }
+
+    /// Returns the positions range `(left, right)` (inclusive) of
+    /// the [`SparseTape`] or [`Option::None`] when the [`SparseTape`] is empty.
+    pub fn range(&self) -> Option<(isize, isize)> {
+        match self.len {
+            0 => None,
+            len => Some((self.left, self.left + len as isize - 1)),
+        }
+    }
+
+    /// Sets the [`Symbol`] at the position. When the position is out
+    /// of the [`SparseTape`] range, the range extends to the position
+    /// without storing blank symbols between.
+    pub fn set_at(&mut self, position: isize, symbol: S) {
+        match self.range() {
+            None => {
+                self.left = position;
+                self.len = 1;
+            }
+            Some((left, _)) if position < left => {
+                self.len += (left - position) as usize;
+                self.left = position;
+            }
+            Some((_, right)) if right < position => {
+                self.len += (position - right) as usize;
+            }
+            Some(_) => {}
+        }
+        match symbol == self.blank {
+            true => self.cells.remove(&position),
+            false => self.cells.insert(position, symbol),
+        };
+    }
+}
+
+impl<S: Symbol> TapeStorage<S> for SparseTape<S> {
+    fn get(&self, index: usize) -> Option<&S> {
+        match index < self.len {
+            true => Some(self.get_at(self.position(index))),
+            false => None,
+        }
+    }
+
+    /// Sets the [`Symbol`] at the index position.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    fn set(&mut self, index: usize, symbol: S) {
+        assert!(
+            index < self.len,
+            "set error: index out of bounds: the len is {} but the index is {}",
+            self.len,
+            index
+        );
+        self.set_at(self.position(index), symbol);
+    }
+
+    fn grow_left(&mut self, symbol: S) {
+        match self.len {
+            0 => self.set_at(self.left, symbol),
+            _ => self.set_at(self.left - 1, symbol),
+        }
+    }
+
+    fn grow_right(&mut self, symbol: S) {
+        self.set_at(self.position(self.len), symbol);
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &S> + '_> {
+        Box::new((0..self.len).map(move |index| self.get_at(self.position(index))))
+    }
+
+    fn blank(&self) -> Option<&S> {
+        Some(&self.blank)
+    }
+
+    /// Returns indexes of [`SparseTape::bounds`] (only non-blank symbols
+    /// are stored, so the tape is not iterated).
+    fn content_bounds(&self) -> Option<(usize, usize)> {
+        let (left, right) = self.bounds()?;
+        Some(((left - self.left) as usize, (right - self.left) as usize))
+    }
+}
+
+impl<S: Symbol> PartialEq for SparseTape<S> {
+    /// Compares symbols of tapes at every position (the blank symbols
+    /// and non-blank symbols) and the position of the index `0`, so equal
+    /// indexes of equal tapes are at the same position. Right ends
+    /// of visited ranges are not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.blank == other.blank && self.left == other.left && self.cells == other.cells
+    }
+}
+
+impl<S: Symbol> Eq for SparseTape<S> {}
+
+impl<S: Symbol> Display for SparseTape<S> {
+    /// Writes symbols only between the leftmost and the rightmost
+    /// non-blank symbols (blank symbols at the edges are trimmed).
+    ///
+    /// The output starts at the position `bounds().0`, not at the index `0`,
+    /// so the symbol at the index is the output character
+    /// `position(index) - bounds().0` (see [`SparseTape::bounds`]
+    /// and [`SparseTape::position`]).
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if let Some((left, right)) = self.bounds() {
+            for position in left..=right {
+                write!(f, "{}", self.get_at(position))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{Debug, Display};
+
+use crate::Symbol;
+
+/// [`TapeStorage`] is the backend of the [`crate::state::Configuration`] tape.
+/// The [`crate::state::Tape`] type is the default [`Vec`]-based realization,
+/// but any other storage (sparse, run-length, instrumented, etc.) can be used
+/// by [`crate::state::Configuration`] and [`crate::machines::Classic`]
+/// when it implements this trait.
+///
+/// Storage is indexed from `0` to `len() - 1` and can grow only at the edges
+/// (symbols cannot be removed, only replaced).
+///
+/// Storages are compared by `==` by their symbols only: bookkeeping like
+/// the blank annotation of the [`crate::state::Tape`] or the visited range
+/// of the [`crate::state::SparseTape`] is not compared (the blank symbol
+/// of the [`crate::state::SparseTape`] is the symbol of every unset
+/// position, so it's compared as a symbol).
+pub trait TapeStorage<S: Symbol>: Clone + Debug + Display + Eq + PartialEq {
+    /// Returns [`Option::Some`] when the index is in the storage bounds,
+    /// otherwise [`Option::None`].
+    fn get(&self, index: usize) -> Option<&S>;
+
+    /// Sets the [`Symbol`] at the index position.
+    ///
+    /// # Panics
+    /// Storage may panic if the index is out of bounds.
+    fn set(&mut self, index: usize, symbol: S);
+
+    /// Extends the storage by the [`Symbol`] at the begin. After this call
+    /// all indexes are shifted by `1`.
+    fn grow_left(&mut self, symbol: S);
+
+    /// Extends the storage by the [`Symbol`] at the end.
+    fn grow_right(&mut self, symbol: S);
+
+    /// Returns the storage length.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the storage contains no symbols, otherwise `false`.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over all symbols from the begin to the end.
+    fn iter(&self) -> Box<dyn Iterator<Item = &S> + '_>;
+
+    /// Returns the blank [`Symbol`] reference when the storage knows it,
+    /// otherwise [`Option::None`] (by default).
+    fn blank(&self) -> Option<&S> {
+        None
+    }
+
+    /// Sets the blank [`Symbol`] for storages which keep the blank
+    /// annotation (e.g. [`crate::state::Tape`]). Other storages ignore it
+    /// by default.
+    fn set_blank(&mut self, blank: S) {
+        let _ = blank;
+    }
+
+    /// Returns indexes `(first, last)` (inclusive) of the first and the last
+    /// non-blank symbols or [`Option::None`] when the storage contains only
+    /// blank symbols. When the blank is unknown, all symbols are the content.
+    fn content_bounds(&self) -> Option<(usize, usize)> {
+        match self.blank() {
+            Some(blank) => bounds(self.iter(), blank),
+            None => self.len().checked_sub(1).map(|last| (0, last)),
+        }
+    }
+
+    /// Returns symbols in [`TapeStorage::content_bounds`] (the output word),
+    /// i.e. symbols without blank symbols at the edges.
+    fn trimmed(&self) -> Vec<S> {
+        match self.content_bounds() {
+            Some((first, last)) => self
+                .iter()
+                .skip(first)
+                .take(last - first + 1)
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns `true` when [`TapeStorage::trimmed`] symbols of both storages
+    /// are equal (blank padding is ignored), otherwise `false`.
+    fn content_eq<U: TapeStorage<S>>(&self, other: &U) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+/// Returns indexes `(first, last)` (inclusive) of symbols which are not
+/// the blank [`Symbol`].
+pub(crate) fn bounds<'a, S: Symbol + 'a>(
+    symbols: impl Iterator<Item = &'a S>,
+    blank: &S,
+) -> Option<(usize, usize)> {
+    let mut bounds = None;
+    for (index, symbol) in symbols.enumerate() {
+        if symbol != blank {
+            bounds = Some((bounds.map_or(index, |(first, _)| first), index));
+        }
+    }
+    bounds
+}
@@ -1,6 +1,7 @@
 use std::fmt::{Display, Error, Formatter};
 use std::iter::FromIterator;
 
+use crate::state::TapeStorage;
 use crate::Symbol;
 
 /// [`Tape`] type is the main part of the [`crate::state::Configuration`]
@@ -9,16 +10,43 @@ use crate::Symbol;
 /// [`Tape`] can be created with the [`Tape::new`] method and [`IntoIterator`]
 /// object or with the [`Tape::from`] method as a [`Tape<char>`] but only
 /// for [`str`] and [`String`] types.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// [`Tape`] can know its blank symbol (see [`Tape::with_blank`]), then
+/// [`TapeStorage::trimmed`] returns the output word without blank symbols
+/// at the edges and [`TapeStorage::content_eq`] compares tapes ignoring
+/// the blank padding. The blank symbol is not compared by `==`.
+#[derive(Clone, Debug)]
 pub struct Tape<S: Symbol> {
     tape: Vec<S>,
+    blank: Option<S>,
 }
 
 impl<S: Symbol> Tape<S> {
     /// Constructs a new [`Tape`] from [`IntoIterator`] object.
     #[rustfmt::skip]
     pub fn new(symbols: impl IntoIterator<Item = S>) -> Self {
-        Tape { tape: Vec::from_iter(symbols) }
+        Tape { tape: Vec::from_iter(symbols), blank: None }
+    }
+
+    /// Constructs a new [`Tape`] with the blank [`Symbol`] from
+    /// [`IntoIterator`] object.
+    #[rustfmt::skip]
+    pub fn with_blank(blank: S, symbols: impl IntoIterator<Item = S>) -> Self {
+        Tape { tape: Vec::from_iter(symbols), blank: Some(blank) }
+    }
+
+    /// Returns the blank [`Symbol`] reference if it's set.
+    ///
+    /// Zero cost method.
+    pub fn blank(&self) -> Option<&S> {
+        self.blank.as_ref()
+    }
+
+    /// Sets the blank [`Symbol`] of the [`Tape`].
+    ///
+    /// This function is not permanent so the blank symbol can be changed.
+    pub fn set_blank(&mut self, blank: S) {
+        self.blank = Some(blank);
     }
 
     /// Returns an immutable [`Vec`] reference to the inner container.
@@ -68,9 +96,61 @@ impl<S: Symbol> Tape<S> {
     }
 }
 
+impl<S: Symbol> TapeStorage<S> for Tape<S> {
+    fn get(&self, index: usize) -> Option<&S> {
+        self.tape.get(index)
+    }
+
+    fn set(&mut self, index: usize, symbol: S) {
+        self.tape[index] = symbol;
+    }
+
+    fn grow_left(&mut self, symbol: S) {
+        self.tape.insert(0, symbol);
+    }
+
+    fn grow_right(&mut self, symbol: S) {
+        self.tape.push(symbol);
+    }
+
+    fn len(&self) -> usize {
+        self.tape.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &S> + '_> {
+        Box::new(self.tape.iter())
+    }
+
+    fn blank(&self) -> Option<&S> {
+        self.blank.as_ref()
+    }
+
+    fn set_blank(&mut self, blank: S) {
+        self.blank = Some(blank);
+    }
+}
+
+impl<S: Symbol> PartialEq for Tape<S> {
+    /// Compares symbols of tapes (blank symbols are not compared).
+    fn eq(&self, other: &Self) -> bool {
+        self.tape == other.tape
+    }
+}
+
+impl<S: Symbol> Eq for Tape<S> {}
+
 impl<S: Symbol> Display for Tape<S> {
+    /// Writes symbols one by one or separated by `|` when the alternate
+    /// flag is used (`{:#}`), e.g. `|0|1|1|`.
     #[rustfmt::skip]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if f.alternate() {
+            write!(f, "|")?;
+            for symbol in &self.tape {
+                write!(f, "{}|", symbol)?;
+            }
+            return Ok(());
+        }
         write!(f, "{}", &self.tape.clone()
                                   .into_iter()
                                   .map(|s| s.to_string())
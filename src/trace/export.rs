@@ -0,0 +1,203 @@
+use crate::instruction::{Event, Move};
+use crate::trace::Trace;
+use crate::Symbol;
+
+impl<S: Symbol> Trace<S> {
+    /// Exports the [`Trace`] to JSON Lines. The first line is the header with
+    /// the initial tape, index and state, e.g.
+    /// `{"tape":["0","1"],"index":0,"state":1}`, and others are steps, e.g.
+    /// `{"step":1,"position":0,"state":1,"symbol":"0","next":2,"write":"1","move":">"}`.
+    ///
+    /// Steps which are not plain applied instructions have the `event` field:
+    /// `"bounded"` when the head stays on the first cell instead of the move
+    /// and `"uncovered"` for heads without instructions (such steps have
+    /// no `write` and `move` fields).
+    ///
+    /// Symbols are written by their [`std::fmt::Display`] implementation.
+    pub fn to_jsonl(&self) -> String {
+        let tape: Vec<String> = self.tape.iter().map(|s| json_string(s)).collect();
+        let mut jsonl = format!(
+            "{{\"tape\":[{}],\"index\":{},\"state\":{}}}\n",
+            tape.join(","),
+            self.index,
+            self.state
+        );
+        for (number, step) in self.steps.iter().enumerate() {
+            let head = step.event.head();
+            jsonl.push_str(&format!(
+                "{{\"step\":{},\"position\":{},\"state\":{},\"symbol\":{},\"next\":{}",
+                number + 1,
+                step.position,
+                head.state,
+                json_string(&head.symbol),
+                step.event.state(),
+            ));
+            if let Some(inst) = step.event.instruction() {
+                jsonl.push_str(&format!(
+                    ",\"write\":{},\"move\":\"{}\"",
+                    json_string(&inst.tail.symbol),
+                    inst.tail.movement
+                ));
+            }
+            match event_kind(&step.event) {
+                "applied" => jsonl.push_str("}\n"),
+                kind => jsonl.push_str(&format!(",\"event\":\"{}\"}}\n", kind)),
+            }
+        }
+        jsonl
+    }
+
+    /// Exports [`crate::trace::Step`]s to CSV with the header
+    /// `step,position,state,symbol,next,write,move,event`. The `event`
+    /// is `applied`, `bounded` or `uncovered` (see [`Trace::to_jsonl`]),
+    /// `write` and `move` are empty for `uncovered` steps.
+    ///
+    /// Symbols are written by their [`std::fmt::Display`] implementation.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,position,state,symbol,next,write,move,event\n");
+        for (number, step) in self.steps.iter().enumerate() {
+            let head = step.event.head();
+            let (write, movement) = match step.event.instruction() {
+                Some(inst) => (csv_field(&inst.tail.symbol), inst.tail.movement.to_string()),
+                None => (String::new(), String::new()),
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{}\n",
+                number + 1,
+                step.position,
+                head.state,
+                csv_field(&head.symbol),
+                step.event.state(),
+                write,
+                movement,
+                event_kind(&step.event)
+            ));
+        }
+        csv
+    }
+
+    /// Exports the [`Trace`] to the space-time diagram: one row per
+    /// configuration (the initial one and after each step) with the step
+    /// number, the state and all visited cells. The head cell is marked
+    /// by brackets, e.g. ` 1  2 | 0 [1] 0 |`.
+    pub fn to_diagram(&self) -> String {
+        let (left, mut cells) = self.cells();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|s| s.to_string().chars().count());
+        let width = width.max().unwrap_or(1);
+        let states = self.steps.iter().map(|step| step.event.state());
+        let s_width = states
+            .chain(Some(self.state))
+            .map(|state| state.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let n_width = self.steps.len().to_string().len();
+
+        let mut diagram = String::new();
+        let mut position = self.index as isize;
+        let mut state = self.state;
+        for number in 0..=self.steps.len() {
+            diagram.push_str(&format!(
+                "{:>n$} {:>s$} |",
+                number,
+                state,
+                n = n_width,
+                s = s_width
+            ));
+            for (cell, symbol) in cells.iter().enumerate() {
+                let symbol = symbol.as_ref().map(|s| s.to_string()).unwrap_or_default();
+                let symbol = format!("{:^w$}", symbol, w = width);
+                match cell as isize + left == position {
+                    true => diagram.push_str(&format!("[{}]", symbol)),
+                    false => diagram.push_str(&format!(" {} ", symbol)),
+                }
+            }
+            diagram.push_str("|\n");
+
+            if let Some(step) = self.steps.get(number) {
+                cells[(step.position - left) as usize] = Some(step.event.symbol().clone());
+                state = step.event.state();
+                position = step.position + movement_delta(step.event.movement());
+            }
+        }
+        diagram
+    }
+
+    /// Returns the leftmost visited position and the initial symbols of all
+    /// visited cells. Cells out of the initial tape get the first read symbol
+    /// and cells that are reached but never read are unknown.
+    fn cells(&self) -> (isize, Vec<Option<S>>) {
+        let mut left = 0;
+        let mut cells: Vec<Option<S>> = self.tape.iter().cloned().map(Some).collect();
+        if cells.is_empty() {
+            cells.push(None);
+        }
+        for step in &self.steps {
+            let next = step.position + movement_delta(step.event.movement());
+            for position in [step.position, next] {
+                while position < left {
+                    cells.insert(0, None);
+                    left -= 1;
+                }
+                while position >= left + cells.len() as isize {
+                    cells.push(None);
+                }
+            }
+            let cell = &mut cells[(step.position - left) as usize];
+            if cell.is_none() {
+                *cell = Some(step.event.head().symbol.clone());
+            }
+        }
+        (left, cells)
+    }
+}
+
+/// Returns the position change for the [`Move`].
+pub(crate) fn movement_delta(movement: Move) -> isize {
+    match movement {
+        Move::Left => -1,
+        Move::None => 0,
+        Move::Right => 1,
+    }
+}
+
+/// Returns the name of the [`Event`] kind which is used by exports.
+fn event_kind<S: Symbol>(event: &Event<S>) -> &'static str {
+    match event {
+        Event::Applied {
+            instruction,
+            movement,
+        } if *movement != instruction.tail.movement => "bounded",
+        Event::Applied { .. } => "applied",
+        Event::Uncovered { .. } => "uncovered",
+    }
+}
+
+/// Returns the quoted and escaped JSON string of the symbol.
+fn json_string<S: Symbol>(symbol: &S) -> String {
+    let mut json = String::from("\"");
+    for ch in symbol.to_string().chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Returns the CSV field of the symbol which is quoted only when necessary.
+fn csv_field<S: Symbol>(symbol: &S) -> String {
+    let field = symbol.to_string();
+    match field.contains(|ch| matches!(ch, ',' | '"' | '\n' | '\r')) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field,
+    }
+}
@@ -0,0 +1,269 @@
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use crate::instruction::{Event, Head, Instruction, Move, State};
+use crate::trace::export::movement_delta;
+use crate::trace::{Step, Trace};
+use crate::Symbol;
+
+/// JSON value which can be met in the [`Trace`] JSON Lines.
+enum Value {
+    Number(String),
+    Text(String),
+    List(Vec<String>),
+}
+
+impl<S: Symbol + FromStr> Trace<S> {
+    /// Imports the [`Trace`] from JSON Lines exported by [`Trace::to_jsonl`].
+    /// Symbols are parsed by their [`FromStr`] implementation.
+    ///
+    /// Returns [`Ok(Trace)`] when the text is valid, otherwise [`Err(String)`]
+    /// with diagnostic information.
+    pub fn from_jsonl(text: &str) -> Result<Self, String> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| String::from("import error: trace header is missing"))?;
+
+        let header =
+            parse_object(header).map_err(|msg| format!("import error: line 1: {}", msg))?;
+        let tape = match field(&header, "tape") {
+            Some(Value::List(symbols)) => symbols
+                .iter()
+                .map(|symbol| parse_symbol(symbol))
+                .collect::<Result<Vec<S>, String>>(),
+            _ => Err(String::from("field tape must be a list")),
+        };
+        let tape = tape.map_err(|msg| format!("import error: line 1: {}", msg))?;
+        let (index, state) = parse_header(&header, tape.len())
+            .map_err(|msg| format!("import error: line 1: {}", msg))?;
+
+        let mut steps = Vec::new();
+        for (line, text) in lines {
+            let step = parse_step(text)
+                .map_err(|msg| format!("import error: line {}: {}", line + 1, msg))?;
+            steps.push(step);
+        }
+        // Left shift is the count of cells grown at the left side of the initial tape
+        let shift = steps
+            .iter()
+            .map(|step: &Step<S>| step.position + movement_delta(step.event.movement()))
+            .min()
+            .map_or(0, |left| (-left).max(0) as usize);
+        Ok(Trace {
+            tape,
+            index,
+            state,
+            steps,
+            shift,
+        })
+    }
+}
+
+/// Parses the index and the [`State`] of the header, the index must be
+/// in the tape bounds.
+fn parse_header(object: &[(String, Value)], len: usize) -> Result<(usize, State), String> {
+    let index = number(object, "index")?;
+    let state = number(object, "state")?;
+    if index < 0 || state < 0 {
+        return Err(String::from("index and state cannot be negative"));
+    }
+    if len <= index as usize {
+        return Err(format!(
+            "index out of bounds: the len is {} but the index is {}",
+            len, index
+        ));
+    }
+    Ok((index as usize, State(state as usize)))
+}
+
+/// Parses the [`Step`] from the JSON object line.
+fn parse_step<S: Symbol + FromStr>(line: &str) -> Result<Step<S>, String> {
+    let object = parse_object(line)?;
+    let position = number(&object, "position")?;
+    let h_state = number(&object, "state")?;
+    let t_state = number(&object, "next")?;
+    let h_symbol = parse_symbol(text(&object, "symbol")?)?;
+    if h_state < 0 || t_state < 0 {
+        return Err(String::from("states cannot be negative"));
+    }
+    let kind = match field(&object, "event") {
+        None => "applied",
+        Some(_) => text(&object, "event")?,
+    };
+    if kind == "uncovered" {
+        let event = Event::Uncovered {
+            head: Head::new(State(h_state as usize), h_symbol),
+            state: State(t_state as usize),
+        };
+        return Ok(Step {
+            event,
+            position: position as isize,
+        });
+    }
+    let t_symbol = parse_symbol(text(&object, "write")?)?;
+    let movement = match text(&object, "move")? {
+        "<" => Move::Left,
+        "-" => Move::None,
+        ">" => Move::Right,
+        other => return Err(format!("unknown move {}", other)),
+    };
+    let actual = match kind {
+        "applied" => movement,
+        "bounded" if movement == Move::Left => Move::None,
+        "bounded" => return Err(format!("bounded step cannot move {}", movement)),
+        other => return Err(format!("unknown event {}", other)),
+    };
+    let event = Event::Applied {
+        instruction: Instruction::build(
+            State(h_state as usize),
+            h_symbol,
+            State(t_state as usize),
+            t_symbol,
+            movement,
+        ),
+        movement: actual,
+    };
+    Ok(Step {
+        event,
+        position: position as isize,
+    })
+}
+
+fn parse_symbol<S: FromStr>(symbol: &str) -> Result<S, String> {
+    S::from_str(symbol).map_err(|_| format!("cannot parse symbol {:?}", symbol))
+}
+
+fn field<'a>(object: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+    object
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+}
+
+fn number(object: &[(String, Value)], name: &str) -> Result<i64, String> {
+    match field(object, name) {
+        Some(Value::Number(number)) => number
+            .parse()
+            .map_err(|_| format!("field {} must be an integer", name)),
+        _ => Err(format!("field {} must be a number", name)),
+    }
+}
+
+fn text<'a>(object: &'a [(String, Value)], name: &str) -> Result<&'a str, String> {
+    match field(object, name) {
+        Some(Value::Text(text)) => Ok(text),
+        _ => Err(format!("field {} must be a string", name)),
+    }
+}
+
+/// Parses the flat JSON object which values are numbers, strings
+/// or lists of strings.
+fn parse_object(line: &str) -> Result<Vec<(String, Value)>, String> {
+    let mut chars = line.trim().chars().peekable();
+    let mut object = Vec::new();
+    expect(&mut chars, '{')?;
+    if skip_spaces(&mut chars) == Some('}') {
+        chars.next();
+    } else {
+        loop {
+            skip_spaces(&mut chars);
+            let key = parse_string(&mut chars)?;
+            expect(&mut chars, ':')?;
+            let value = match skip_spaces(&mut chars) {
+                Some('"') => Value::Text(parse_string(&mut chars)?),
+                Some('[') => Value::List(parse_list(&mut chars)?),
+                Some(ch) if ch == '-' || ch.is_ascii_digit() => {
+                    let mut number = String::new();
+                    while let Some(ch) = chars.next_if(|ch| *ch == '-' || ch.is_ascii_digit()) {
+                        number.push(ch);
+                    }
+                    Value::Number(number)
+                }
+                _ => return Err(format!("unexpected value for key {}", key)),
+            };
+            object.push((key, value));
+            match skip_spaces(&mut chars) {
+                Some(',') => chars.next(),
+                Some('}') => {
+                    chars.next();
+                    break;
+                }
+                _ => return Err(String::from("expected , or }")),
+            };
+        }
+    }
+    match skip_spaces(&mut chars) {
+        None => Ok(object),
+        Some(_) => Err(String::from("unexpected symbols after object")),
+    }
+}
+
+fn parse_list(chars: &mut Peekable<Chars>) -> Result<Vec<String>, String> {
+    let mut list = Vec::new();
+    expect(chars, '[')?;
+    if skip_spaces(chars) == Some(']') {
+        chars.next();
+        return Ok(list);
+    }
+    loop {
+        skip_spaces(chars);
+        list.push(parse_string(chars)?);
+        match skip_spaces(chars) {
+            Some(',') => chars.next(),
+            Some(']') => {
+                chars.next();
+                return Ok(list);
+            }
+            _ => return Err(String::from("expected , or ]")),
+        };
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    let mut string = String::new();
+    expect(chars, '"')?;
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(string),
+            Some('\\') => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('r') => string.push('\r'),
+                Some('t') => string.push('\t'),
+                Some('b') => string.push('\u{8}'),
+                Some('f') => string.push('\u{c}'),
+                Some('u') => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&code, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| format!("invalid escape \\u{}", code))?;
+                    string.push(code);
+                }
+                Some(ch) if matches!(ch, '"' | '\\' | '/') => string.push(ch),
+                _ => return Err(String::from("invalid escape")),
+            },
+            Some(ch) => string.push(ch),
+            None => return Err(String::from("unterminated string")),
+        }
+    }
+}
+
+fn expect(chars: &mut Peekable<Chars>, expected: char) -> Result<(), String> {
+    match skip_spaces(chars) {
+        Some(ch) if ch == expected => {
+            chars.next();
+            Ok(())
+        }
+        _ => Err(format!("expected {}", expected)),
+    }
+}
+
+/// Skips whitespaces and returns the next symbol without consuming.
+fn skip_spaces(chars: &mut Peekable<Chars>) -> Option<char> {
+    while chars.next_if(|ch| ch.is_whitespace()).is_some() {}
+    chars.peek().copied()
+}
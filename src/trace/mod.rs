@@ -0,0 +1,229 @@
+//! Provides [`Trace`] and it's component [`Step`] which record
+//! the execution of a Turing machine.
+//!
+//! [`Trace`] is recorded by the [`crate::machines::Tracer`] machine and can be
+//! exported to JSON Lines ([`Trace::to_jsonl`]), CSV ([`Trace::to_csv`])
+//! or a space-time diagram text ([`Trace::to_diagram`]). Traces exported
+//! to JSON Lines can be imported back by [`Trace::from_jsonl`] and verified
+//! against a [`crate::program::Program`] by [`Trace::verify`].
+//!
+//! # Examples
+//! ```rust
+//! use turing_machine_rs::instruction::{Move, State};
+//! use turing_machine_rs::machines::{Classic, Tracer};
+//! use turing_machine_rs::program::{Extend, Program};
+//! use turing_machine_rs::state::Tape;
+//! use turing_machine_rs::trace::Trace;
+//! use turing_machine_rs::TuringMachine;
+//!
+//! fn main() -> Result<(), String> {
+//!     let mut program = Program::new(vec!['0', '1'], State(1));
+//!     program.extend([(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Right)])?;
+//!     let tracer = Tracer::new(Classic::new(program.clone(), '0')?);
+//!
+//!     tracer.translate_nrm(Tape::from("11"))?;
+//!     let trace = tracer.take_trace().unwrap();
+//!     assert_eq!(3, trace.len());
+//!
+//!     let imported: Trace<char> = Trace::from_jsonl(&trace.to_jsonl())?;
+//!     assert_eq!(trace, imported);
+//!     imported.verify(&program)?;
+//!     Ok(())
+//! }
+//! ```
+
+mod export;
+mod import;
+
+use self::export::movement_delta;
+use crate::instruction::{Event, Move, State};
+use crate::program::Program;
+use crate::state::{Configuration, TapeStorage};
+use crate::Symbol;
+
+/// [`Step`] is a component of the [`Trace`]. It contains the [`Event`]
+/// of the machine and the head position.
+///
+/// The tape delta of the step is the replacement of the
+/// [`crate::instruction::Head`] symbol by the [`Event::symbol`]
+/// at the step position.
+///
+/// [`Step`] fields doesn't needs in control or protection so they are public.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Step<S: Symbol> {
+    /// The [`Event`] of the step: the applied [`crate::instruction::Instruction`]
+    /// with the actual movement or the head without the instruction.
+    pub event: Event<S>,
+    /// The head index relative to the begin of the initial tape. Cells at
+    /// the left side of the initial tape have negative positions.
+    pub position: isize,
+}
+
+/// [`Trace`] is the record of the execution: the initial tape, index
+/// and state, and all applied [`Step`]s.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace<S: Symbol> {
+    tape: Vec<S>,
+    index: usize,
+    state: State,
+    steps: Vec<Step<S>>,
+    shift: usize,
+}
+
+impl<S: Symbol> Trace<S> {
+    /// Constructs a new empty [`Trace`] started from the [`Configuration`].
+    pub fn new<T: TapeStorage<S>>(conf: &Configuration<S, T>) -> Self {
+        Trace {
+            tape: conf.tape().iter().cloned().collect(),
+            index: conf.index(),
+            state: conf.state,
+            steps: Vec::new(),
+            shift: 0,
+        }
+    }
+
+    /// Returns the initial tape symbols.
+    ///
+    /// Zero cost method.
+    pub fn tape(&self) -> &[S] {
+        &self.tape
+    }
+
+    /// Returns the initial index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the initial [`State`].
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Returns recorded [`Step`]s.
+    ///
+    /// Zero cost method.
+    pub fn steps(&self) -> &[Step<S>] {
+        &self.steps
+    }
+
+    /// Returns `true` if the [`Trace`] has no steps, otherwise `false`.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the count of recorded [`Step`]s.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Records the [`Step`] of the [`Event`] which is made at the index
+    /// of the tape. The tape grows when the head moves left from the first
+    /// cell.
+    pub(crate) fn record(&mut self, event: Event<S>, index: usize) {
+        let position = index as isize - self.shift as isize;
+        if index == 0 && event.movement() == Move::Left {
+            self.shift += 1;
+        }
+        self.steps.push(Step { event, position });
+    }
+
+    /// Replays the [`Trace`] against the [`Program`].
+    ///
+    /// Returns [`Ok(())`] when every [`Step`] is the [`Program`] instruction
+    /// (or the head without the instruction for [`Event::Uncovered`])
+    /// for the replayed tape, state and position, otherwise [`Err(String)`]
+    /// with diagnostic information. The head can stay instead of the move
+    /// to the left only on the first cell of the tape.
+    ///
+    /// Note that cells out of the initial tape are unknown (the [`Program`]
+    /// doesn't know the default symbol), so the first read of such cell
+    /// is accepted as is.
+    pub fn verify(&self, program: &Program<S>) -> Result<(), String> {
+        let mut cells: Vec<(isize, S)> = Vec::new();
+        let mut position = self.index as isize;
+        let mut first = 0;
+        let mut state = self.state;
+        for (number, step) in self.steps.iter().enumerate() {
+            let head = step.event.head();
+            if step.position != position || head.state != state {
+                return Err(format!(
+                    "verify error: step {} expected at position {} with state {} but found at {} with {}",
+                    number + 1, position, state, step.position, head.state
+                ));
+            }
+            let cell = cells.iter().position(|(pos, _)| *pos == position);
+            let symbol = match cell {
+                Some(cell) => Some(&cells[cell].1),
+                None if position >= 0 => self.tape.get(position as usize),
+                None => None,
+            };
+            if symbol.map_or(false, |symbol| symbol != &head.symbol) {
+                return Err(format!(
+                    "verify error: step {} reads {} but tape contains {}",
+                    number + 1,
+                    head.symbol,
+                    symbol.unwrap()
+                ));
+            }
+            let found = program.get(head)?;
+            match &step.event {
+                Event::Applied {
+                    instruction,
+                    movement,
+                } => {
+                    if found != Some(instruction) {
+                        return Err(format!(
+                            "verify error: step {} instruction {} is not in program",
+                            number + 1,
+                            instruction
+                        ));
+                    }
+                    let stays = position == first && instruction.tail.movement == Move::Left;
+                    if *movement != instruction.tail.movement && !(stays && *movement == Move::None)
+                    {
+                        return Err(format!(
+                            "verify error: step {} moves {} by instruction {}",
+                            number + 1,
+                            movement,
+                            instruction
+                        ));
+                    }
+                }
+                Event::Uncovered { .. } => {
+                    if let Some(instruction) = found {
+                        return Err(format!(
+                            "verify error: step {} head ({}) is covered by instruction {}",
+                            number + 1,
+                            head,
+                            instruction
+                        ));
+                    }
+                }
+            }
+            match cell {
+                Some(cell) => cells[cell].1 = step.event.symbol().clone(),
+                None => cells.push((position, step.event.symbol().clone())),
+            }
+            state = step.event.state();
+            position += movement_delta(step.event.movement());
+            first = first.min(position);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the [`Move`] of the step by indexes and lengths of the tape
+/// before and after the step, and `true` when the tape grew to the left.
+pub(crate) fn infer_movement(
+    index: usize,
+    len: usize,
+    next: usize,
+    next_len: usize,
+) -> (Move, bool) {
+    match (index, next) {
+        (old, new) if old == new && len < next_len => (Move::Left, true),
+        (old, new) if old < new => (Move::Right, false),
+        (old, new) if old > new => (Move::Left, false),
+        _ => (Move::None, false),
+    }
+}
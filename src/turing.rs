@@ -1,5 +1,6 @@
-use crate::instruction::State;
-use crate::state::{Configuration, Tape};
+use crate::instruction::{Event, Head, Instruction, State, Tail};
+use crate::state::{Configuration, Tape, TapeStorage};
+use crate::trace::infer_movement;
 use crate::Symbol;
 
 /// Provides ability to execute [`crate::state::Configuration`]s and translate
@@ -10,6 +11,9 @@ use crate::Symbol;
 ///
 /// Most important trait.
 ///
+/// The tape type `T` can be any [`TapeStorage`] realization and is [`Tape`]
+/// by default.
+///
 /// # Examples
 /// ```rust
 /// extern crate turing_machine_rs;
@@ -21,7 +25,7 @@ use crate::Symbol;
 /// use turing_machine_rs::TuringMachine;
 ///
 /// fn main() -> Result<(), String> {
-///    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', 'e', '_'];
+///    let alphabet = vec!['t', 'e', 's', 'n', 'i', 'c', '_'];
 ///    let mut program = Program::new(alphabet, State(4));
 ///     // Trait for more comfortable coding
 ///     program.extend([
@@ -49,18 +53,54 @@ use crate::Symbol;
 ///     Ok(())
 /// }
 /// ```
-pub trait TuringMachine<S: Symbol> {
+pub trait TuringMachine<S: Symbol, T: TapeStorage<S> = Tape<S>> {
     /// Executes the [`crate::program::Program`] and returns a mutated [`Configuration`]
     /// using the [`TuringMachine::execute_until`] method with the `conf.state == 0`
     /// predicate. This is the most commonly used method for [`crate::program::Program`] execution.
-    fn execute(&self, conf: Configuration<S>) -> Result<Configuration<S>, String> {
+    ///
+    /// The method (and translate methods which use it) loops forever when
+    /// the machine never halts. Machines of the crate return [`Err(String)`]
+    /// for heads which are uncovered with the [`crate::machines::Uncovered::Stay`]
+    /// policy, other machines can be executed by the [`TuringMachine::execute_until`]
+    /// method with a step limit in the predicate.
+    fn execute(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String> {
         self.execute_until(conf, |conf| conf.state == State(0))
     }
 
     /// A Turing machine must have the ability to execute [`crate::program::Program`]
     /// and change the [`Configuration`] once. This is important for machines,
     /// and its realization can vary depending on machine type.
-    fn execute_once(&self, conf: Configuration<S>) -> Result<Configuration<S>, String>;
+    fn execute_once(&self, conf: Configuration<S, T>) -> Result<Configuration<S, T>, String>;
+
+    /// Executes the [`crate::program::Program`] and changes the [`Configuration`] once
+    /// like the [`TuringMachine::execute_once`] method, and returns the [`Event`]
+    /// which tells what the machine did (tracing machines record it).
+    ///
+    /// By default the [`Event`] is [`Event::Applied`] with the [`Instruction`]
+    /// inferred from configurations before and after the step: the written
+    /// symbol and the movement. Machines which know the applied instruction
+    /// or resolve heads without it (see [`Event::Uncovered`]) override it.
+    fn execute_event(
+        &self,
+        conf: Configuration<S, T>,
+    ) -> Result<(Configuration<S, T>, Event<S>), String> {
+        let head = Head::new(conf.state, conf.try_get_symbol()?.clone());
+        let (index, len) = (conf.index(), conf.len());
+        let next = self.execute_once(conf)?;
+        let (movement, grown) = infer_movement(index, len, next.index(), next.len());
+        let symbol = match next.tape().get(index + grown as usize) {
+            Some(symbol) => symbol.clone(),
+            None => next.try_get_symbol()?.clone(),
+        };
+        let instruction = Instruction::new(head, Tail::new(next.state, symbol, movement));
+        Ok((
+            next,
+            Event::Applied {
+                instruction,
+                movement,
+            },
+        ))
+    }
 
     /// Executes program untill stop predicate equals to `false` and returns
     /// a mutated [`Configuration`].
@@ -96,13 +136,23 @@ pub trait TuringMachine<S: Symbol> {
     /// ```
     fn execute_until(
         &self,
-        conf: Configuration<S>,
-        until: impl Fn(&Configuration<S>) -> bool,
-    ) -> Result<Configuration<S>, String>;
+        conf: Configuration<S, T>,
+        until: impl Fn(&Configuration<S, T>) -> bool,
+    ) -> Result<Configuration<S, T>, String>;
+
+    /// Returns the blank [`Symbol`] reference which the machine uses
+    /// for the empty [`Tape`] (the empty word) and for trimming
+    /// in [`TuringMachine::translate_word`].
+    ///
+    /// Returns [`Option::None`] by default, so words cannot be translated
+    /// by the machine.
+    fn blank(&self) -> Option<&S> {
+        None
+    }
 
     /// Translates and returns a mutated [`Tape`] using the [`TuringMachine::execute`]
     /// method as the [`Configuration::new_std`].
-    fn translate_std(&self, tape: Tape<S>) -> Result<Tape<S>, String> {
+    fn translate_std(&self, tape: T) -> Result<T, String> {
         let conf = Configuration::new_std(tape)?;
         let exec = self.execute(conf)?;
         Ok(exec.into_tape())
@@ -110,9 +160,24 @@ pub trait TuringMachine<S: Symbol> {
 
     /// Translates and returns a mutated [`Tape`] using the [`TuringMachine::execute`]
     /// method as the [`Configuration::new_nrm`].
-    fn translate_nrm(&self, tape: Tape<S>) -> Result<Tape<S>, String> {
+    fn translate_nrm(&self, tape: T) -> Result<T, String> {
         let conf = Configuration::new_nrm(tape)?;
         let exec = self.execute(conf)?;
         Ok(exec.into_tape())
     }
+
+    /// Translates the input word using the [`TuringMachine::execute`] method
+    /// as the [`Configuration::with_blank`] with the [`TuringMachine::blank`]
+    /// and returns the output word (see [`TapeStorage::trimmed`]). So the empty
+    /// word is translated too. The blank symbol of the [`Tape`] is used
+    /// for trimming when it's known.
+    ///
+    /// Returns [`Err(String)`] when the machine has no blank [`Symbol`].
+    fn translate_word(&self, word: T, std: bool) -> Result<Vec<S>, String> {
+        let blank = self
+            .blank()
+            .ok_or_else(|| String::from("translate error: machine has no blank symbol"))?;
+        let conf = Configuration::with_blank(word, blank.clone(), std);
+        Ok(self.execute(conf)?.trimmed())
+    }
 }
@@ -0,0 +1,65 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+
+use turing_machine_rs::arbitrary::{configuration, instruction, program, properties, tape};
+use turing_machine_rs::instruction::State;
+use turing_machine_rs::program::Program;
+use turing_machine_rs::state::Configuration;
+
+proptest! {
+    #[test]
+    fn any_configuration_is_valid(conf in any::<Configuration<char>>()) {
+        prop_assert!(conf.index() < conf.len());
+    }
+
+    #[test]
+    fn any_program_is_valid(program in any::<Program<char>>()) {
+        prop_assert!(!program.alphabet().is_empty());
+        prop_assert!(program.l_state() > State(0));
+    }
+
+    #[test]
+    fn instruction_is_valid(inst in instruction(vec!['0', '1'], State(3))) {
+        let mut program = Program::new(vec!['0', '1'], State(3));
+        prop_assert_eq!(program.insert(inst.clone()), Ok(None));
+        prop_assert_eq!(program.get(&inst.head), Ok(Some(&inst)));
+    }
+
+    #[test]
+    fn configuration_is_valid(conf in configuration(vec!['0', '1'], State(3), 0..8)) {
+        prop_assert!(!conf.is_empty());
+        prop_assert!(conf.index() < conf.len());
+        prop_assert!(State(1) <= conf.state && conf.state <= State(3));
+    }
+
+    #[test]
+    fn with_preserves_components(
+        first in program(vec!['_', '0', '1'], State(3)),
+        second in program(vec!['_', '0', '1'], State(2)),
+        tape in tape(vec!['_', '0', '1'], 0..8),
+    ) {
+        properties::with_preserves_components(&first, &second, &'_', &tape, 100)?;
+    }
+
+    #[test]
+    fn source_round_trip(program in any::<Program<char>>()) {
+        properties::source_round_trip(&program)?;
+    }
+
+    #[test]
+    fn trace_round_trip(
+        program in program(vec!['_', '0', '1'], State(4)),
+        tape in tape(vec!['_', '0', '1'], 0..8),
+    ) {
+        properties::trace_round_trip(&program, '_', &tape, 100)?;
+    }
+
+    #[test]
+    fn storages_agree(
+        program in program(vec!['_', '0', '1'], State(4)),
+        tape in tape(vec!['_', '0', '1'], 0..8),
+    ) {
+        properties::storages_agree(&program, &'_', &tape, 100)?;
+    }
+}
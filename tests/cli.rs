@@ -0,0 +1,250 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const NICE_TEST: &str = "examples/programs/nice_test.tm";
+const BINARY_ADDITION: &str = "examples/programs/binary_addition.tm";
+
+fn tm(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tm"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn run() {
+    let output = tm(&["run", NICE_TEST, "test"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "tape: nice\nsteps: 4\nhalt: halted\n");
+}
+
+#[test]
+fn run_std() {
+    let output = tm(&["run", BINARY_ADDITION, "10101+111", "--std"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("tape: 11100"));
+}
+
+#[test]
+fn run_stdin() {
+    let output = tm(&["run", NICE_TEST], "nice\n");
+    assert_eq!(stdout(&output), "tape: test\nsteps: 4\nhalt: halted\n");
+
+    let output = tm(&["run", NICE_TEST, "-"], "test\n");
+    assert_eq!(stdout(&output), "tape: nice\nsteps: 4\nhalt: halted\n");
+}
+
+#[test]
+fn run_trace() {
+    let output = tm(&["run", NICE_TEST, "nice", "--trace", "--limit=10"], "");
+    assert!(stdout(&output).starts_with(" 0 q₁nice\n 1 tq₂ice\n"));
+}
+
+#[test]
+fn run_limit() {
+    let output = tm(&["run", NICE_TEST, "test", "--limit", "2"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "tape: nist\nsteps: 2\nhalt: step limit 2 reached (state 3, index 2)\n"
+    );
+}
+
+#[test]
+fn run_uncovered() {
+    let output = tm(&["run", NICE_TEST, "tent"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("halt: uncovered case"));
+}
+
+#[test]
+fn fail_run() {
+    let cases: [&[&str]; 5] = [
+        &["run"],
+        &["run", "missing.tm", "test"],
+        &["run", NICE_TEST, "text"],
+        &["run", NICE_TEST, "test", "--blank", "x"],
+        &["run", NICE_TEST, "test", "--unknown"],
+    ];
+    for args in cases {
+        let output = tm(args, "");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("tm: "));
+    }
+}
+
+#[test]
+fn step() {
+    let output = tm(&["step", NICE_TEST, "test", "--plain"], "s\n\nq\n");
+    assert!(output.status.success());
+    let screens: Vec<&str> = std::str::from_utf8(&output.stdout)
+        .unwrap()
+        .split("(tm) ")
+        .collect();
+    assert_eq!(screens.len(), 4);
+    assert!(screens[0]
+        .starts_with("step 0 | state 1 | index 0\ntest\n^ q₁\nnext: (1, t) -> (2, n, >)\n"));
+    assert!(screens[0].contains("  > (1, t) -> (2, n, >)\n    (2, e) -> (3, i, >)\n"));
+    assert!(screens[2].starts_with(
+        "step 2 | state 3 | index 2\nnist\n  ^ q₃\nlast: (2, e) -> (3, i, >)\nnext: (3, s) -> (4, c, >)\n"
+    ));
+}
+
+#[test]
+fn step_back_and_edit() {
+    let output = tm(
+        &["step", NICE_TEST, "test", "--plain"],
+        "s 2\nset 3 s\nb\nb 5\nq\n",
+    );
+    let screens: Vec<String> = stdout(&output).split("(tm) ").map(String::from).collect();
+    assert!(screens[2].starts_with("step 2 | state 3 | index 2\nniss\n"));
+    assert!(screens[3].starts_with("step 2 | state 3 | index 2\nnist\n"));
+    assert!(screens[4].starts_with("step 0 | state 1 | index 0\ntest\n"));
+    assert!(screens[4].contains("history is empty\n"));
+}
+
+#[test]
+fn step_continue() {
+    let output = tm(
+        &["step", NICE_TEST, "test", "--plain"],
+        "break 4 t\nbreak\nc\nc\nc\n",
+    );
+    let screens: Vec<String> = stdout(&output).split("(tm) ").map(String::from).collect();
+    assert!(screens[2].contains("breakpoints: (4, t)\n"));
+    assert!(screens[3].starts_with("step 3 | state 4 | index 3\n"));
+    assert!(screens[3].contains("breakpoint at state 4\n"));
+    assert!(screens[4].starts_with("step 4 | state 0 | index 3\nnice\n"));
+    assert!(screens[4].contains("next: halted\n"));
+    assert!(screens[5].contains("machine is halted\n"));
+}
+
+#[test]
+fn step_errors() {
+    let output = tm(
+        &["step", NICE_TEST, "tent", "--plain"],
+        "jump\nset 9 t\nset 0 x\ndelete 1\nc\n",
+    );
+    assert!(output.status.success());
+    let screens: Vec<String> = stdout(&output).split("(tm) ").map(String::from).collect();
+    assert!(screens[1].contains("unknown command \"jump\""));
+    assert!(screens[2].contains("index 9 is out of the tape with len 4"));
+    assert!(screens[3].contains("symbol 'x' is not in alphabet"));
+    assert!(screens[4].contains("breakpoint (1) is not set"));
+    assert!(screens[5].contains("uncovered case: have no tail for head (3, n) in program"));
+}
+
+#[test]
+fn repl() {
+    let commands = "\
+alphabet _ 0 1
+1, 0 -> 1, 1, >
+add 1, 1 -> 1, 0, >
+tape 011
+run
+1, _ -> 0, _, -
+run
+reset
+step 2
+remove 1 0
+list
+q
+";
+    let output = tm(&["repl"], commands);
+    assert!(output.status.success());
+    let outputs: Vec<String> = stdout(&output).split("(tm) ").map(String::from).collect();
+    assert_eq!(
+        outputs[1],
+        "program: Program<char> { alphabet ['_', '0', '1'] instuctions: 0, l_state: 1 }\n"
+    );
+    assert_eq!(outputs[2], "added: (1, 0) -> (1, 1, >)\n");
+    assert_eq!(outputs[3], "added: (1, 1) -> (1, 0, >)\n");
+    assert_eq!(
+        outputs[5],
+        "tape: 100_\nsteps: 3\nerror: uncovered case: have no tail for head (1, _) in program\n"
+    );
+    // The machine continues from the uncovered case with the new instruction
+    assert_eq!(outputs[7], "tape: 100_\nsteps: 4\nhalted\n");
+    assert_eq!(outputs[9], "1 1q₁11\n2 10q₁1\n");
+    assert_eq!(outputs[10], "removed: (1, 0) -> (1, 1, >)\n");
+    assert_eq!(
+        outputs[11],
+        "alphabet: _ 0 1\nl_state: 1\n1, 1 -> 1, 0, >\n1, _ -> 0, _, -\n"
+    );
+}
+
+#[test]
+fn repl_errors() {
+    let commands = "\
+run
+load missing.tm
+load examples/programs/nice_test.tm
+1, x -> 0, t, -
+remove 5 t
+l_state 3
+tape nope
+run
+";
+    let output = tm(&["repl"], commands);
+    let outputs: Vec<String> = stdout(&output).split("(tm) ").map(String::from).collect();
+    assert_eq!(outputs[1], "error: alphabet is empty\n");
+    assert!(outputs[2].starts_with("error: cannot read file missing.tm"));
+    assert!(outputs[3].starts_with("program: "));
+    assert!(outputs[4].starts_with("error: set error: instruction (1, x)"));
+    assert!(outputs[5].starts_with("error: remove error: required state 5"));
+    assert!(outputs[6].starts_with("error: set error: instruction (3, s)"));
+    assert!(outputs[8].starts_with("error: input symbol 'o' is not in alphabet"));
+}
+
+#[test]
+fn repl_load_save() {
+    let path = std::env::temp_dir().join(format!("tm-repl-{}.tm", std::process::id()));
+    let path = path.to_str().unwrap();
+    let commands = format!("alphabet a b\n1, a -> 0, b, >\nsave {}\n", path);
+    let output = tm(&["repl"], &commands);
+    assert!(stdout(&output).contains(&format!("saved: {}\n", path)));
+
+    let output = tm(&["repl", path], "tape a\nrun\n");
+    let _ = std::fs::remove_file(path);
+    assert!(stdout(&output).contains("tape: ba\nsteps: 1\nhalted\n"));
+}
+
+#[test]
+fn test() {
+    let output = tm(
+        &[
+            "test",
+            BINARY_ADDITION,
+            "examples/programs/binary_addition.spec",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "result: ok. 4 passed; 0 failed\n");
+
+    let path = std::env::temp_dir().join(format!("tm-test-{}.spec", std::process::id()));
+    std::fs::write(&path, "name: wrong\ninput: test\noutput: test\n").unwrap();
+    let output = tm(&["test", NICE_TEST, path.to_str().unwrap(), "--all"], "");
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "FAIL wrong (line 1, 4 steps)\n    output:\n    - test\n    + nice\n      ^\nresult: FAILED. 0 passed; 1 failed\n"
+    );
+}
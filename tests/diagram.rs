@@ -0,0 +1,203 @@
+use turing_machine_rs::diagram::Diagram;
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::TuringMachine;
+
+/// Returns `(width, height, pixels)` of the PNG made by the [`Diagram`]
+/// (stored deflate blocks only).
+fn decode_png(png: &[u8]) -> (u32, u32, Vec<u8>) {
+    assert_eq!(
+        &png[..8],
+        &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+    );
+    let (mut width, mut height, mut data) = (0, 0, Vec::new());
+    let mut offset = 8;
+    while offset < png.len() {
+        let len = u32::from_be_bytes([
+            png[offset],
+            png[offset + 1],
+            png[offset + 2],
+            png[offset + 3],
+        ]);
+        let kind = &png[offset + 4..offset + 8];
+        let body = &png[offset + 8..offset + 8 + len as usize];
+        match kind {
+            b"IHDR" => {
+                width = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+                height = u32::from_be_bytes([body[4], body[5], body[6], body[7]]);
+            }
+            b"IDAT" => data.extend_from_slice(body),
+            _ => {}
+        }
+        offset += 12 + len as usize;
+    }
+    let mut pixels = Vec::new();
+    let mut cursor = 2;
+    loop {
+        let last = data[cursor] & 1 == 1;
+        let len = u16::from_le_bytes([data[cursor + 1], data[cursor + 2]]) as usize;
+        pixels.extend_from_slice(&data[cursor + 5..cursor + 5 + len]);
+        cursor += 5 + len;
+        if last {
+            break;
+        }
+    }
+    (width, height, pixels)
+}
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_machine() -> Classic<char> {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program
+            .extend([(1, '0', 0, '1', Move::None), (1, '1', 1, '1', Move::Left)])
+            .unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    #[test]
+    fn record() {
+        let conf = Configuration::new_nrm(Tape::from("11")).unwrap();
+        let diagram = Diagram::record(&new_machine(), conf, 100).unwrap();
+
+        assert_eq!(diagram.len(), 3);
+        // Tape grows to the left so the diagram has 3 columns
+        assert_eq!(diagram.size(), (3, 3));
+    }
+
+    #[test]
+    fn record_limit() {
+        let mut program = Program::new(vec!['0'], State(1));
+        program.extend([(1, '0', 1, '0', Move::Right)]).unwrap();
+        let machine = Classic::new(program, '0').unwrap();
+
+        let conf = Configuration::new_nrm(Tape::from("0")).unwrap();
+        let diagram = Diagram::record(&machine, conf, 10).unwrap();
+
+        assert_eq!(diagram.len(), 11);
+        assert_eq!(diagram.size(), (11, 11));
+    }
+
+    #[test]
+    fn push() {
+        let machine = new_machine();
+        let mut diagram = Diagram::new();
+        assert!(diagram.is_empty());
+
+        let mut conf = Configuration::new_nrm(Tape::from("1")).unwrap();
+        diagram.push(&conf);
+        while conf.state != State(0) {
+            conf = machine.execute_once(conf).unwrap();
+            diagram.push(&conf);
+        }
+        assert_eq!(diagram.len(), 3);
+        assert_eq!(diagram.size(), (2, 3));
+    }
+
+    #[test]
+    fn sampling_and_crop() {
+        let conf = Configuration::new_nrm(Tape::from("1111")).unwrap();
+        let mut diagram = Diagram::record(&new_machine(), conf, 100).unwrap();
+        assert_eq!(diagram.size(), (5, 3));
+
+        diagram.set_sampling(2);
+        assert_eq!(diagram.size(), (5, 2));
+
+        diagram.set_crop(Some((0, 1)));
+        diagram.set_cell_size(3);
+        assert_eq!(diagram.size(), (6, 6));
+    }
+
+    #[test]
+    fn to_svg() {
+        let conf = Configuration::new_nrm(Tape::from("11")).unwrap();
+        let mut diagram = Diagram::record(&new_machine(), conf, 100).unwrap();
+        diagram.set_color('1', [0, 0, 0]);
+        diagram.set_cell_size(4);
+
+        let svg = diagram.to_svg();
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"12\"")
+        );
+        assert!(svg.ends_with("</svg>\n"));
+        // 8 cells (the first row is shorter) and 3 inner rects for heads
+        assert_eq!(svg.matches("<rect").count(), 11);
+        assert!(svg.contains("<rect x=\"8\" y=\"0\" width=\"4\" height=\"4\" fill=\"#000000\"/>"));
+    }
+
+    #[test]
+    fn to_png() {
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let mut diagram = Diagram::record(&new_machine(), conf, 100).unwrap();
+        diagram.set_color('1', [0, 0, 0]);
+        diagram.set_color('0', [255, 255, 255]);
+
+        let (width, height, pixels) = decode_png(&diagram.to_png());
+        assert_eq!((width, height), (3, 3));
+        assert_eq!(pixels.len(), (3 * 4 + 1) * 3);
+        // The first row: no cell at -1, the head at 0, the symbol '0' at 1
+        assert_eq!(&pixels[0..5], &[0, 0, 0, 0, 0]);
+        assert_eq!(&pixels[9..13], &[255, 255, 255, 255]);
+        // The last row: the halt state head at -1
+        assert_eq!(&pixels[27..31], &[255, 0, 0, 255]);
+        assert_eq!(&pixels[31..35], &[0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn to_png_empty() {
+        let diagram: Diagram<char> = Diagram::new();
+        let (width, height, pixels) = decode_png(&diagram.to_png());
+        assert_eq!((width, height), (1, 1));
+        assert_eq!(pixels, vec![0, 0, 0, 0, 0]);
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let mut diagram = Diagram::record(&new_machine(), conf, 100).unwrap();
+        diagram.set_crop(Some((2, 1)));
+        let (width, height, _) = decode_png(&diagram.to_png());
+        assert_eq!((width, height), (1, 1));
+    }
+
+    #[test]
+    fn to_png_large() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '0', 1, '1', Move::Right)]).unwrap();
+        let machine = Classic::new(program, '0').unwrap();
+
+        let conf = Configuration::new_nrm(Tape::from("0")).unwrap();
+        let mut diagram = Diagram::record(&machine, conf, 200).unwrap();
+        diagram.set_cell_size(2);
+
+        let (width, height, pixels) = decode_png(&diagram.to_png());
+        assert_eq!((width, height), (402, 402));
+        assert_eq!(pixels.len(), (402 * 4 + 1) * 402);
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn record() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('1'), Move::None),
+                (1, Box::new('1'), 1, Box::new('1'), Move::Left),
+            ])
+            .unwrap();
+        let machine = Classic::new(program, Box::new('0')).unwrap();
+
+        let conf = Configuration::new_nrm(Tape::new("11".chars().map(Box::new))).unwrap();
+        let mut diagram = Diagram::record(&machine, conf, 100).unwrap();
+        diagram.set_cell_size(2);
+
+        assert_eq!(diagram.size(), (6, 6));
+        let (width, height, _) = decode_png(&diagram.to_png());
+        assert_eq!((width, height), (6, 6));
+    }
+}
@@ -1,7 +1,7 @@
 use turing_machine_rs::instruction::{Head, Instruction, Move, State, Tail};
-use turing_machine_rs::machines::Classic;
+use turing_machine_rs::machines::{Classic, Lba, Uncovered};
 use turing_machine_rs::program::{Extend, Program};
-use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::state::{Boundary, Configuration, Tape};
 use turing_machine_rs::{TuringMachine, With};
 
 #[cfg(test)]
@@ -179,6 +179,45 @@ mod copy_turing_machine {
         let expected = Tape::from("001");
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn translate_empty_word() {
+        let machine = new_success_machine();
+        assert_eq!(machine.blank(), Some(&'0'));
+
+        let result = machine.translate_word(Tape::new([]), false).unwrap();
+        assert_eq!(vec!['1'], result);
+
+        let result = machine.translate_word(Tape::new([]), true).unwrap();
+        assert_eq!(vec!['1'], result);
+
+        // Translate methods keep the baseline behaviour
+        let result = machine.translate_nrm(Tape::new([]));
+        assert!(result.unwrap_err().starts_with("index out of bounds"));
+
+        let result = machine.translate_std(Tape::new([]));
+        assert!(result.unwrap_err().starts_with("index out of bounds"));
+    }
+
+    #[test]
+    fn translate_word() {
+        let machine = new_success_machine();
+
+        let result = machine.translate_word(Tape::from("010"), false).unwrap();
+        assert_eq!(vec!['1'], result);
+
+        let result = machine.translate_word(Tape::from("010"), true).unwrap();
+        assert_eq!(vec!['1', '0', '1'], result);
+
+        // The tape blank is used instead of the machine blank
+        let tape = Tape::with_blank('1', "010".chars());
+        let result = machine.translate_word(tape, true).unwrap();
+        assert_eq!(vec!['0', '1', '0'], result);
+
+        // The translate method doesn't trim the tape
+        let result = machine.translate_std(Tape::from("010")).unwrap();
+        assert_eq!(Tape::from("0101"), result);
+    }
 }
 
 #[cfg(test)]
@@ -230,28 +269,19 @@ mod clone_turing_machine {
             .unwrap();
         let machine = Classic::new(program, Box::new(' ')).unwrap();
 
-        let conf = Configuration::new(
-            Tape::new("001100".chars().map(|ch| Box::new(ch))),
-            5,
-            State(0),
-        )
-        .unwrap();
+        let conf =
+            Configuration::new(Tape::new("001100".chars().map(Box::new)), 5, State(0)).unwrap();
         let result = machine.execute(conf.clone()).unwrap();
 
         let expected = conf;
 
         assert_eq!(expected, result);
 
-        let conf =
-            Configuration::new_std(Tape::new("001100".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_std(Tape::new("001100".chars().map(Box::new))).unwrap();
         let result = machine.execute(conf).unwrap();
 
-        let expected = Configuration::new(
-            Tape::new(" 110011 ".chars().map(|ch| Box::new(ch))),
-            6,
-            State(0),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new(" 110011 ".chars().map(Box::new)), 6, State(0)).unwrap();
 
         assert_eq!(expected, result);
     }
@@ -294,17 +324,13 @@ mod clone_turing_machine {
     fn success_execute_until() {
         let machine = new_success_machine();
 
-        let conf = Configuration::new_std(Tape::new("010".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_std(Tape::new("010".chars().map(Box::new))).unwrap();
         let result = machine
             .execute_until(conf, |conf| conf.state == State(3))
             .unwrap();
 
-        let expected = Configuration::new(
-            Tape::new("0101".chars().map(|ch| Box::new(ch))),
-            2,
-            State(3),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new("0101".chars().map(Box::new)), 2, State(3)).unwrap();
 
         assert_eq!(expected, result);
     }
@@ -314,7 +340,7 @@ mod clone_turing_machine {
     fn fail_execute_until() {
         let machine = new_fail_machine();
 
-        let conf = Configuration::new_std(Tape::new("010".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_std(Tape::new("010".chars().map(Box::new))).unwrap();
         let _ = machine
             .execute_until(conf, |conf| conf.state == State(3))
             .unwrap();
@@ -325,9 +351,9 @@ mod clone_turing_machine {
         let machine = new_success_machine();
 
         let result = machine
-            .translate_std(Tape::new("010".chars().map(|ch| Box::new(ch))))
+            .translate_std(Tape::new("010".chars().map(Box::new)))
             .unwrap();
-        let expected = Tape::new("0101".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("0101".chars().map(Box::new));
         assert_eq!(expected, result);
     }
 
@@ -336,11 +362,26 @@ mod clone_turing_machine {
         let machine = new_success_machine();
 
         let result = machine
-            .translate_nrm(Tape::new("010".chars().map(|ch| Box::new(ch))))
+            .translate_nrm(Tape::new("010".chars().map(Box::new)))
             .unwrap();
-        let expected = Tape::new("001".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("001".chars().map(Box::new));
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn translate_empty_word() {
+        let machine = new_success_machine();
+        assert_eq!(machine.blank(), Some(&Box::new('0')));
+
+        let result = machine.translate_word(Tape::new([]), false).unwrap();
+        assert_eq!(vec![Box::new('1')], result);
+
+        let result = machine.translate_word(Tape::new([]), true).unwrap();
+        assert_eq!(vec![Box::new('1')], result);
+
+        let result = machine.translate_std(Tape::new([]));
+        assert!(result.unwrap_err().starts_with("index out of bounds"));
+    }
 }
 
 #[cfg(test)]
@@ -606,16 +647,16 @@ mod clone_with_for_classic {
         let choose_machine = choose_machine.with(&zerofy).unwrap();
         let choose_machine = choose_machine.with(&left_shift).unwrap();
 
-        let tape = Tape::new("0101101110".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("0101101110".chars().map(Box::new));
         let result = choose_machine.translate_nrm(tape).unwrap();
 
-        let expected = Tape::new("0110000000".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("0110000000".chars().map(Box::new));
         assert_eq!(expected, result);
 
-        let tape = Tape::new("010111010".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("010111010".chars().map(Box::new));
         let result = choose_machine.translate_nrm(tape).unwrap();
 
-        let expected = Tape::new("011100000".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("011100000".chars().map(Box::new));
 
         assert_eq!(expected, result);
     }
@@ -654,17 +695,17 @@ mod clone_with_for_classic {
 
         let choose_machine = choose_machine.unwrap();
 
-        let tape = Tape::new("0101101110".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("0101101110".chars().map(Box::new));
         let result = choose_machine.translate_nrm(tape).unwrap();
 
-        let expected = Tape::new("0110000000".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("0110000000".chars().map(Box::new));
 
         assert_eq!(expected, result);
 
-        let tape = Tape::new("010111010".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("010111010".chars().map(Box::new));
         let result = choose_machine.translate_nrm(tape).unwrap();
 
-        let expected = Tape::new("011100000".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("011100000".chars().map(Box::new));
 
         assert_eq!(expected, result);
     }
@@ -685,3 +726,658 @@ mod clone_with_for_classic {
         zero_one.with(&zero_one_two).unwrap();
     }
 }
+
+#[cfg(test)]
+mod copy_branch_for_classic {
+    use super::*;
+
+    fn new_machine(l_state: usize, tuples: &[(usize, char, usize, char, Move)]) -> Classic<char> {
+        let mut program = Program::new(vec!['0', '1'], State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    fn new_read_machine() -> Classic<char> {
+        new_machine(
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 0, '1', Move::None)],
+        )
+    }
+
+    fn new_zerofy_machine() -> Classic<char> {
+        new_machine(
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Right)],
+        )
+    }
+
+    fn new_right_machine() -> Classic<char> {
+        new_machine(
+            1,
+            &[(1, '0', 0, '0', Move::Right), (1, '1', 0, '1', Move::Right)],
+        )
+    }
+
+    #[test]
+    fn branch_on_symbol() {
+        let machine = new_read_machine()
+            .branch_on_symbol(&['1'], &new_zerofy_machine(), &new_right_machine())
+            .unwrap();
+
+        let result = machine.execute(Configuration::new_nrm(Tape::from("110")).unwrap());
+        let expected = Configuration::new(Tape::from("000"), 2, State(0));
+        assert_eq!(result, expected);
+
+        let result = machine.execute(Configuration::new_nrm(Tape::from("011")).unwrap());
+        let expected = Configuration::new(Tape::from("011"), 1, State(0));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn branch_on_state() {
+        // Goes to the state `2` (accept) on `1` and halts on `0`
+        let test = new_machine(
+            2,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 2, '1', Move::None)],
+        );
+        let machine = test
+            .branch_on_state(State(2), &new_zerofy_machine(), &new_right_machine())
+            .unwrap();
+
+        let result = machine.translate_nrm(Tape::from("110")).unwrap();
+        assert_eq!(result, Tape::from("000"));
+
+        let result = machine.translate_nrm(Tape::from("0")).unwrap();
+        assert_eq!(result, Tape::from("00"));
+    }
+
+    #[test]
+    fn fail_branch() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '1', 0, '0', Move::None)]).unwrap();
+        let other = Classic::new(program, '1').unwrap();
+
+        let error = new_read_machine()
+            .branch_on_symbol(&['1'], &other, &new_right_machine())
+            .unwrap_err();
+        assert_eq!(
+            error,
+            "branch error: classic machines have different default symbols: 0 and 1"
+        );
+
+        let error = new_read_machine()
+            .branch_on_state(State(1), &new_zerofy_machine(), &new_right_machine())
+            .unwrap_err();
+        assert!(error.starts_with("branch error: accept state 1"));
+    }
+}
+
+#[cfg(test)]
+mod copy_while_for_classic {
+    use super::*;
+
+    // Unary multiplication: the tape `1^a 0 1^b 0` becomes `x^a 0 1^b 0 1^(a*b)`
+    fn new_machine(l_state: usize, tuples: &[(usize, char, usize, char, Move)]) -> Classic<char> {
+        let mut program = Program::new(vec!['_', '0', '1', 'x', 'y'], State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        Classic::new(program, '_').unwrap()
+    }
+
+    // Marks the current `a` digit and shifts the head to the first `b` digit
+    fn new_shift_machine() -> Classic<char> {
+        #[rustfmt::skip]
+        let machine = new_machine(2, &[
+            (1, '1', 2, 'x', Move::Right),
+            (2, '1', 2, '1', Move::Right),
+            (2, '0', 0, '0', Move::Right),
+        ]);
+        machine
+    }
+
+    // Copies the `b` block to the end of the tape and returns the head back
+    fn new_copy_machine() -> Classic<char> {
+        #[rustfmt::skip]
+        let machine = new_machine(5, &[
+            (1, '1', 2, 'y', Move::Right),
+            (1, '0', 5, '0', Move::Left),
+            (2, '1', 2, '1', Move::Right),
+            (2, '0', 3, '0', Move::Right),
+            (3, '1', 3, '1', Move::Right),
+            (3, '_', 4, '1', Move::Left),
+            (4, '1', 4, '1', Move::Left),
+            (4, '0', 4, '0', Move::Left),
+            (4, 'y', 1, 'y', Move::Right),
+            (5, 'y', 5, '1', Move::Left),
+            (5, '0', 0, '0', Move::Right),
+        ]);
+        machine
+    }
+
+    // Returns the head to the next unmarked `a` digit (or to the separator)
+    fn new_return_machine() -> Classic<char> {
+        #[rustfmt::skip]
+        let machine = new_machine(2, &[
+            (1, '1', 1, '1', Move::Left),
+            (1, '0', 2, '0', Move::Left),
+            (2, '1', 2, '1', Move::Left),
+            (2, 'x', 0, 'x', Move::Right),
+        ]);
+        machine
+    }
+
+    fn new_multiplication_machine() -> Classic<char> {
+        let body = new_shift_machine()
+            .with(&new_copy_machine())
+            .unwrap()
+            .with(&new_return_machine())
+            .unwrap();
+        body.repeat_while(&['1']).unwrap()
+    }
+
+    #[test]
+    fn unary_multiplication() {
+        let machine = new_multiplication_machine();
+
+        // 3 * 2
+        let result = machine.translate_word(Tape::from("1110110"), false);
+        assert_eq!(result, Ok("xxx0110111111".chars().collect()));
+
+        // 1 * 3
+        let result = machine.translate_word(Tape::from("101110"), false);
+        assert_eq!(result, Ok("x01110111".chars().collect()));
+    }
+
+    #[test]
+    fn zero_iterations() {
+        let machine = new_multiplication_machine();
+
+        let result = machine.execute(Configuration::new_nrm(Tape::from("0110")).unwrap());
+        let expected = Configuration::new(Tape::from("0110"), 0, State(0));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn fail_repeat_while() {
+        let error = new_shift_machine().repeat_while(&['2']).unwrap_err();
+        assert!(error.starts_with("while error: symbol 2 is not in alphabet"));
+    }
+}
+
+#[cfg(test)]
+mod copy_union_for_classic {
+    use super::*;
+
+    fn new_machine(
+        alphabet: &str,
+        l_state: usize,
+        tuples: &[(usize, char, usize, char, Move)],
+    ) -> Classic<char> {
+        let mut program = Program::new(alphabet.chars().collect(), State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    // Moves the head right until `0`
+    fn new_skip_machine() -> Classic<char> {
+        new_machine(
+            "01",
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 1, '1', Move::Right)],
+        )
+    }
+
+    // Writes the end marker
+    fn new_mark_machine() -> Classic<char> {
+        new_machine(
+            "01#",
+            1,
+            &[(1, '0', 0, '#', Move::None), (1, '#', 0, '#', Move::None)],
+        )
+    }
+
+    #[test]
+    fn with_union() {
+        let machine = new_skip_machine().with_union(&new_mark_machine()).unwrap();
+
+        let result = machine.translate_nrm(Tape::from("110"));
+        assert_eq!(result, Ok(Tape::from("11#")));
+
+        // The skip machine part doesn't know the new symbol
+        let result = machine.translate_nrm(Tape::from("1#"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "uncovered case: have no tail for head (1, #) in program"
+            ))
+        );
+    }
+
+    #[test]
+    fn with_mapping() {
+        let end = new_machine(
+            "0ab",
+            1,
+            &[(1, '0', 0, 'b', Move::None), (1, 'b', 0, 'b', Move::None)],
+        );
+        let machine = new_skip_machine()
+            .with_mapping(&end, &[('b', '#')])
+            .unwrap();
+
+        assert_eq!(machine.program().alphabet(), &vec!['0', '1', 'a', '#']);
+        let result = machine.translate_nrm(Tape::from("10"));
+        assert_eq!(result, Ok(Tape::from("1#")));
+    }
+
+    #[test]
+    fn fail_with_union() {
+        let mut program = Program::new(vec!['0', '1', '#'], State(1));
+        program.extend([(1, '0', 0, '#', Move::None)]).unwrap();
+        let other = Classic::new(program, '#').unwrap();
+
+        let error = new_skip_machine().with_union(&other).unwrap_err();
+        assert_eq!(
+            error,
+            "with error: classic machines have different default symbols: 0 and #"
+        );
+    }
+}
+
+#[cfg(test)]
+mod copy_uncovered_for_classic {
+    use super::*;
+
+    // Inverts `1`s until `0`, the head on `0` is uncovered
+    fn new_partial_machine(uncovered: Uncovered) -> Classic<char> {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '1', 1, '0', Move::Right)]).unwrap();
+        let mut machine = Classic::new(program, '0').unwrap();
+        machine.set_uncovered(uncovered);
+        machine
+    }
+
+    #[test]
+    fn default_error() {
+        let machine = new_partial_machine(Uncovered::default());
+        assert_eq!(machine.uncovered(), Uncovered::Error);
+
+        let result = machine.translate_nrm(Tape::from("110"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "uncovered case: have no tail for head (1, 0) in program"
+            ))
+        );
+    }
+
+    #[test]
+    fn halt() {
+        let machine = new_partial_machine(Uncovered::Halt);
+
+        let conf = Configuration::new_nrm(Tape::from("110")).unwrap();
+        let conf = machine.execute(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("000"), 2, State(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject() {
+        let machine = new_partial_machine(Uncovered::Reject);
+
+        let result = machine.translate_nrm(Tape::from("10"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "reject: have no tail for head (1, 0) in program"
+            ))
+        );
+    }
+
+    #[test]
+    fn stay() {
+        let machine = new_partial_machine(Uncovered::Stay);
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let expected = Configuration::new(Tape::from("00"), 1, State(1)).unwrap();
+        let conf = machine.execute_once(conf).unwrap();
+        let conf = machine.execute_once(conf).unwrap();
+        assert_eq!(conf, expected);
+        let conf = machine.execute_once(conf).unwrap();
+        assert_eq!(conf, expected);
+
+        // The execution until the halt never ends
+        let result = machine.translate_nrm(Tape::from("10"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "execute error: uncovered head (1, 0) stays forever by the Stay policy"
+            ))
+        );
+    }
+
+    #[test]
+    fn composed_policy() {
+        let machine = new_partial_machine(Uncovered::Halt);
+        let machine = machine
+            .with(&new_partial_machine(Uncovered::Error))
+            .unwrap();
+        assert_eq!(machine.uncovered(), Uncovered::Halt);
+
+        // The implicit halt stops the whole machine
+        let result = machine.translate_nrm(Tape::from("10"));
+        assert_eq!(result, Ok(Tape::from("00")));
+    }
+
+    #[test]
+    fn out_of_program_state() {
+        let machine = new_partial_machine(Uncovered::Halt);
+
+        let conf = Configuration::new(Tape::from("0"), 0, State(2)).unwrap();
+        let result = machine.execute(conf);
+        assert_eq!(
+            result,
+            Err(String::from(
+                "get error: required state 2 is large then largest 1"
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod clone_uncovered_for_classic {
+    use super::*;
+
+    fn new_partial_machine(uncovered: Uncovered) -> Classic<Box<char>> {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([(1, Box::new('1'), 1, Box::new('0'), Move::Right)])
+            .unwrap();
+        let mut machine = Classic::new(program, Box::new('0')).unwrap();
+        machine.set_uncovered(uncovered);
+        machine
+    }
+
+    #[test]
+    fn halt() {
+        let machine = new_partial_machine(Uncovered::Halt);
+
+        let result = machine.translate_nrm(Tape::new(['1', '1', '0'].map(Box::new)));
+        assert_eq!(result, Ok(Tape::new(['0', '0', '0'].map(Box::new))));
+    }
+
+    #[test]
+    fn reject() {
+        let machine = new_partial_machine(Uncovered::Reject);
+
+        let result = machine.translate_nrm(Tape::new(['1', '0'].map(Box::new)));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "reject: have no tail for head (1, 0) in program"
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod copy_boundary_for_classic {
+    use super::*;
+
+    // Marks `1`s from right to left and halts on the first mark or blank
+    fn new_mark_machine(boundary: Boundary) -> Classic<char> {
+        let mut program = Program::new(vec!['_', '1', 'x'], State(1));
+        program
+            .extend([
+                (1, '1', 1, 'x', Move::Left),
+                (1, 'x', 0, 'x', Move::None),
+                (1, '_', 0, '_', Move::None),
+            ])
+            .unwrap();
+        let mut machine = Classic::new(program, '_').unwrap();
+        machine.set_boundary(boundary);
+        machine
+    }
+
+    #[test]
+    fn default_grow() {
+        let machine = new_mark_machine(Boundary::default());
+        assert_eq!(machine.boundary(), Boundary::Grow);
+
+        let conf = Configuration::new_std(Tape::from("111")).unwrap();
+        let conf = machine.execute(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("_xxx"), 0, State(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn stay() {
+        // The head stays on the first cell and reads its own mark
+        let machine = new_mark_machine(Boundary::Stay);
+
+        let conf = Configuration::new_std(Tape::from("111")).unwrap();
+        let conf = machine.execute(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("xxx"), 0, State(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn left_end_detection() {
+        // The textbook trick: mark the cell, move left and check the mark,
+        // the mark is read again only on the first cell of the one-way tape
+        let mut program = Program::new(vec!['_', '0', '1', 'x', 'y'], State(3));
+        program
+            .extend([
+                (1, '0', 2, 'x', Move::Left),
+                (1, '1', 2, 'y', Move::Left),
+                (2, '0', 3, '0', Move::Right),
+                (2, '1', 3, '1', Move::Right),
+                (2, 'x', 0, '0', Move::None),
+                (2, 'y', 0, '1', Move::None),
+                (3, 'x', 1, '0', Move::Left),
+                (3, 'y', 1, '1', Move::Left),
+            ])
+            .unwrap();
+        let mut machine = Classic::new(program, '_').unwrap();
+        machine.set_boundary(Boundary::Stay);
+
+        let conf = Configuration::new_std(Tape::from("0110")).unwrap();
+        let conf = machine.execute(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("0110"), 0, State(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn error() {
+        let machine = new_mark_machine(Boundary::Error);
+        let result = machine.translate_std(Tape::from("11"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "shift error: cannot move left from the first cell in state 1"
+            ))
+        );
+
+        // The first cell is not reached
+        let result = machine.translate_std(Tape::from("x1"));
+        assert_eq!(result, Ok(Tape::from("xx")));
+    }
+
+    #[test]
+    fn composed_boundary() {
+        let machine = new_mark_machine(Boundary::Stay);
+        let machine = machine.with(&new_mark_machine(Boundary::Grow)).unwrap();
+        assert_eq!(machine.boundary(), Boundary::Stay);
+    }
+}
+
+#[cfg(test)]
+mod clone_boundary_for_classic {
+    use super::*;
+
+    #[test]
+    fn stay() {
+        let mut program = Program::new(vec![Box::new('_'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('1'), 1, Box::new('_'), Move::Left),
+                (1, Box::new('_'), 0, Box::new('1'), Move::None),
+            ])
+            .unwrap();
+        let mut machine = Classic::new(program, Box::new('_')).unwrap();
+        machine.set_boundary(Boundary::Stay);
+
+        let result = machine.translate_std(Tape::new(['1', '1'].map(Box::new)));
+        assert_eq!(result, Ok(Tape::new(['1', '_'].map(Box::new))));
+    }
+}
+
+#[cfg(test)]
+mod copy_stay_for_classic {
+    use super::*;
+
+    // Inverts the word and halts on the first blank
+    fn new_invert_machine() -> Classic<char> {
+        let mut program = Program::new(vec!['_', '0', '1'], State(1));
+        program
+            .extend([
+                (1, '0', 1, '1', Move::Right),
+                (1, '1', 1, '0', Move::Right),
+                (1, '_', 0, '_', Move::None),
+            ])
+            .unwrap();
+        Classic::new(program, '_').unwrap()
+    }
+
+    fn corpus(len: usize) -> Vec<Configuration<char>> {
+        let mut words = vec![String::new()];
+        let mut corpus = Vec::new();
+        for _ in 0..len {
+            words = words
+                .iter()
+                .flat_map(|word| ['_', '0', '1'].map(|symbol| format!("{}{}", word, symbol)))
+                .collect();
+            for word in &words {
+                for index in 0..word.len() {
+                    let tape = Tape::from(word.as_str());
+                    corpus.push(Configuration::new(tape, index, State(1)).unwrap());
+                }
+            }
+        }
+        corpus
+    }
+
+    #[test]
+    fn without_stay() {
+        let machine = new_invert_machine();
+        let stayless = machine.without_stay().unwrap();
+
+        assert_eq!(stayless.program().l_state(), State(2));
+        assert!(stayless
+            .program()
+            .instructions()
+            .iter()
+            .all(|inst| inst.tail.movement != Move::None));
+
+        // The right move grows the tape by the default symbol
+        let result = stayless.translate_nrm(Tape::from("01"));
+        assert_eq!(result, Ok(Tape::from("10__")));
+        assert_eq!(machine.equivalent(&stayless, corpus(4), 20), Ok(()));
+    }
+
+    #[test]
+    fn without_stay_bounded() {
+        let mut machine = new_invert_machine();
+        machine.set_boundary(Boundary::Error);
+        let stayless = machine.without_stay().unwrap();
+        assert_eq!(stayless.boundary(), Boundary::Error);
+
+        // The head stays on the last cell, the auxiliary state grows the tape
+        let conf = Configuration::new_std(Tape::from("01_")).unwrap();
+        assert_eq!(
+            machine.execute(conf.clone()),
+            Configuration::new(Tape::from("01_"), 2, State(0))
+        );
+        assert_eq!(
+            stayless.execute(conf),
+            Configuration::new(Tape::from("01__"), 2, State(0))
+        );
+        assert_eq!(machine.equivalent(&stayless, corpus(3), 20), Ok(()));
+
+        // Fixed tapes cannot grow to the right
+        let mut program = Program::new(vec!['<', '>', '1'], State(2));
+        program
+            .extend([(1, '1', 1, '1', Move::Right), (1, '>', 2, '>', Move::None)])
+            .unwrap();
+        let stayless = program.without_stay().unwrap();
+        assert!(Lba::new(program, '<', '>', State(2)).is_ok());
+        assert_eq!(
+            Lba::new(stayless, '<', '>', State(2)).unwrap_err(),
+            "lba error: instruction (1, >) -> (3, >, >) passes the end marker >"
+        );
+    }
+
+    #[test]
+    fn equivalent_uncovered() {
+        let mut program = Program::new(vec!['_', '0', '1'], State(1));
+        program.extend([(1, '0', 0, '1', Move::None)]).unwrap();
+        let machine = Classic::new(program, '_').unwrap();
+        let stayless = machine.without_stay().unwrap();
+
+        // Both machines have the same uncovered case error
+        assert_eq!(machine.equivalent(&stayless, corpus(3), 4), Ok(()));
+    }
+
+    #[test]
+    fn fail_equivalent() {
+        let machine = new_invert_machine();
+        let other = new_invert_machine().without_stay().unwrap();
+
+        // The stayless machine takes an extra step
+        let corpus = vec![Configuration::new_nrm(Tape::from("0")).unwrap()];
+        assert_eq!(
+            machine.equivalent(&other, corpus.clone(), 2),
+            Err(String::from(
+                "equivalence error: machines differ on \
+                 Configuration { Tape: \"0\", Index: 0, State: 1 }: \
+                 Configuration { Tape: \"1_\", Index: 1, State: 0 } and \
+                 limit: machine doesn't halt in 2 steps"
+            ))
+        );
+        assert_eq!(machine.equivalent(&other, corpus, 3), Ok(()));
+
+        let mut program = Program::new(vec!['_', '0', '1'], State(1));
+        program.extend([(1, '_', 0, '_', Move::None)]).unwrap();
+        let blank = Classic::new(program, '_').unwrap();
+        let corpus = vec![Configuration::new_nrm(Tape::from("1")).unwrap()];
+        assert!(machine.equivalent(&blank, corpus, 10).is_err());
+    }
+}
+
+#[cfg(test)]
+mod clone_stay_for_classic {
+    use super::*;
+
+    #[test]
+    fn without_stay() {
+        let mut program = Program::new(vec![Box::new('_'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('1'), 1, Box::new('1'), Move::Right),
+                (1, Box::new('_'), 0, Box::new('1'), Move::None),
+            ])
+            .unwrap();
+        let machine = Classic::new(program, Box::new('_')).unwrap();
+        let stayless = machine.without_stay().unwrap();
+
+        let corpus = ["1", "11", "_1"]
+            .map(|word| Configuration::new_nrm(Tape::new(word.chars().map(Box::new))).unwrap());
+        assert_eq!(machine.equivalent(&stayless, corpus, 10), Ok(()));
+    }
+}
@@ -0,0 +1,213 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, Compiled, Uncovered};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Boundary, Configuration, SparseTape, Tape};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    // Increments the binary number (the head is on the last digit)
+    fn new_increment_machine() -> Classic<char> {
+        let mut program = Program::new(vec!['_', '0', '1'], State(2));
+        program
+            .extend([
+                (1, '1', 1, '0', Move::Left),
+                (1, '0', 2, '1', Move::Right),
+                (1, '_', 2, '1', Move::Right),
+                (2, '0', 2, '0', Move::Right),
+                (2, '1', 2, '1', Move::Right),
+                (2, '_', 0, '_', Move::Left),
+            ])
+            .unwrap();
+        Classic::new(program, '_').unwrap()
+    }
+
+    #[test]
+    fn creation() {
+        let classic = new_increment_machine();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+        assert_eq!(compiled.alphabet(), classic.program().symbols());
+        assert_eq!(TuringMachine::<char>::blank(&compiled), Some(&'_'));
+
+        let compiled: Compiled<char, u8> = Compiled::new(classic.program(), '0').unwrap();
+        assert_eq!(TuringMachine::<char>::blank(&compiled), Some(&'0'));
+    }
+
+    #[test]
+    fn fail_creation() {
+        let classic = new_increment_machine();
+        let error = Compiled::<char>::new(classic.program(), '#').unwrap_err();
+        assert_eq!(
+            error,
+            "compile error: default symbol # is not in alphabet ['_', '0', '1']"
+        );
+
+        let alphabet: Vec<char> = (0..300u32).filter_map(char::from_u32).collect();
+        let program = Program::new(alphabet, State(1));
+        let error = Compiled::<char, u8>::new(&program, '\0').unwrap_err();
+        assert_eq!(
+            error,
+            "compile error: alphabet of 300 symbols doesn't fit into u8"
+        );
+        assert!(Compiled::<char, u16>::new(&program, '\0').is_ok());
+
+        let program = Program::new(vec!['0', '1'], State(usize::MAX));
+        let error = Compiled::<char>::new(&program, '0').unwrap_err();
+        assert!(error.starts_with("compile error: table for"));
+    }
+
+    #[test]
+    fn execute() {
+        let classic = new_increment_machine();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+
+        for word in ["0", "1", "1011", "111", "_"] {
+            let conf = Configuration::new_std(Tape::from(word)).unwrap();
+            let expected = classic.execute(conf.clone()).unwrap();
+            assert_eq!(compiled.execute(conf).unwrap(), expected, "{}", word);
+        }
+
+        let result = compiled.translate_std(Tape::from("111"));
+        assert_eq!(result, Ok(Tape::from("1000_")));
+    }
+
+    #[test]
+    fn execute_sparse() {
+        let program = new_increment_machine().program().clone();
+        let classic: Classic<char, SparseTape<char>> = Classic::for_storage(program, '_').unwrap();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+
+        let tape = SparseTape::from_symbols('_', "11".chars());
+        let conf = Configuration::new_std(tape).unwrap();
+        let result = compiled.execute(conf.clone()).unwrap();
+        assert_eq!(result, classic.execute(conf).unwrap());
+        assert_eq!(result.trimmed(), vec!['1', '0', '0']);
+    }
+
+    #[test]
+    fn execute_once() {
+        let classic = new_increment_machine();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+
+        let mut expected = Configuration::new_std(Tape::from("11")).unwrap();
+        let mut result = expected.clone();
+        while expected.state != State(0) {
+            expected = classic.execute_once(expected).unwrap();
+            result = compiled.execute_once(result).unwrap();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn execute_until() {
+        let classic = new_increment_machine();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+
+        let conf = Configuration::new_std(Tape::from("0111")).unwrap();
+        let until = |conf: &Configuration<char>| conf.state == State(2);
+        let expected = classic.execute_until(conf.clone(), until).unwrap();
+        assert_eq!(compiled.execute_until(conf, until).unwrap(), expected);
+    }
+
+    #[test]
+    fn fail_execute() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '0', 1, '1', Move::Right)]).unwrap();
+        let classic = Classic::new(program, '0').unwrap();
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+
+        let conf = Configuration::new_nrm(Tape::from("01")).unwrap();
+        let expected = classic.execute(conf.clone()).unwrap_err();
+        assert_eq!(compiled.execute(conf).unwrap_err(), expected);
+
+        let conf = Configuration::new_nrm(Tape::from("0#")).unwrap();
+        let error = compiled.execute(conf.clone()).unwrap_err();
+        assert_eq!(
+            error,
+            "execute error: tape symbol # is not in alphabet ['0', '1']"
+        );
+        let until = |conf: &Configuration<char>| conf.state == State(0);
+        assert_eq!(compiled.execute_until(conf, until).unwrap_err(), error);
+
+        let conf = Configuration::new(Tape::from("0"), 0, State(2)).unwrap();
+        let expected = classic.execute(conf.clone()).unwrap_err();
+        assert_eq!(compiled.execute(conf).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn boundary() {
+        let mut classic = new_increment_machine();
+
+        for boundary in [Boundary::Grow, Boundary::Stay, Boundary::Error] {
+            classic.set_boundary(boundary);
+            let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+            assert_eq!(compiled.boundary(), boundary);
+
+            let conf = Configuration::new_std(Tape::from("111")).unwrap();
+            let expected = classic.execute(conf.clone());
+            assert_eq!(compiled.execute(conf.clone()), expected);
+
+            // The interned tape follows the growth of the configuration tape
+            let until = |conf: &Configuration<char>| conf.state == State(0);
+            let expected = classic.execute_until(conf.clone(), until);
+            assert_eq!(compiled.execute_until(conf, until), expected);
+        }
+    }
+
+    #[test]
+    fn uncovered() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '1', 1, '0', Move::Right)]).unwrap();
+        let mut classic = Classic::new(program, '0').unwrap();
+
+        for policy in [Uncovered::Halt, Uncovered::Reject] {
+            classic.set_uncovered(policy);
+            let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+            assert_eq!(compiled.uncovered(), policy);
+
+            let conf = Configuration::new_nrm(Tape::from("110")).unwrap();
+            let expected = classic.execute(conf.clone());
+            assert_eq!(compiled.execute(conf), expected);
+        }
+
+        classic.set_uncovered(Uncovered::Stay);
+        let compiled: Compiled<char> = Compiled::from_classic(&classic).unwrap();
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let until = |conf: &Configuration<char>| conf.index() == 1;
+        let conf = compiled.execute_until(conf, until).unwrap();
+        let expected = classic.execute_once(conf.clone()).unwrap();
+        assert_eq!(compiled.execute_once(conf), Ok(expected));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let expected = classic.execute(conf.clone());
+        assert!(expected.is_err());
+        assert_eq!(compiled.execute(conf), expected);
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn execute() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(2));
+        program
+            .extend([
+                (1, Box::new('0'), 2, Box::new('0'), Move::Left),
+                (1, Box::new('1'), 1, Box::new('1'), Move::Right),
+                (2, Box::new('0'), 0, Box::new('1'), Move::None),
+                (2, Box::new('1'), 2, Box::new('1'), Move::Left),
+            ])
+            .unwrap();
+        let classic = Classic::new(program, Box::new('0')).unwrap();
+        let compiled: Compiled<Box<char>, u8> = Compiled::from_classic(&classic).unwrap();
+
+        let tape = Tape::new(['1', '1'].map(Box::new));
+        let expected = classic.translate_nrm(tape.clone()).unwrap();
+        assert_eq!(compiled.translate_nrm(tape), Ok(expected.clone()));
+        assert_eq!(expected, Tape::new(['1', '1', '1', '0'].map(Box::new)));
+    }
+}
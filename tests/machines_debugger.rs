@@ -75,7 +75,7 @@ mod clone {
         let machine = new_custom_machine();
         let mut debugger = Debugger::new(machine);
 
-        let conf = Configuration::new_nrm(Tape::new("   ".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_nrm(Tape::new("   ".chars().map(Box::new))).unwrap();
         let buffer = Rc::new(RefCell::new(String::new()));
 
         let c_buffer = buffer.clone();
@@ -230,12 +230,10 @@ mod clone_turing_machine {
         let machine = new_zerofy_machine();
         let debugger = Debugger::new(machine);
 
-        let conf =
-            Configuration::new_nrm(Tape::new("0110".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_nrm(Tape::new("0110".chars().map(Box::new))).unwrap();
         let result = debugger.execute(conf).unwrap();
 
-        let mut expected =
-            Configuration::new_nrm(Tape::new("0000".chars().map(|ch| Box::new(ch)))).unwrap();
+        let mut expected = Configuration::new_nrm(Tape::new("0000".chars().map(Box::new))).unwrap();
         expected.state = State(0);
 
         assert_eq!(expected, result);
@@ -246,18 +244,13 @@ mod clone_turing_machine {
         let machine = new_zerofy_machine();
         let debugger = Debugger::new(machine);
 
-        let conf =
-            Configuration::new_nrm(Tape::new("0110".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_nrm(Tape::new("0110".chars().map(Box::new))).unwrap();
         let result = debugger
             .execute_once(debugger.execute_once(conf).unwrap())
             .unwrap();
 
-        let expected = Configuration::new(
-            Tape::new("0110".chars().map(|ch| Box::new(ch))),
-            2,
-            State(2),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new("0110".chars().map(Box::new)), 2, State(2)).unwrap();
 
         assert_eq!(expected, result);
     }
@@ -267,20 +260,14 @@ mod clone_turing_machine {
         let machine = new_zerofy_machine();
         let debugger = Debugger::new(machine);
 
-        let conf =
-            Configuration::new_nrm(Tape::new("0110".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_nrm(Tape::new("0110".chars().map(Box::new))).unwrap();
 
         let result = debugger
             .execute_until(conf, |conf| conf.state == State(3))
             .unwrap();
 
         assert_eq!(
-            Configuration::new(
-                Tape::new("0110".chars().map(|ch| Box::new(ch))),
-                2,
-                State(3)
-            )
-            .unwrap(),
+            Configuration::new(Tape::new("0110".chars().map(Box::new)), 2, State(3)).unwrap(),
             result
         );
     }
@@ -302,10 +289,10 @@ mod clone_turing_machine {
         let debugger = Debugger::new(machine);
 
         let expected = debugger
-            .translate_std(Tape::new("010".chars().map(|ch| Box::new(ch))))
+            .translate_std(Tape::new("010".chars().map(Box::new)))
             .unwrap();
 
-        assert_eq!(expected, Tape::new("0101".chars().map(|ch| Box::new(ch))));
+        assert_eq!(expected, Tape::new("0101".chars().map(Box::new)));
     }
 
     #[test]
@@ -325,11 +312,63 @@ mod clone_turing_machine {
         let debugger = Debugger::new(machine);
 
         let result = debugger
-            .translate_nrm(Tape::new("010".chars().map(|ch| Box::new(ch))))
+            .translate_nrm(Tape::new("010".chars().map(Box::new)))
             .unwrap();
 
-        let expected = Tape::new("001".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("001".chars().map(Box::new));
 
         assert_eq!(expected, result);
     }
 }
+
+#[cfg(test)]
+mod copy_instruction_handler {
+    use super::*;
+    use turing_machine_rs::instruction::{Head, Tail};
+    use turing_machine_rs::machines::Uncovered;
+
+    #[test]
+    fn tail() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program
+            .extend([(1, '1', 1, '0', Move::Left), (1, '0', 0, '1', Move::Right)])
+            .unwrap();
+        let mut debugger = Debugger::new(Classic::new(program, '0').unwrap());
+
+        let buffer = Rc::new(RefCell::new(Vec::new()));
+        let i_buffer = buffer.clone();
+        debugger.set_i_handler(move |head, tail| {
+            i_buffer.borrow_mut().push((head.clone(), tail.clone()));
+        });
+        debugger.translate_nrm(Tape::from("1")).unwrap();
+
+        let expected = vec![
+            (
+                Head::new(State(1), '1'),
+                Tail::new(State(1), '0', Move::Left),
+            ),
+            // The tail symbol is the symbol under the head after the step
+            (
+                Head::new(State(1), '0'),
+                Tail::new(State(0), '0', Move::Right),
+            ),
+        ];
+        assert_eq!(expected, *buffer.borrow());
+    }
+
+    #[test]
+    fn stay() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '1', 1, '0', Move::Right)]).unwrap();
+        let mut machine = Classic::new(program, '0').unwrap();
+        machine.set_uncovered(Uncovered::Stay);
+        let mut debugger = Debugger::new(machine);
+
+        let error =
+            String::from("execute error: uncovered head (1, 0) stays forever by the Stay policy");
+        assert_eq!(debugger.translate_nrm(Tape::from("10")), Err(error.clone()));
+
+        debugger.set_c_handler(|_| {});
+        assert_eq!(debugger.translate_nrm(Tape::from("10")), Err(error));
+    }
+}
@@ -0,0 +1,273 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Decision, Lba};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_program(
+        alphabet: &str,
+        l_state: usize,
+        tuples: &[(usize, char, usize, char, Move)],
+    ) -> Program<char> {
+        let mut program = Program::new(alphabet.chars().collect(), State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        program
+    }
+
+    // Accepts the context-sensitive language a^n b^n c^n
+    fn new_abc_machine() -> Lba<char> {
+        #[rustfmt::skip]
+        let program = new_program("<>abcxyz", 6, &[
+            // Marks `a` and goes to `b` (or checks the rest)
+            (1, 'a', 2, 'x', Move::Right),
+            (1, 'y', 5, 'y', Move::Right),
+            (1, '>', 6, '>', Move::None),
+            // Marks `b` and goes to `c`
+            (2, 'a', 2, 'a', Move::Right),
+            (2, 'y', 2, 'y', Move::Right),
+            (2, 'b', 3, 'y', Move::Right),
+            // Marks `c`
+            (3, 'b', 3, 'b', Move::Right),
+            (3, 'z', 3, 'z', Move::Right),
+            (3, 'c', 4, 'z', Move::Left),
+            // Returns to the last marked `a`
+            (4, 'a', 4, 'a', Move::Left),
+            (4, 'b', 4, 'b', Move::Left),
+            (4, 'y', 4, 'y', Move::Left),
+            (4, 'z', 4, 'z', Move::Left),
+            (4, 'x', 1, 'x', Move::Right),
+            // Checks that only marked symbols are left
+            (5, 'y', 5, 'y', Move::Right),
+            (5, 'z', 5, 'z', Move::Right),
+            (5, '>', 6, '>', Move::None),
+        ]);
+        Lba::new(program, '<', '>', State(6)).unwrap()
+    }
+
+    fn words(alphabet: &[char], len: usize) -> Vec<String> {
+        let mut words = vec![String::new()];
+        for _ in 0..len {
+            words = words
+                .iter()
+                .flat_map(|word| {
+                    alphabet
+                        .iter()
+                        .map(move |symbol| format!("{}{}", word, symbol))
+                })
+                .collect();
+        }
+        words
+    }
+
+    #[test]
+    fn creation() {
+        let lba = new_abc_machine();
+        assert_eq!(lba.accept(), State(6));
+        assert_eq!(lba.markers(), (&'<', &'>'));
+        assert_eq!(lba.program().l_state(), State(6));
+    }
+
+    #[test]
+    fn configuration() {
+        let lba = new_abc_machine();
+
+        let conf = lba.configuration(Tape::from("ab")).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("<ab>"), 1, State(1)).unwrap()
+        );
+
+        let conf = lba.configuration(Tape::from("")).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("<>"), 1, State(1)).unwrap()
+        );
+
+        assert_eq!(
+            lba.configuration(Tape::from("a>")),
+            Err(String::from("lba error: input contains the end marker >"))
+        );
+        assert!(lba.configuration(Tape::from("ad")).is_err());
+    }
+
+    #[test]
+    fn decide_abc() {
+        let lba = new_abc_machine();
+
+        assert_eq!(lba.decide(Tape::from("")), Ok(Decision::Accept(1)));
+        for len in 0..=6 {
+            for word in words(&['a', 'b', 'c'], len) {
+                let n = len / 3;
+                let expected =
+                    len % 3 == 0 && word == "a".repeat(n) + &"b".repeat(n) + &"c".repeat(n);
+                assert_eq!(
+                    lba.accepts(Tape::from(word.as_str())),
+                    Ok(expected),
+                    "{}",
+                    word
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn decide_reject() {
+        let lba = new_abc_machine();
+        assert_eq!(lba.decide(Tape::from("ba")), Ok(Decision::Reject(0)));
+
+        // The halt is not the acceptance
+        let program = new_program("<>a", 2, &[(1, 'a', 0, 'a', Move::None)]);
+        let lba = Lba::new(program, '<', '>', State(2)).unwrap();
+        assert_eq!(lba.decide(Tape::from("a")), Ok(Decision::Reject(1)));
+    }
+
+    #[test]
+    fn decide_loop() {
+        // Stays on the same cell forever
+        let program = new_program("<>a", 2, &[(1, 'a', 1, 'a', Move::None)]);
+        let lba = Lba::new(program, '<', '>', State(2)).unwrap();
+        assert_eq!(
+            lba.decide(Tape::from("a")),
+            Ok(Decision::Loop {
+                start: 0,
+                period: 1
+            })
+        );
+    }
+
+    #[test]
+    fn decide_delayed_loop() {
+        // Bounces between `b` and the right marker after the first step
+        #[rustfmt::skip]
+        let program = new_program("<>ab", 4, &[
+            (1, 'a', 2, 'a', Move::Right),
+            (2, 'b', 3, 'b', Move::Right),
+            (3, '>', 2, '>', Move::Left),
+        ]);
+        let lba = Lba::new(program, '<', '>', State(4)).unwrap();
+        assert_eq!(
+            lba.decide(Tape::from("ab")),
+            Ok(Decision::Loop {
+                start: 1,
+                period: 2
+            })
+        );
+        assert_eq!(lba.accepts(Tape::from("ab")), Ok(false));
+    }
+
+    #[test]
+    fn fail_creation() {
+        let fail = |tuples: &[(usize, char, usize, char, Move)]| {
+            Lba::new(new_program("<>a", 2, tuples), '<', '>', State(2)).unwrap_err()
+        };
+
+        assert_eq!(
+            fail(&[(1, '<', 1, 'a', Move::Right)]),
+            "lba error: instruction (1, <) -> (1, a, >) overwrites the end marker <"
+        );
+        assert_eq!(
+            fail(&[(1, 'a', 1, '>', Move::Right)]),
+            "lba error: instruction (1, a) -> (1, >, >) writes the end marker >"
+        );
+        assert_eq!(
+            fail(&[(1, '>', 1, '>', Move::Right)]),
+            "lba error: instruction (1, >) -> (1, >, >) passes the end marker >"
+        );
+        assert_eq!(
+            fail(&[(1, '<', 1, '<', Move::Left)]),
+            "lba error: instruction (1, <) -> (1, <, <) passes the end marker <"
+        );
+
+        let program = new_program("<>a", 2, &[(2, 'a', 1, 'a', Move::Left)]);
+        assert_eq!(
+            Lba::new(program, '<', '>', State(2)),
+            Err(String::from(
+                "lba error: accept state 2 cannot have instruction (2, a) -> (1, a, <)"
+            ))
+        );
+
+        let program = new_program("<>a", 2, &[]);
+        assert_eq!(
+            Lba::new(program.clone(), '<', '<', State(2)),
+            Err(String::from(
+                "lba error: end markers must be different but both are <"
+            ))
+        );
+        assert_eq!(
+            Lba::new(program.clone(), '<', '#', State(2)),
+            Err(String::from(
+                "lba error: end marker # is not in alphabet ['<', '>', 'a']"
+            ))
+        );
+        assert_eq!(
+            Lba::new(program, '<', '>', State(0)),
+            Err(String::from(
+                "lba error: accept state 0 is not in program states 1..=2"
+            ))
+        );
+    }
+
+    #[test]
+    fn execute() {
+        let lba = new_abc_machine();
+
+        let conf = lba.configuration(Tape::from("abc")).unwrap();
+        let conf = lba.execute(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("<xyz>"), 4, State(6)).unwrap()
+        );
+
+        let conf = lba.configuration(Tape::from("ac")).unwrap();
+        assert_eq!(
+            lba.execute(conf),
+            Err(String::from(
+                "uncovered case: have no tail for head (2, c) in program"
+            ))
+        );
+
+        // The configuration without end markers
+        let conf = Configuration::new_nrm(Tape::from("a")).unwrap();
+        assert_eq!(
+            lba.execute(conf),
+            Err(String::from(
+                "lba error: head (1, a) leaves the bounded tape of length 1"
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn decide() {
+        let alphabet = ['<', '>', 'a', 'b'].map(Box::new).to_vec();
+        let mut program = Program::new(alphabet, State(3));
+        program
+            .extend([
+                (1, Box::new('a'), 1, Box::new('a'), Move::Right),
+                (1, Box::new('>'), 3, Box::new('>'), Move::None),
+                (1, Box::new('b'), 2, Box::new('b'), Move::Left),
+                (2, Box::new('a'), 1, Box::new('a'), Move::Right),
+            ])
+            .unwrap();
+        let lba = Lba::new(program, Box::new('<'), Box::new('>'), State(3)).unwrap();
+
+        let word = |word: &str| Tape::new(word.chars().map(Box::new));
+        assert_eq!(lba.decide(word("aa")), Ok(Decision::Accept(3)));
+        assert_eq!(lba.decide(word("b")), Ok(Decision::Reject(1)));
+        assert_eq!(
+            lba.decide(word("ab")),
+            Ok(Decision::Loop {
+                start: 1,
+                period: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, MetaMachine, Report};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_machine(l_state: usize, tuples: &[(usize, char, usize, char, Move)]) -> Classic<char> {
+        let mut program = Program::new(vec!['0', '1'], State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    fn new_fill_machine() -> Classic<char> {
+        new_machine(
+            1,
+            &[(1, '0', 0, '1', Move::Right), (1, '1', 1, '1', Move::Right)],
+        )
+    }
+
+    fn new_zerofy_machine() -> Classic<char> {
+        new_machine(
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Left)],
+        )
+    }
+
+    #[test]
+    fn creation() {
+        let machines = Tape::new([new_fill_machine(), new_zerofy_machine()]);
+        let mut meta = MetaMachine::new(machines.clone());
+        assert_eq!(meta.machines(), &machines);
+        assert_eq!(meta.reset(), State(1));
+
+        meta.set_reset(State(2));
+        assert_eq!(meta.reset(), State(2));
+    }
+
+    #[test]
+    fn run() {
+        let meta = MetaMachine::new(Tape::new([new_fill_machine(), new_zerofy_machine()]));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let reports = meta.run(conf, 100).unwrap();
+        assert_eq!(
+            reports,
+            vec![
+                Report {
+                    index: 0,
+                    steps: 2,
+                    configuration: Configuration::new(Tape::from("110"), 2, State(0)).unwrap(),
+                },
+                Report {
+                    index: 1,
+                    steps: 1,
+                    configuration: Configuration::new(Tape::from("110"), 2, State(0)).unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn run_empty() {
+        let meta: MetaMachine<Classic<char>, char> = MetaMachine::new(Tape::new([]));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        assert_eq!(meta.run(conf, 100), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn from_control() {
+        let fill = new_fill_machine();
+        let zerofy = new_zerofy_machine();
+
+        // Replaces the first machine by the zerofy machine
+        let mut program = Program::new(vec![fill.clone(), zerofy.clone()], State(1));
+        program
+            .extend([
+                (1, fill.clone(), 0, zerofy.clone(), Move::None),
+                (1, zerofy.clone(), 0, zerofy.clone(), Move::None),
+            ])
+            .unwrap();
+        let control = Classic::new(program, fill.clone()).unwrap();
+
+        let machines = Tape::new([fill.clone(), fill.clone()]);
+        let meta = MetaMachine::from_control(&control, machines).unwrap();
+        assert_eq!(meta.machines(), &Tape::new([zerofy, fill]));
+
+        let conf = Configuration::new_nrm(Tape::from("1")).unwrap();
+        let reports = meta.run(conf, 100).unwrap();
+        let last = reports.last().unwrap();
+        assert_eq!(last.configuration.tape(), &Tape::from("10"));
+    }
+
+    #[test]
+    fn to_sequence() {
+        let mut meta = MetaMachine::new(Tape::new([new_fill_machine(), new_zerofy_machine()]));
+        meta.set_reset(State(1));
+        let sequence = meta.to_sequence();
+        assert_eq!(sequence.len(), 2);
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let reports = meta.run(conf.clone(), 100).unwrap();
+        let result = sequence.execute(conf).unwrap();
+        assert_eq!(result, reports[1].configuration);
+    }
+
+    #[test]
+    fn fail_run() {
+        let meta = MetaMachine::new(Tape::new([
+            new_fill_machine(),
+            new_machine(1, &[(1, '1', 0, '1', Move::None)]),
+        ]));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let error = meta.run(conf, 100).unwrap_err();
+        assert!(error.starts_with("meta error: machine 1 failed: uncovered case"));
+    }
+
+    #[test]
+    fn run_steps() {
+        let endless = new_machine(
+            1,
+            &[(1, '0', 1, '0', Move::Right), (1, '1', 1, '1', Move::Right)],
+        );
+        let meta = MetaMachine::new(Tape::new([new_fill_machine(), endless]));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        assert_eq!(
+            meta.run(conf.clone(), 10),
+            Err("meta error: machine 1 exceeds 10 steps".to_string())
+        );
+        assert_eq!(
+            meta.run(conf, 1),
+            Err("meta error: machine 0 exceeds 1 steps".to_string())
+        );
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn run() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('1'), Move::Right),
+                (1, Box::new('1'), 1, Box::new('1'), Move::Right),
+            ])
+            .unwrap();
+        let fill = Classic::new(program, Box::new('0')).unwrap();
+        let meta = MetaMachine::new(Tape::new([fill.clone(), fill]));
+
+        let conf = Configuration::new_nrm(Tape::new([Box::new('1')])).unwrap();
+        let reports = meta.run(conf, 100).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(
+            reports[1].configuration.tape(),
+            &Tape::new(['1', '1', '1', '0'].map(Box::new))
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, Sequence, Tracer, Uncovered};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_machine(
+        alphabet: &str,
+        l_state: usize,
+        tuples: &[(usize, char, usize, char, Move)],
+    ) -> Classic<char> {
+        let mut program = Program::new(alphabet.chars().collect(), State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    fn new_fill_machine() -> Classic<char> {
+        new_machine(
+            "01",
+            1,
+            &[(1, '0', 0, '1', Move::Right), (1, '1', 1, '1', Move::Right)],
+        )
+    }
+
+    fn new_mark_machine() -> Classic<char> {
+        new_machine(
+            "012",
+            1,
+            &[(1, '0', 0, '2', Move::None), (1, '1', 0, '2', Move::None)],
+        )
+    }
+
+    fn new_sequence() -> Sequence<char> {
+        let mut sequence = Sequence::new();
+        sequence.push(new_fill_machine());
+        sequence.push(Tracer::new(new_mark_machine()));
+        sequence
+    }
+
+    #[test]
+    fn creation() {
+        let sequence: Sequence<char> = Sequence::new();
+        assert!(sequence.is_empty());
+        assert_eq!(sequence.len(), 0);
+        assert_eq!(sequence.reset(), State(1));
+        assert_eq!(sequence.blank(), None);
+
+        let sequence = new_sequence();
+        assert!(!sequence.is_empty());
+        assert_eq!(sequence.len(), 2);
+        assert_eq!(sequence.blank(), Some(&'0'));
+    }
+
+    #[test]
+    fn execute() {
+        let sequence = new_sequence();
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let result = sequence.execute(conf);
+        let expected = Configuration::new(Tape::from("112"), 2, State(0));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn stage() {
+        let sequence = new_sequence();
+        assert_eq!(sequence.stage(State(0)), None);
+        assert_eq!(sequence.stage(State(1)), Some((0, State(1))));
+        assert_eq!(sequence.stage(State(2)), Some((1, State(1))));
+        assert_eq!(sequence.stage(State(5)), Some((0, State(3))));
+        assert_eq!(sequence.state(1, State(1)), Ok(State(2)));
+        assert_eq!(sequence.state(0, State(3)), Ok(State(5)));
+        assert_eq!(sequence.state(1, State(0)), Ok(State(0)));
+        assert!(sequence.state(2, State(1)).is_err());
+        assert!(sequence.state(1, State(usize::MAX)).is_err());
+
+        let empty: Sequence<char> = Sequence::new();
+        assert_eq!(empty.stage(State(1)), None);
+        assert!(empty.state(0, State(1)).is_err());
+    }
+
+    #[test]
+    fn execute_once() {
+        let sequence = new_sequence();
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let conf = sequence.execute_once(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("10"), 1, State(1)).unwrap()
+        );
+
+        // The first machine halts and the second one starts by the state 1
+        let conf = sequence.execute_once(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("110"), 2, State(2)).unwrap()
+        );
+
+        let conf = sequence.execute_once(conf).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("112"), 2, State(0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn execute_until() {
+        let sequence = new_sequence();
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let conf = sequence
+            .execute_until(conf, |conf| conf.len() == 3)
+            .unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("110"), 2, State(2)).unwrap()
+        );
+
+        // Other configurations still start from the first machine
+        let other = Configuration::new_nrm(Tape::from("0")).unwrap();
+        let result = sequence.execute(other);
+        let expected = Configuration::new(Tape::from("12"), 1, State(0));
+        assert_eq!(result, expected);
+
+        // The execution is continued by the second machine
+        let result = sequence.execute(conf);
+        let expected = Configuration::new(Tape::from("112"), 2, State(0));
+        assert_eq!(result, expected);
+
+        // The execution stops when the last machine halts
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let result = sequence.execute_until(conf, |_| false);
+        let expected = Configuration::new(Tape::from("112"), 2, State(0));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn set_reset() {
+        let mut sequence = Sequence::new();
+        sequence.push(new_fill_machine());
+        sequence.push(new_machine(
+            "012",
+            2,
+            &[(2, '0', 0, '2', Move::None), (2, '1', 0, '2', Move::None)],
+        ));
+        sequence.set_reset(State(2));
+        assert_eq!(sequence.reset(), State(2));
+
+        let result = sequence.translate_nrm(Tape::from("10"));
+        assert_eq!(result, Ok(Tape::from("112")));
+    }
+
+    #[test]
+    fn translate_empty_word() {
+        let sequence = new_sequence();
+
+        let result = sequence.translate_word(Tape::from(""), false);
+        assert_eq!(result, Ok(vec!['1', '2']));
+
+        let sequence: Sequence<char> = Sequence::new();
+        let result = sequence.translate_word(Tape::from(""), false);
+        assert_eq!(
+            result,
+            Err(String::from("translate error: machine has no blank symbol"))
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let sequence: Sequence<char> = Sequence::new();
+
+        let conf = Configuration::new_nrm(Tape::from("01")).unwrap();
+        let result = sequence.execute(conf);
+        let expected = Configuration::new(Tape::from("01"), 0, State(0));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn fail_execute() {
+        let mut sequence = Sequence::new();
+        sequence.push(new_fill_machine());
+        sequence.push(new_machine("01", 1, &[(1, '1', 0, '1', Move::None)]));
+
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let error = sequence.execute(conf).unwrap_err();
+        assert!(error.starts_with("uncovered case"));
+    }
+
+    #[test]
+    fn stay() {
+        let mut machine = new_machine("01", 1, &[(1, '1', 0, '1', Move::None)]);
+        machine.set_uncovered(Uncovered::Stay);
+        let mut sequence = Sequence::new();
+        sequence.push(new_fill_machine());
+        sequence.push(Tracer::new(machine));
+
+        let error =
+            String::from("execute error: uncovered head (1, 0) stays forever by the Stay policy");
+        let result = sequence.translate_nrm(Tape::from("10"));
+        assert_eq!(result, Err(error));
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    fn new_machine(
+        alphabet: &str,
+        tuples: &[(usize, char, usize, char, Move)],
+    ) -> Classic<Box<char>> {
+        let mut program = Program::new(alphabet.chars().map(Box::new).collect(), State(1));
+        program
+            .extend(
+                tuples
+                    .iter()
+                    .map(|&(h_state, h_symbol, t_state, t_symbol, movement)| {
+                        (
+                            h_state,
+                            Box::new(h_symbol),
+                            t_state,
+                            Box::new(t_symbol),
+                            movement,
+                        )
+                    }),
+            )
+            .unwrap();
+        Classic::new(program, Box::new('0')).unwrap()
+    }
+
+    #[test]
+    fn execute() {
+        let mut sequence = Sequence::new();
+        sequence.push(new_machine(
+            "01",
+            &[(1, '0', 0, '1', Move::Right), (1, '1', 1, '1', Move::Right)],
+        ));
+        sequence.push(Tracer::new(new_machine(
+            "012",
+            &[(1, '0', 0, '2', Move::None), (1, '1', 0, '2', Move::None)],
+        )));
+
+        let tape = Tape::new(['1', '0'].map(Box::new));
+        let result = sequence.translate_nrm(tape);
+        assert_eq!(result, Ok(Tape::new(['1', '1', '2'].map(Box::new))));
+    }
+}
@@ -0,0 +1,274 @@
+use turing_machine_rs::instruction::{Event, Head, Instruction, Move, State};
+use turing_machine_rs::machines::{Classic, Tracer, Uncovered};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Boundary, Configuration, Tape};
+use turing_machine_rs::trace::{Step, Trace};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_zerofy_machine() -> Classic<char> {
+        let mut program = Program::new(vec!['0', '1'], State(4));
+        program
+            .extend([
+                (1, '0', 2, '0', Move::Right),
+                (2, '0', 3, '0', Move::Left),
+                (2, '1', 2, '1', Move::Right),
+                (3, '0', 0, '0', Move::None),
+                (3, '1', 4, '0', Move::None),
+                (4, '0', 3, '0', Move::Left),
+            ])
+            .unwrap();
+        Classic::new(program, '0').unwrap()
+    }
+
+    #[test]
+    fn creation() {
+        let tracer = Tracer::new(new_zerofy_machine());
+        assert_eq!(tracer.trace(), None);
+    }
+
+    #[test]
+    fn execute() {
+        let machine = new_zerofy_machine();
+        let tracer = Tracer::new(machine.clone());
+
+        let conf = Configuration::new_nrm(Tape::from("011")).unwrap();
+        let result = tracer.execute(conf.clone()).unwrap();
+
+        assert_eq!(machine.execute(conf).unwrap(), result);
+
+        let trace = tracer.take_trace().unwrap();
+        assert_eq!(trace.tape(), &['0', '1', '1']);
+        assert_eq!(trace.index(), 0);
+        assert_eq!(trace.state(), State(1));
+        assert_eq!(trace.len(), 9);
+        assert_eq!(
+            trace.steps()[0],
+            Step {
+                event: Event::Applied {
+                    instruction: Instruction::build(State(1), '0', State(2), '0', Move::Right),
+                    movement: Move::Right,
+                },
+                position: 0,
+            }
+        );
+        assert_eq!(
+            trace.steps()[4],
+            Step {
+                event: Event::Applied {
+                    instruction: Instruction::build(State(3), '1', State(4), '0', Move::None),
+                    movement: Move::None,
+                },
+                position: 2,
+            }
+        );
+        assert_eq!(tracer.trace(), None);
+    }
+
+    /// Machine which implements only required methods.
+    struct Once(Classic<char>);
+
+    impl TuringMachine<char> for Once {
+        fn execute_once(&self, conf: Configuration<char>) -> Result<Configuration<char>, String> {
+            self.0.execute_once(conf)
+        }
+
+        fn execute_until(
+            &self,
+            conf: Configuration<char>,
+            until: impl Fn(&Configuration<char>) -> bool,
+        ) -> Result<Configuration<char>, String> {
+            self.0.execute_until(conf, until)
+        }
+    }
+
+    #[test]
+    fn inferred_events() {
+        let machine = new_zerofy_machine();
+        let tracer = Tracer::new(machine.clone());
+        let inferred = Tracer::new(Once(machine));
+
+        let conf = Configuration::new_nrm(Tape::from("011")).unwrap();
+        let result = tracer.execute(conf.clone());
+        assert_eq!(inferred.execute(conf), result);
+        assert_eq!(inferred.take_trace(), tracer.take_trace());
+    }
+
+    #[test]
+    fn left_growth() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program
+            .extend([(1, '0', 0, '1', Move::None), (1, '1', 1, '1', Move::Left)])
+            .unwrap();
+        let tracer = Tracer::new(Classic::new(program.clone(), '0').unwrap());
+
+        let result = tracer.translate_nrm(Tape::from("1")).unwrap();
+        assert_eq!(result, Tape::from("11"));
+
+        let trace = tracer.take_trace().unwrap();
+        let positions: Vec<isize> = trace.steps().iter().map(|step| step.position).collect();
+        assert_eq!(positions, vec![0, -1]);
+        trace.verify(&program).unwrap();
+    }
+
+    #[test]
+    fn continued_trace() {
+        let tracer = Tracer::new(new_zerofy_machine());
+
+        let conf = Configuration::new_nrm(Tape::from("01")).unwrap();
+        let conf = tracer.execute_once(conf).unwrap();
+        tracer.execute_once(conf).unwrap();
+
+        assert_eq!(tracer.trace().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn boundary() {
+        let mut program = Program::new(vec!['0', '1'], State(2));
+        program
+            .extend([
+                (1, '0', 2, '1', Move::Left),
+                (2, '0', 0, '0', Move::None),
+                (2, '1', 0, '1', Move::None),
+            ])
+            .unwrap();
+        let mut machine = Classic::new(program.clone(), '0').unwrap();
+        let first = Event::Applied {
+            instruction: Instruction::build(State(1), '0', State(2), '1', Move::Left),
+            movement: Move::Left,
+        };
+
+        machine.set_boundary(Boundary::Grow);
+        let tracer = Tracer::new(machine.clone());
+        let result = tracer.translate_std(Tape::from("0")).unwrap();
+        assert_eq!(result, Tape::from("01"));
+        let trace = tracer.take_trace().unwrap();
+        trace.verify(&program).unwrap();
+        assert_eq!(trace.steps()[0].event, first);
+        let positions: Vec<isize> = trace.steps().iter().map(|step| step.position).collect();
+        assert_eq!(positions, vec![0, -1]);
+
+        machine.set_boundary(Boundary::Stay);
+        let tracer = Tracer::new(machine.clone());
+        let result = tracer.translate_std(Tape::from("0")).unwrap();
+        assert_eq!(result, Tape::from("1"));
+        let trace = tracer.take_trace().unwrap();
+        trace.verify(&program).unwrap();
+        assert_eq!(
+            trace.steps()[0].event,
+            Event::Applied {
+                instruction: Instruction::build(State(1), '0', State(2), '1', Move::Left),
+                movement: Move::None,
+            }
+        );
+        let positions: Vec<isize> = trace.steps().iter().map(|step| step.position).collect();
+        assert_eq!(positions, vec![0, 0]);
+        let imported = Trace::from_jsonl(&trace.to_jsonl()).unwrap();
+        assert_eq!(imported.steps(), trace.steps());
+        imported.verify(&program).unwrap();
+
+        machine.set_boundary(Boundary::Error);
+        let tracer = Tracer::new(machine.clone());
+        assert!(tracer.translate_std(Tape::from("0")).is_err());
+        let trace = tracer.take_trace().unwrap();
+        trace.verify(&program).unwrap();
+        assert_eq!(trace.len(), 0);
+    }
+
+    #[test]
+    fn uncovered() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '1', 1, '0', Move::Right)]).unwrap();
+        let mut machine = Classic::new(program.clone(), '0').unwrap();
+
+        machine.set_uncovered(Uncovered::Halt);
+        let tracer = Tracer::new(machine.clone());
+        let result = tracer.translate_nrm(Tape::from("10")).unwrap();
+        assert_eq!(result, Tape::from("00"));
+
+        let trace = tracer.take_trace().unwrap();
+        trace.verify(&program).unwrap();
+        assert_eq!(trace.len(), 2);
+        assert_eq!(
+            trace.steps()[1].event,
+            Event::Uncovered {
+                head: Head::new(State(1), '0'),
+                state: State(0),
+            }
+        );
+
+        machine.set_uncovered(Uncovered::Stay);
+        let tracer = Tracer::new(machine);
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        let steps = std::cell::Cell::new(0);
+        let until = |_: &Configuration<char>| {
+            steps.set(steps.get() + 1);
+            steps.get() > 3
+        };
+        let conf = tracer.execute_until(conf, until).unwrap();
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("00"), 1, State(1)).unwrap()
+        );
+
+        let trace = tracer.take_trace().unwrap();
+        trace.verify(&program).unwrap();
+        assert_eq!(trace.len(), 3);
+        let stay = Event::Uncovered {
+            head: Head::new(State(1), '0'),
+            state: State(1),
+        };
+        assert_eq!(trace.steps()[1].event, stay);
+        assert_eq!(trace.steps()[2].event, stay);
+
+        // The execution until the halt never ends
+        let result = tracer.translate_nrm(Tape::from("10"));
+        assert_eq!(
+            result,
+            Err(String::from(
+                "execute error: uncovered head (1, 0) stays forever by the Stay policy"
+            ))
+        );
+        assert_eq!(tracer.take_trace().unwrap().steps()[1].event, stay);
+    }
+
+    #[test]
+    fn fail_execute() {
+        let program = Program::new(vec!['0', '1'], State(1));
+        let tracer = Tracer::new(Classic::new(program, '0').unwrap());
+
+        let conf = Configuration::new_nrm(Tape::from("01")).unwrap();
+        assert!(tracer.execute(conf).is_err());
+        assert_eq!(tracer.take_trace().unwrap().len(), 0);
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn execute() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('0'), Move::None),
+                (1, Box::new('1'), 1, Box::new('0'), Move::Right),
+            ])
+            .unwrap();
+        let tracer = Tracer::new(Classic::new(program.clone(), Box::new('0')).unwrap());
+
+        let result = tracer
+            .translate_nrm(Tape::new("11".chars().map(Box::new)))
+            .unwrap();
+        assert_eq!(result, Tape::new("000".chars().map(Box::new)));
+
+        let trace = tracer.take_trace().unwrap();
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace.steps()[2].position, 2);
+        trace.verify(&program).unwrap();
+    }
+}
@@ -11,6 +11,12 @@ mod copy {
         let _ = Program::new(vec![' '], State(1));
     }
 
+    #[test]
+    fn creation_large_l_state() {
+        let program = Program::new(vec![' ', '0'], State(usize::MAX));
+        assert_eq!(program.l_state(), State(usize::MAX));
+    }
+
     #[test]
     fn alphabet() {
         let program = Program::new(vec![' '], State(1));
@@ -51,6 +57,31 @@ mod copy {
         origin.get(&Head::new(State(2), '0')).unwrap();
     }
 
+    #[test]
+    fn success_remove() {
+        let mut origin = Program::new(vec!['0', '1'], State(1));
+        let inst = Instruction::new(
+            Head::new(State(1), '0'),
+            Tail::new(State(1), '0', Move::Right),
+        );
+        origin.insert(inst.clone()).unwrap();
+        assert_eq!(origin.instructions(), &vec![inst.clone()]);
+
+        let result = origin.remove(&Head::new(State(1), '0')).unwrap();
+        assert_eq!(Some(inst), result);
+        assert!(origin.instructions().is_empty());
+
+        let result = origin.remove(&Head::new(State(1), '0')).unwrap();
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fail_remove() {
+        let mut origin = Program::new(vec!['0', '1'], State(1));
+        origin.remove(&Head::new(State(2), '0')).unwrap();
+    }
+
     #[test]
     fn l_state() {
         let origin = Program::new(vec!['0', '1'], State(1));
@@ -240,6 +271,15 @@ mod copy_with {
 
         origin.with(&extension).unwrap();
     }
+
+    #[test]
+    fn fail_with_large_l_state() {
+        let origin = Program::new(vec!['0'], State(usize::MAX));
+        let extension = Program::new(vec!['0'], State(1));
+
+        let error = origin.with(&extension).unwrap_err();
+        assert!(error.starts_with("extend error: last states"));
+    }
 }
 
 #[cfg(test)]
@@ -251,6 +291,12 @@ mod clone {
         let _ = Program::new(vec![Box::new(' ')], State(1));
     }
 
+    #[test]
+    fn creation_large_l_state() {
+        let program = Program::new(vec![Box::new(' '), Box::new('0')], State(usize::MAX));
+        assert_eq!(program.l_state(), State(usize::MAX));
+    }
+
     #[test]
     fn alphabet() {
         let program = Program::new(vec![Box::new(' ')], State(1));
@@ -285,6 +331,31 @@ mod clone {
         origin.get(&Head::new(State(2), Box::new('0'))).unwrap();
     }
 
+    #[test]
+    fn success_remove() {
+        let mut origin = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        let inst = Instruction::new(
+            Head::new(State(1), Box::new('0')),
+            Tail::new(State(1), Box::new('0'), Move::Right),
+        );
+        origin.insert(inst.clone()).unwrap();
+        assert_eq!(origin.instructions(), &vec![inst.clone()]);
+
+        let result = origin.remove(&Head::new(State(1), Box::new('0'))).unwrap();
+        assert_eq!(Some(inst), result);
+        assert!(origin.instructions().is_empty());
+
+        let result = origin.remove(&Head::new(State(1), Box::new('0'))).unwrap();
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fail_remove() {
+        let mut origin = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        origin.remove(&Head::new(State(2), Box::new('0'))).unwrap();
+    }
+
     #[test]
     fn l_state() {
         let origin = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
@@ -477,4 +548,13 @@ mod clone_with {
 
         origin.with(&extension).unwrap();
     }
+
+    #[test]
+    fn fail_with_large_l_state() {
+        let origin = Program::new(vec![Box::new('0')], State(usize::MAX));
+        let extension = Program::new(vec![Box::new('0')], State(1));
+
+        let error = origin.with(&extension).unwrap_err();
+        assert!(error.starts_with("extend error: last states"));
+    }
 }
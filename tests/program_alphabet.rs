@@ -0,0 +1,129 @@
+use turing_machine_rs::instruction::State;
+use turing_machine_rs::program::{Alphabet, Program};
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn creation() {
+        let alphabet = Alphabet::new(['0', '1', '#']).unwrap();
+        assert_eq!(alphabet.as_vec(), &vec!['0', '1', '#']);
+        assert_eq!(alphabet.len(), 3);
+        assert!(!alphabet.is_empty());
+
+        let alphabet: Alphabet<char> = Alphabet::new([]).unwrap();
+        assert!(alphabet.is_empty());
+    }
+
+    #[test]
+    fn fail_creation() {
+        let error = Alphabet::new(['0', '1', '0']).unwrap_err();
+        assert_eq!(error, "alphabet error: symbol 0 is duplicated");
+    }
+
+    #[test]
+    fn from_vec() {
+        let alphabet = Alphabet::from(vec!['0', '1', '0', '1']);
+        assert_eq!(alphabet.as_vec(), &vec!['0', '1']);
+    }
+
+    #[test]
+    fn index() {
+        let alphabet = Alphabet::new(['_', '0', '1']).unwrap();
+        assert_eq!(alphabet.index(&'_'), Some(0));
+        assert_eq!(alphabet.index(&'1'), Some(2));
+        assert_eq!(alphabet.index(&'#'), None);
+        assert_eq!(alphabet.get(1), Some(&'0'));
+        assert_eq!(alphabet.get(3), None);
+        assert!(alphabet.contains(&'0'));
+        assert!(!alphabet.contains(&'#'));
+    }
+
+    #[test]
+    fn equality() {
+        let binary = Alphabet::new(['0', '1']).unwrap();
+        assert_eq!(binary, Alphabet::new(['1', '0']).unwrap());
+        assert_ne!(binary, Alphabet::new(['0']).unwrap());
+        assert_ne!(binary, Alphabet::new(['0', '1', '#']).unwrap());
+    }
+
+    #[test]
+    fn subset_and_union() {
+        let binary = Alphabet::new(['0', '1']).unwrap();
+        let marked = Alphabet::new(['#', '1']).unwrap();
+
+        assert!(binary.is_subset(&binary));
+        assert!(!binary.is_subset(&marked));
+
+        let union = binary.union(&marked);
+        assert_eq!(union.as_vec(), &vec!['0', '1', '#']);
+        assert!(binary.is_subset(&union));
+        assert!(marked.is_subset(&union));
+    }
+
+    #[test]
+    fn iteration_and_debug() {
+        let alphabet = Alphabet::new(['0', '1']).unwrap();
+        let symbols: Vec<char> = alphabet.iter().cloned().collect();
+        assert_eq!(symbols, vec!['0', '1']);
+        let symbols: Vec<&char> = (&alphabet).into_iter().collect();
+        assert_eq!(symbols, vec![&'0', &'1']);
+        assert_eq!(format!("{:?}", alphabet), "['0', '1']");
+    }
+
+    #[test]
+    fn program() {
+        let program = Program::new(vec!['0', '1'], State(1));
+        assert_eq!(program.alphabet(), &vec!['0', '1']);
+
+        let error = Program::try_new(vec!['0', '1', '0'], State(1)).unwrap_err();
+        assert_eq!(error, "alphabet error: symbol 0 is duplicated");
+
+        let alphabet = Alphabet::new(['1', '0']).unwrap();
+        let other = Program::from_alphabet(alphabet.clone(), State(1));
+        assert_eq!(other.symbols(), &alphabet);
+        assert_eq!(program.symbols(), other.symbols());
+    }
+
+    #[test]
+    #[should_panic(expected = "alphabet error: symbol 0 is duplicated")]
+    fn program_duplicates() {
+        let _ = Program::new(vec!['0', '1', '0'], State(1));
+    }
+
+    #[test]
+    fn fail_parse_duplicates() {
+        let error = "alphabet: 0 1 0".parse::<Program<char>>().unwrap_err();
+        assert_eq!(
+            error,
+            "parse error: line 1: alphabet error: symbol 0 is duplicated"
+        );
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn creation() {
+        let alphabet = Alphabet::new([Box::new('0'), Box::new('1')]).unwrap();
+        assert_eq!(alphabet.index(&Box::new('1')), Some(1));
+
+        let error = Alphabet::new([Box::new('0'), Box::new('0')]).unwrap_err();
+        assert_eq!(error, "alphabet error: symbol 0 is duplicated");
+    }
+
+    #[test]
+    fn union() {
+        let binary = Alphabet::new([Box::new('0'), Box::new('1')]).unwrap();
+        let marked = Alphabet::new([Box::new('#')]).unwrap();
+        let union = binary.union(&marked);
+        assert_eq!(
+            union.as_vec(),
+            &vec![Box::new('0'), Box::new('1'), Box::new('#')]
+        );
+        assert_eq!(union, marked.union(&binary));
+    }
+}
@@ -0,0 +1,406 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::With;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_program(l_state: usize, tuples: &[(usize, char, usize, char, Move)]) -> Program<char> {
+        let mut program = Program::new(vec!['0', '1'], State(l_state));
+        program.extend(tuples.iter().cloned()).unwrap();
+        program
+    }
+
+    fn new_read_program() -> Program<char> {
+        new_program(
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 0, '1', Move::None)],
+        )
+    }
+
+    fn new_right_program() -> Program<char> {
+        new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Right), (1, '1', 0, '1', Move::Right)],
+        )
+    }
+
+    fn new_left_program() -> Program<char> {
+        new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Left), (1, '1', 0, '1', Move::Left)],
+        )
+    }
+
+    #[test]
+    fn branch_on_symbol() {
+        let program = new_read_program()
+            .branch_on_symbol(&['1'], &new_right_program(), &new_left_program())
+            .unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(4, &[
+            (1, '0', 2, '0', Move::None),
+            (1, '1', 2, '1', Move::None),
+            // Dispatch
+            (2, '0', 4, '0', Move::None),
+            (2, '1', 3, '1', Move::None),
+            // Then
+            (3, '0', 0, '0', Move::Right),
+            (3, '1', 0, '1', Move::Right),
+            // Otherwise
+            (4, '0', 0, '0', Move::Left),
+            (4, '1', 0, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn branch_on_symbol_empty() {
+        let empty = Program::new(vec!['0', '1'], State(0));
+        let program = new_read_program()
+            .branch_on_symbol(&['0', '1'], &empty, &new_left_program())
+            .unwrap();
+
+        assert_eq!(program.l_state(), State(3));
+        let dispatch: Vec<State> = program.instructions()[2..4]
+            .iter()
+            .map(|inst| inst.tail.state)
+            .collect();
+        assert_eq!(dispatch, vec![State(0), State(0)]);
+    }
+
+    #[test]
+    fn fail_branch_on_symbol() {
+        let other = Program::new(vec!['0'], State(1));
+        let error = new_read_program()
+            .branch_on_symbol(&['1'], &other, &new_left_program())
+            .unwrap_err();
+        assert!(error.starts_with("branch error: alphabet"));
+
+        let error = new_read_program()
+            .branch_on_symbol(&['2'], &new_right_program(), &new_left_program())
+            .unwrap_err();
+        assert!(error.starts_with("branch error: symbol 2 is not in alphabet"));
+
+        let large = Program::new(vec!['0', '1'], State(usize::MAX));
+        let error = new_read_program()
+            .branch_on_symbol(&['1'], &large, &new_left_program())
+            .unwrap_err();
+        assert!(error.starts_with("branch error: last states"));
+    }
+
+    #[test]
+    fn branch_on_state() {
+        let test = new_program(
+            2,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 2, '1', Move::None)],
+        );
+        let program = test
+            .branch_on_state(State(2), &new_right_program(), &new_left_program())
+            .unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(4, &[
+            (1, '0', 4, '0', Move::None),
+            (1, '1', 3, '1', Move::None),
+            // Then
+            (3, '0', 0, '0', Move::Right),
+            (3, '1', 0, '1', Move::Right),
+            // Otherwise
+            (4, '0', 0, '0', Move::Left),
+            (4, '1', 0, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn fail_branch_on_state() {
+        let test = new_program(
+            2,
+            &[(1, '0', 0, '0', Move::None), (2, '1', 2, '1', Move::None)],
+        );
+        for accept in [State(0), State(2), State(3)] {
+            let error = test
+                .branch_on_state(accept, &new_right_program(), &new_left_program())
+                .unwrap_err();
+            assert!(error.starts_with("branch error: accept state"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn repeat_while() {
+        let program = new_right_program().repeat_while(&['1']).unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(2, &[
+            // Guard
+            (1, '0', 0, '0', Move::None),
+            (1, '1', 2, '1', Move::None),
+            // Body
+            (2, '0', 1, '0', Move::Right),
+            (2, '1', 1, '1', Move::Right),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn repeat_while_empty() {
+        let empty = Program::new(vec!['0', '1'], State(0));
+        let program = empty.repeat_while(&['0', '1']).unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(1, &[
+            (1, '0', 0, '0', Move::None),
+            (1, '1', 0, '1', Move::None),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn fail_repeat_while() {
+        let error = new_right_program().repeat_while(&['2']).unwrap_err();
+        assert!(error.starts_with("while error: symbol 2 is not in alphabet"));
+
+        let large = Program::new(vec!['0', '1'], State(usize::MAX));
+        let error = large.repeat_while(&['1']).unwrap_err();
+        assert!(error.starts_with("while error: last states"));
+    }
+
+    #[test]
+    fn with_different_order() {
+        let mut other = Program::new(vec!['1', '0'], State(1));
+        other.extend([(1, '1', 0, '0', Move::None)]).unwrap();
+        let program = new_right_program().with(&other).unwrap();
+
+        assert_eq!(program.alphabet(), &vec!['0', '1']);
+        assert_eq!(program.l_state(), State(2));
+    }
+
+    #[test]
+    fn with_union() {
+        let mut other = Program::new(vec!['#', '1', '0'], State(1));
+        other
+            .extend([(1, '#', 0, '1', Move::None), (1, '1', 0, '#', Move::None)])
+            .unwrap();
+        let program = new_right_program().with_union(&other).unwrap();
+
+        let mut expected = Program::new(vec!['0', '1', '#'], State(2));
+        #[rustfmt::skip]
+        expected.extend([
+            (1, '0', 2, '0', Move::Right),
+            (1, '1', 2, '1', Move::Right),
+            (2, '#', 0, '1', Move::None),
+            (2, '1', 0, '#', Move::None),
+        ]).unwrap();
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn with_mapping() {
+        let mut other = Program::new(vec!['0', 'a', 'b'], State(1));
+        other
+            .extend([(1, 'a', 0, 'b', Move::Left), (1, 'b', 0, '0', Move::None)])
+            .unwrap();
+        let program = new_right_program()
+            .with_mapping(&other, &[('a', '1'), ('b', '#')])
+            .unwrap();
+
+        let mut expected = Program::new(vec!['0', '1', '#'], State(2));
+        #[rustfmt::skip]
+        expected.extend([
+            (1, '0', 2, '0', Move::Right),
+            (1, '1', 2, '1', Move::Right),
+            (2, '1', 0, '#', Move::Left),
+            (2, '#', 0, '0', Move::None),
+        ]).unwrap();
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn fail_with_mapping() {
+        let other = Program::new(vec!['0', 'a', 'b'], State(1));
+
+        let error = new_right_program()
+            .with_mapping(&other, &[('c', '1')])
+            .unwrap_err();
+        assert!(error.starts_with("with error: symbol c is not in alphabet"));
+
+        let error = new_right_program()
+            .with_mapping(&other, &[('a', '1'), ('b', '1')])
+            .unwrap_err();
+        assert!(error.contains("merges symbols a and b into 1"), "{}", error);
+
+        let error = new_right_program()
+            .with_mapping(&other, &[('a', '0')])
+            .unwrap_err();
+        assert!(error.contains("merges symbols 0 and a into 0"), "{}", error);
+    }
+
+    #[test]
+    fn without_stay() {
+        #[rustfmt::skip]
+        let program = new_program(2, &[
+            (1, '0', 2, '1', Move::None),
+            (1, '1', 1, '0', Move::Right),
+            (2, '0', 0, '0', Move::None),
+            (2, '1', 2, '0', Move::None),
+        ]);
+        let program = program.without_stay().unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(4, &[
+            (1, '0', 4, '1', Move::Right),
+            (1, '1', 1, '0', Move::Right),
+            (2, '0', 3, '0', Move::Right),
+            (2, '1', 4, '0', Move::Right),
+            // Returns to halt
+            (3, '0', 0, '0', Move::Left),
+            (3, '1', 0, '1', Move::Left),
+            // Returns to the state 2
+            (4, '0', 2, '0', Move::Left),
+            (4, '1', 2, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn without_stay_unchanged() {
+        let program = new_program(1, &[(1, '0', 0, '1', Move::Left)]);
+        assert_eq!(program.without_stay(), Ok(program));
+    }
+
+    #[test]
+    fn fail_without_stay() {
+        let mut program = Program::new(vec!['0'], State(usize::MAX));
+        program.extend([(1, '0', 0, '0', Move::None)]).unwrap();
+        assert_eq!(
+            program.without_stay(),
+            Err(format!(
+                "stay error: last states {}, 1 are too large to be composed",
+                usize::MAX
+            ))
+        );
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    fn new_program(
+        l_state: usize,
+        tuples: &[(usize, char, usize, char, Move)],
+    ) -> Program<Box<char>> {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(l_state));
+        program
+            .extend(
+                tuples
+                    .iter()
+                    .map(|&(h_state, h_symbol, t_state, t_symbol, movement)| {
+                        (
+                            h_state,
+                            Box::new(h_symbol),
+                            t_state,
+                            Box::new(t_symbol),
+                            movement,
+                        )
+                    }),
+            )
+            .unwrap();
+        program
+    }
+
+    #[test]
+    fn branch_on_symbol() {
+        let read = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 0, '1', Move::None)],
+        );
+        let right = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Right), (1, '1', 0, '1', Move::Right)],
+        );
+        let left = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Left), (1, '1', 0, '1', Move::Left)],
+        );
+        let program = read
+            .branch_on_symbol(&[Box::new('1')], &right, &left)
+            .unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(4, &[
+            (1, '0', 2, '0', Move::None),
+            (1, '1', 2, '1', Move::None),
+            (2, '0', 4, '0', Move::None),
+            (2, '1', 3, '1', Move::None),
+            (3, '0', 0, '0', Move::Right),
+            (3, '1', 0, '1', Move::Right),
+            (4, '0', 0, '0', Move::Left),
+            (4, '1', 0, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn branch_on_state() {
+        let test = new_program(
+            2,
+            &[(1, '0', 0, '0', Move::None), (1, '1', 2, '1', Move::None)],
+        );
+        let right = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Right), (1, '1', 0, '1', Move::Right)],
+        );
+        let left = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Left), (1, '1', 0, '1', Move::Left)],
+        );
+        let program = test.branch_on_state(State(2), &right, &left).unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(4, &[
+            (1, '0', 4, '0', Move::None),
+            (1, '1', 3, '1', Move::None),
+            (3, '0', 0, '0', Move::Right),
+            (3, '1', 0, '1', Move::Right),
+            (4, '0', 0, '0', Move::Left),
+            (4, '1', 0, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn repeat_while() {
+        let right = new_program(
+            1,
+            &[(1, '0', 0, '0', Move::Right), (1, '1', 0, '1', Move::Right)],
+        );
+        let program = right.repeat_while(&[Box::new('1')]).unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(2, &[
+            (1, '0', 0, '0', Move::None),
+            (1, '1', 2, '1', Move::None),
+            (2, '0', 1, '0', Move::Right),
+            (2, '1', 1, '1', Move::Right),
+        ]);
+        assert_eq!(program, expected);
+    }
+
+    #[test]
+    fn without_stay() {
+        let program = new_program(1, &[(1, '1', 0, '0', Move::None)]);
+        let program = program.without_stay().unwrap();
+
+        #[rustfmt::skip]
+        let expected = new_program(2, &[
+            (1, '1', 2, '0', Move::Right),
+            (2, '0', 0, '0', Move::Left),
+            (2, '1', 0, '1', Move::Left),
+        ]);
+        assert_eq!(program, expected);
+    }
+}
@@ -0,0 +1,156 @@
+use turing_machine_rs::instruction::{Head, Instruction, Move, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::Tape;
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let program: Program<char> = "
+            # Inverts the tape
+            alphabet: _ 0 1
+            l_state: 2
+
+            1, 0 -> 1, 1, >
+            1 1 -> 1 0 R
+            1, _ -> 0, _, -
+        "
+        .parse()
+        .unwrap();
+
+        let mut expected = Program::new(vec!['_', '0', '1'], State(2));
+        expected
+            .extend([
+                (1, '0', 1, '1', Move::Right),
+                (1, '1', 1, '0', Move::Right),
+                (1, '_', 0, '_', Move::None),
+            ])
+            .unwrap();
+        assert_eq!(program, expected);
+
+        let machine = Classic::new(program, '_').unwrap();
+        let result = machine.translate_nrm(Tape::from("0110")).unwrap();
+        assert_eq!(result, Tape::from("1001_"));
+    }
+
+    #[test]
+    fn parse_without_headers() {
+        let program: Program<char> = "1, a -> 2, b, <\n2, b -> 0, c, L\n".parse().unwrap();
+        assert_eq!(program.alphabet(), &vec!['a', 'b', 'c']);
+        assert_eq!(program.l_state(), State(2));
+        assert_eq!(program.instructions().len(), 2);
+    }
+
+    #[test]
+    fn parse_quoted() {
+        let program: Program<char> = "alphabet: ' ' ',' ''' #\n1, ' ' -> 0, ',', N\n1 ''' -> 1 # >"
+            .parse()
+            .unwrap();
+        assert_eq!(program.alphabet(), &vec![' ', ',', '\'', '#']);
+        assert_eq!(
+            program.get(&Head::new(State(1), ' ')).unwrap(),
+            Some(&Instruction::build(
+                State(1),
+                ' ',
+                State(0),
+                ',',
+                Move::None
+            ))
+        );
+        assert_eq!(
+            program.get(&Head::new(State(1), '\'')).unwrap(),
+            Some(&Instruction::build(
+                State(1),
+                '\'',
+                State(1),
+                '#',
+                Move::Right
+            ))
+        );
+    }
+
+    #[test]
+    fn to_source() {
+        let mut program = Program::new(vec![' ', '0', ',', '\''], State(3));
+        program
+            .extend([
+                (1, ' ', 2, '0', Move::Right),
+                (2, ',', 3, '\'', Move::Left),
+                (3, '0', 0, ' ', Move::None),
+            ])
+            .unwrap();
+
+        let source = program.to_source();
+        assert_eq!(
+            source,
+            "alphabet: ' ' 0 ',' '''\nl_state: 3\n1, ' ' -> 2, 0, >\n2, ',' -> 3, ''', <\n3, 0 -> 0, ' ', -\n"
+        );
+        assert_eq!(source.parse::<Program<char>>().unwrap(), program);
+    }
+
+    #[test]
+    fn escapes() {
+        let mut program = Program::new(vec!['\n', '\t', '\\', '\r'], State(1));
+        program
+            .extend([
+                (1, '\n', 1, '\t', Move::Right),
+                (1, '\\', 0, '\r', Move::None),
+            ])
+            .unwrap();
+
+        let source = program.to_source();
+        assert_eq!(
+            source,
+            "alphabet: '\\n' '\\t' \\ '\\r'\nl_state: 1\n1, '\\n' -> 1, '\\t', >\n1, \\ -> 0, '\\r', -\n"
+        );
+        assert_eq!(source.parse::<Program<char>>().unwrap(), program);
+
+        let program: Program<char> = r"alphabet: '\' '''".parse().unwrap();
+        assert_eq!(program.alphabet(), &vec!['\\', '\'']);
+        let program: Program<char> = r"alphabet: '\\' '\''".parse().unwrap();
+        assert_eq!(program.alphabet(), &vec!['\\', '\'']);
+    }
+
+    #[test]
+    fn parse_instruction() {
+        let inst: Instruction<char> = " 1, ' ' -> 0, 1, < ".parse().unwrap();
+        assert_eq!(
+            inst,
+            Instruction::build(State(1), ' ', State(0), '1', Move::Left)
+        );
+
+        let error = "1, 0 -> 0, 1".parse::<Instruction<char>>().unwrap_err();
+        assert!(error.starts_with("parse error: expected instruction"));
+    }
+
+    #[test]
+    fn fail_parse() {
+        let cases = [
+            ("1, 0 -> 1, 1", "line 1"),
+            ("\n1, 0 -> 1, 1, ^", "line 2: unknown move"),
+            ("1, 00 -> 1, 1, >", "must be a single character"),
+            ("x, 0 -> 1, 1, >", "cannot parse state"),
+            ("alphabet: 0 1\n1, 2 -> 1, 1, >", "line 2: set error"),
+            ("l_state: 1\n1, 0 -> 2, 0, >", "line 2: set error"),
+            (
+                "1, 0 -> 1, 1, >\n1, 0 -> 0, 1, >",
+                "line 2: instruction for head (1, 0)",
+            ),
+            ("alphabet: ' 0", "unterminated quoted symbol"),
+        ];
+        for (text, message) in cases {
+            let error = text.parse::<Program<char>>().unwrap_err();
+            assert!(error.starts_with("parse error: "), "{}", error);
+            assert!(
+                error.contains(message),
+                "{} must contain {}",
+                error,
+                message
+            );
+        }
+    }
+}
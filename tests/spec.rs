@@ -0,0 +1,230 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::spec::{Case, Mismatch, Spec, Start};
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_machine() -> Classic<char> {
+        let program: Program<char> = "
+            alphabet: _ 0 1
+            1, 0 -> 1, 1, >
+            1, 1 -> 1, 0, >
+            1, _ -> 0, _, -
+        "
+        .parse()
+        .unwrap();
+        Classic::new(program, '_').unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let spec: Spec<char> = "
+            # The first case
+            name: first
+            input: 01
+            start: index 1
+            output: \" 1 \"
+            index: 2
+            state: 1
+            steps: 5
+            trim: yes
+
+            input: 1
+
+            # Comments don't split cases
+            input: \"\"
+            # start: std
+            start: std
+        "
+        .parse()
+        .unwrap();
+
+        let mut first = Case::new("first", vec!['0', '1']);
+        first.line = 3;
+        first.start = Start::Index(1);
+        first.output = Some(vec![' ', '1', ' ']);
+        first.index = Some(2);
+        first.state = State(1);
+        first.max_steps = 5;
+        first.trim = true;
+
+        let mut second = Case::new("case 2", vec!['1']);
+        second.line = 12;
+
+        let mut third = Case::new("case 3", vec![]);
+        third.line = 15;
+        third.start = Start::Std;
+
+        assert_eq!(spec, Spec::new(vec![first, second, third]));
+    }
+
+    #[test]
+    fn fail_parse() {
+        let cases = [
+            ("input 0", "line 1: expected `key: value`"),
+            ("input: 0\nfoo: 1", "line 2: unknown key \"foo\""),
+            ("input: 0\nstart: last", "line 2: start must be"),
+            ("input: 0\nstart: index x", "cannot parse number \"x\""),
+            ("input: 0\ntrim: true", "trim must be yes or no"),
+            (
+                "\nname: empty\noutput: 1",
+                "line 2: case empty has no input",
+            ),
+        ];
+        for (text, message) in cases {
+            let error = text.parse::<Spec<char>>().unwrap_err();
+            assert!(error.starts_with("spec error: "), "{}", error);
+            assert!(
+                error.contains(message),
+                "{} must contain {}",
+                error,
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn run() {
+        let spec: Spec<char> = "
+            name: passed
+            input: 0110
+            output: 1001_
+            index: 4
+
+            name: trimmed
+            input: 01
+            start: std
+            output: ___00___
+            trim: yes
+
+            name: wrong output
+            input: 01
+            output: 11_
+
+            name: limit
+            input: 0000
+            steps: 2
+
+            name: uncovered
+            input: 0
+            start: index 3
+        "
+        .parse()
+        .unwrap();
+
+        let report = spec.run(&new_machine());
+        assert_eq!(report.passed(), 2);
+        assert_eq!(report.failed(), 3);
+        assert!(!report.is_success());
+
+        let outcomes = report.outcomes();
+        assert!(outcomes[0].is_passed());
+        assert_eq!(outcomes[0].steps, 5);
+        assert_eq!(
+            outcomes[2].mismatches,
+            vec![Mismatch::new("output", "11_".into(), "10_".into())]
+        );
+        assert_eq!(
+            outcomes[3].mismatches,
+            vec![Mismatch::new(
+                "state",
+                "0".into(),
+                "1 (step limit 2 reached)".into()
+            )]
+        );
+        assert_eq!(
+            outcomes[4].error,
+            Some(String::from(
+                "index out of bounds: the len is 1 but the index is 3"
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_word() {
+        let spec: Spec<char> = "
+            input: \"\"
+            output: _
+            index: 0
+
+            input: \"\"
+            start: std
+            output: _
+            index: 0
+        "
+        .parse()
+        .unwrap();
+
+        let report = spec.run(&new_machine());
+        assert!(report.is_success(), "{:#}", report);
+        assert_eq!(report.outcomes()[1].steps, 1);
+    }
+
+    #[test]
+    fn report() {
+        let spec: Spec<char> = "
+            name: passed
+            input: 0
+
+            name: failed
+            input: 0110
+            output: 1011
+            index: 2
+        "
+        .parse()
+        .unwrap();
+
+        let report = spec.run(&new_machine());
+        assert_eq!(
+            report.to_string(),
+            "FAIL failed (line 5, 5 steps)\n    output:\n    - 1011\n    + 1001_\n        ^\n    index:\n    - 2\n    + 4\n      ^\nresult: FAILED. 1 passed; 1 failed"
+        );
+        assert!(format!("{:#}", report).starts_with("PASS passed (line 2, 2 steps)\nFAIL failed"));
+    }
+
+    #[test]
+    fn binary_addition() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let read =
+            |name: &str| std::fs::read_to_string(format!("{}/examples/programs/{}", root, name));
+        let program: Program<char> = read("binary_addition.tm").unwrap().parse().unwrap();
+        let spec: Spec<char> = read("binary_addition.spec").unwrap().parse().unwrap();
+
+        let report = spec.run(&Classic::new(program, ' ').unwrap());
+        assert!(report.is_success(), "{}", report);
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn run() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('1'), Move::None),
+                (1, Box::new('1'), 1, Box::new('0'), Move::Right),
+            ])
+            .unwrap();
+        let machine = Classic::new(program, Box::new('0')).unwrap();
+
+        let mut case = Case::new("carry", vec![Box::new('1'), Box::new('1')]);
+        case.output = Some(vec![Box::new('0'), Box::new('0'), Box::new('1')]);
+        case.index = Some(2);
+        let mut failed = Case::new("failed", vec![Box::new('1')]);
+        failed.output = Some(vec![Box::new('1'), Box::new('1')]);
+        failed.trim = true;
+
+        let report = Spec::new(vec![case, failed]).run(&machine);
+        assert!(report.outcomes()[0].is_passed());
+        assert_eq!(
+            report.outcomes()[1].mismatches,
+            vec![Mismatch::new("output", "11".into(), "1".into())]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use turing_machine_rs::instruction::{Move, State};
-use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::state::{Boundary, Configuration, Tape};
 
 #[cfg(test)]
 mod copy {
@@ -64,6 +64,44 @@ mod copy {
         assert_eq!(expected, conf);
     }
 
+    #[test]
+    fn fail_std_creation() {
+        let conf = Configuration::new_std(Tape::<char>::new([]));
+        assert!(conf.unwrap_err().starts_with("index out of bounds"));
+    }
+
+    #[test]
+    fn trimmed() {
+        let conf = Configuration::new_nrm(Tape::with_blank('_', "__ab_".chars())).unwrap();
+
+        assert_eq!(conf.blank(), Some(&'_'));
+        assert_eq!(conf.content_bounds(), Some((2, 3)));
+        assert_eq!(conf.trimmed(), vec!['a', 'b']);
+
+        let conf = Configuration::with_blank(Tape::from("__ab_"), '_', false);
+        assert_eq!(conf.trimmed(), vec!['a', 'b']);
+
+        // The known blank of the tape is kept
+        let conf = Configuration::with_blank(Tape::with_blank('b', "__ab_".chars()), '_', true);
+        assert_eq!(conf.blank(), Some(&'b'));
+        assert_eq!(conf.trimmed(), vec!['_', '_', 'a', 'b', '_']);
+    }
+
+    #[test]
+    fn blank_creation() {
+        let conf = Configuration::with_blank(Tape::from("test"), '_', false);
+        assert_eq!(conf, Configuration::new_nrm(Tape::from("test")).unwrap());
+
+        let conf = Configuration::with_blank(Tape::from("test"), '_', true);
+        assert_eq!(conf, Configuration::new_std(Tape::from("test")).unwrap());
+
+        let conf = Configuration::with_blank(Tape::<char>::new([]), '_', false);
+        assert_eq!(conf, Configuration::new_nrm(Tape::from("_")).unwrap());
+
+        let conf = Configuration::with_blank(Tape::<char>::new([]), '_', true);
+        assert_eq!(conf, Configuration::new_nrm(Tape::from("_")).unwrap());
+    }
+
     #[test]
     fn creation_equality() {
         let tape = Tape::from("test");
@@ -123,6 +161,47 @@ mod copy {
         assert_eq!(expected, conf);
     }
 
+    #[test]
+    fn shift_bounded() {
+        let mut conf = Configuration::new(Tape::from("ab"), 1, State(2)).unwrap();
+        assert_eq!(
+            conf.shift_bounded(Move::Left, '_', Boundary::Stay),
+            Ok(Move::Left)
+        );
+        assert_eq!(
+            conf.shift_bounded(Move::Left, '_', Boundary::Stay),
+            Ok(Move::None)
+        );
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("ab"), 0, State(2)).unwrap()
+        );
+
+        conf.shift_bounded(Move::Right, '_', Boundary::Error)
+            .unwrap();
+        conf.shift_bounded(Move::Left, '_', Boundary::Error)
+            .unwrap();
+        assert_eq!(
+            conf.shift_bounded(Move::Left, '_', Boundary::Error),
+            Err(String::from(
+                "shift error: cannot move left from the first cell in state 2"
+            ))
+        );
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("ab"), 0, State(2)).unwrap()
+        );
+
+        assert_eq!(
+            conf.shift_bounded(Move::Left, '_', Boundary::default()),
+            Ok(Move::Left)
+        );
+        assert_eq!(
+            conf,
+            Configuration::new(Tape::from("_ab"), 0, State(2)).unwrap()
+        );
+    }
+
     #[test]
     fn len() {
         let tape = Tape::from("test");
@@ -171,14 +250,14 @@ mod clone {
 
     #[test]
     fn success_creation() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let _ = Configuration::new(tape, 0, State(1));
     }
 
     #[test]
     #[should_panic]
     fn fail_creation() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let _ = Configuration::new(tape, 5, State(1)).unwrap();
     }
 
@@ -192,20 +271,12 @@ mod clone {
 
     #[test]
     fn index() {
-        let conf = Configuration::new(
-            Tape::new("test".chars().map(|ch| Box::new(ch))),
-            0,
-            State(1),
-        )
-        .unwrap();
+        let conf =
+            Configuration::new(Tape::new("test".chars().map(Box::new)), 0, State(1)).unwrap();
         assert_eq!(conf.index(), 0);
 
-        let conf = Configuration::new(
-            Tape::new("test".chars().map(|ch| Box::new(ch))),
-            1,
-            State(1),
-        )
-        .unwrap();
+        let conf =
+            Configuration::new(Tape::new("test".chars().map(Box::new)), 1, State(1)).unwrap();
         assert_eq!(conf.index(), 1);
     }
 
@@ -223,34 +294,63 @@ mod clone {
 
     #[test]
     fn nrm_creation() {
-        let conf =
-            Configuration::new_nrm(Tape::new("test".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_nrm(Tape::new("test".chars().map(Box::new))).unwrap();
 
-        let expected = Configuration::new(
-            Tape::new("test".chars().map(|ch| Box::new(ch))),
-            0,
-            State(1),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new("test".chars().map(Box::new)), 0, State(1)).unwrap();
 
         assert_eq!(expected, conf);
     }
 
     #[test]
     fn std_creation() {
-        let conf = Configuration::new_std(Tape::new("test".chars().map(|ch| Box::new(ch))));
-        let expected = Configuration::new(
-            Tape::new("test".chars().map(|ch| Box::new(ch))),
-            3,
-            State(1),
-        );
+        let conf = Configuration::new_std(Tape::new("test".chars().map(Box::new)));
+        let expected = Configuration::new(Tape::new("test".chars().map(Box::new)), 3, State(1));
 
         assert_eq!(expected, conf);
     }
 
+    #[test]
+    fn fail_std_creation() {
+        let conf = Configuration::new_std(Tape::<Box<char>>::new([]));
+        assert!(conf.unwrap_err().starts_with("index out of bounds"));
+    }
+
+    #[test]
+    fn blank_creation() {
+        let conf = Configuration::with_blank(
+            Tape::new("test".chars().map(Box::new)),
+            Box::new('_'),
+            false,
+        );
+        assert_eq!(
+            conf,
+            Configuration::new_nrm(Tape::new("test".chars().map(Box::new))).unwrap()
+        );
+
+        let conf =
+            Configuration::with_blank(Tape::new("test".chars().map(Box::new)), Box::new('_'), true);
+        assert_eq!(
+            conf,
+            Configuration::new_std(Tape::new("test".chars().map(Box::new))).unwrap()
+        );
+
+        let conf = Configuration::with_blank(Tape::<Box<char>>::new([]), Box::new('_'), false);
+        assert_eq!(
+            conf,
+            Configuration::new_nrm(Tape::new([Box::new('_')])).unwrap()
+        );
+
+        let conf = Configuration::with_blank(Tape::<Box<char>>::new([]), Box::new('_'), true);
+        assert_eq!(
+            conf,
+            Configuration::new_nrm(Tape::new([Box::new('_')])).unwrap()
+        );
+    }
+
     #[test]
     fn creation_equality() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
 
         let lhs = Configuration::new_nrm(tape.clone()).unwrap();
         let rhs = Configuration::new(tape.clone(), 0, State(1)).unwrap();
@@ -263,7 +363,7 @@ mod clone {
 
     #[test]
     fn tape() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let conf = Configuration::new_std(tape.clone()).unwrap();
 
         assert_eq!(conf.tape().as_vec(), tape.as_vec());
@@ -271,35 +371,26 @@ mod clone {
 
     #[test]
     fn set_symbol() {
-        let mut conf = Configuration::new(
-            Tape::new("test".chars().map(|ch| Box::new(ch))),
-            0,
-            State(1),
-        )
-        .unwrap();
+        let mut conf =
+            Configuration::new(Tape::new("test".chars().map(Box::new)), 0, State(1)).unwrap();
         conf.set_symbol(Box::new('T'));
 
-        let expected = Configuration::new(
-            Tape::new("Test".chars().map(|ch| Box::new(ch))),
-            0,
-            State(1),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new("Test".chars().map(Box::new)), 0, State(1)).unwrap();
 
         assert_eq!(expected, conf);
     }
 
     #[test]
     fn get_symbol() {
-        let conf =
-            Configuration::new_std(Tape::new("test!".chars().map(|ch| Box::new(ch)))).unwrap();
+        let conf = Configuration::new_std(Tape::new("test!".chars().map(Box::new))).unwrap();
 
         assert_eq!(*conf.get_symbol(), Box::new('!'));
     }
 
     #[test]
     fn shift() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let mut conf = Configuration::new_std(tape).unwrap();
 
         conf.shift(Move::Right, Box::new('!')); // test!
@@ -314,18 +405,30 @@ mod clone {
                                                // ^
         conf.set_symbol(Box::new('T'));
 
-        let expected = Configuration::new(
-            Tape::new("Test!".chars().map(|ch| Box::new(ch))),
-            0,
-            State(1),
-        )
-        .unwrap();
+        let expected =
+            Configuration::new(Tape::new("Test!".chars().map(Box::new)), 0, State(1)).unwrap();
         assert_eq!(expected, conf);
     }
 
+    #[test]
+    fn shift_bounded() {
+        let tape = Tape::new("ab".chars().map(Box::new));
+        let mut conf = Configuration::new_nrm(tape.clone()).unwrap();
+        conf.shift_bounded(Move::Left, Box::new('_'), Boundary::Stay)
+            .unwrap();
+        assert_eq!(conf, Configuration::new_nrm(tape).unwrap());
+
+        assert_eq!(
+            conf.shift_bounded(Move::Left, Box::new('_'), Boundary::Error),
+            Err(String::from(
+                "shift error: cannot move left from the first cell in state 1"
+            ))
+        );
+    }
+
     #[test]
     fn len() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let conf = Configuration::new_std(tape.clone()).unwrap();
 
         assert_eq!(conf.len(), tape.len());
@@ -341,7 +444,7 @@ mod clone {
         // Whatever going on, configuration state must not be changed by self methods
         // It must be changed only by outside
 
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let mut conf = Configuration::new(tape, 0, State(1)).unwrap();
 
         let _ = conf.index();
@@ -0,0 +1,146 @@
+use turing_machine_rs::instruction::State;
+use turing_machine_rs::state::{Configuration, Renderer, SparseTape, Style, Tape};
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn textbook() {
+        let conf = Configuration::new(Tape::from("01101"), 2, State(2)).unwrap();
+        let renderer = Renderer::new(Style::Textbook);
+
+        assert_eq!(renderer.render(&conf), String::from("01q₂101"));
+
+        let conf = Configuration::new(Tape::from("01101"), 0, State(12)).unwrap();
+        assert_eq!(renderer.render(&conf), String::from("q₁₂01101"));
+    }
+
+    #[test]
+    fn caret() {
+        let conf = Configuration::new(Tape::from("01101"), 2, State(2)).unwrap();
+        let renderer = Renderer::new(Style::Caret);
+
+        assert_eq!(renderer.render(&conf), String::from("01101\n  ^ q₂"));
+    }
+
+    #[test]
+    fn window() {
+        let conf = Configuration::new(Tape::from("0123456789"), 5, State(1)).unwrap();
+        let mut renderer = Renderer::new(Style::Textbook);
+        renderer.set_window(Some(2));
+
+        assert_eq!(renderer.render(&conf), String::from("34q₁567"));
+
+        let conf = Configuration::new(Tape::from("0123456789"), 1, State(1)).unwrap();
+        assert_eq!(renderer.render(&conf), String::from("0q₁123"));
+    }
+
+    #[test]
+    fn blank() {
+        let conf = Configuration::new(Tape::from("__01_1___"), 3, State(1)).unwrap();
+        let mut renderer = Renderer::new(Style::Textbook);
+        renderer.set_blank('_');
+
+        assert_eq!(renderer.render(&conf), String::from("0q₁1_1"));
+
+        let conf = Configuration::new(Tape::from("__01_1___"), 7, State(1)).unwrap();
+        assert_eq!(renderer.render(&conf), String::from("01_1_q₁_"));
+
+        let conf = Configuration::new(Tape::from("___"), 1, State(1)).unwrap();
+        assert_eq!(renderer.render(&conf), String::from("q₁_"));
+    }
+
+    #[test]
+    fn colored() {
+        let conf = Configuration::new(Tape::from("010"), 1, State(3)).unwrap();
+        let mut renderer = Renderer::new(Style::Textbook);
+        renderer.set_colored(true);
+
+        assert_eq!(
+            renderer.render(&conf),
+            String::from("0\x1b[1;33mq₃\x1b[0m\x1b[7m1\x1b[0m0")
+        );
+
+        renderer.set_style(Style::Caret);
+        assert_eq!(
+            renderer.render(&conf),
+            String::from("0\x1b[7m1\x1b[0m0\n ^ \x1b[1;33mq₃\x1b[0m")
+        );
+    }
+
+    #[test]
+    fn render_tape() {
+        let mut renderer = Renderer::new(Style::Textbook);
+        assert_eq!(
+            renderer.render_tape(&Tape::from("__01_1__")),
+            String::from("__01_1__")
+        );
+
+        renderer.set_blank('_');
+        assert_eq!(
+            renderer.render_tape(&Tape::from("__01_1__")),
+            String::from("01_1")
+        );
+        assert_eq!(renderer.render_tape(&Tape::from("___")), String::new());
+    }
+
+    #[test]
+    fn sparse() {
+        let conf =
+            Configuration::new(SparseTape::from_symbols('0', "0110".chars()), 0, State(1)).unwrap();
+        let renderer = Renderer::new(Style::Textbook);
+
+        assert_eq!(renderer.render(&conf), String::from("q₁0110"));
+    }
+
+    #[test]
+    fn alternate() {
+        let conf = Configuration::new(Tape::from("01101"), 2, State(2)).unwrap();
+
+        assert_eq!(format!("{:#}", conf), String::from("01q₂101"));
+        assert_eq!(
+            format!("{}", conf),
+            String::from("Configuration { Tape: \"01101\", Index: 2, State: 2 }")
+        );
+        assert_eq!(format!("{:#}", conf.tape()), String::from("|0|1|1|0|1|"));
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn textbook() {
+        let conf =
+            Configuration::new(Tape::new("01101".chars().map(Box::new)), 2, State(2)).unwrap();
+        let renderer = Renderer::new(Style::Textbook);
+
+        assert_eq!(renderer.render(&conf), String::from("01q₂101"));
+    }
+
+    #[test]
+    fn caret() {
+        let conf = Configuration::new(
+            Tape::new(["ab", "c", "def"].iter().map(|s| Box::new(s.to_string()))),
+            2,
+            State(1),
+        )
+        .unwrap();
+        let renderer = Renderer::new(Style::Caret);
+
+        assert_eq!(renderer.render(&conf), String::from("abcdef\n   ^^^ q₁"));
+    }
+
+    #[test]
+    fn blank() {
+        let conf =
+            Configuration::new(Tape::new("__01_1___".chars().map(Box::new)), 3, State(1)).unwrap();
+        let mut renderer = Renderer::new(Style::Textbook);
+        renderer.set_blank(Box::new('_'));
+        renderer.set_window(Some(1));
+
+        assert_eq!(renderer.render(&conf), String::from("0q₁1_"));
+    }
+}
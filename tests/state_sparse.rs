@@ -0,0 +1,209 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::Classic;
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, SparseTape, Tape, TapeStorage};
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn creation() {
+        let tape = SparseTape::new('0');
+
+        assert!(tape.is_empty());
+        assert_eq!(tape.blank(), &'0');
+        assert_eq!(tape.bounds(), None);
+        assert_eq!(tape.range(), None);
+    }
+
+    #[test]
+    fn from_symbols() {
+        let tape = SparseTape::from_symbols('0', "0110".chars());
+
+        assert_eq!(tape.len(), 4);
+        assert_eq!(tape.range(), Some((0, 3)));
+        assert_eq!(tape.bounds(), Some((1, 2)));
+        assert_eq!(tape.get(0), Some(&'0'));
+        assert_eq!(tape.get(1), Some(&'1'));
+        assert_eq!(tape.get(4), None);
+    }
+
+    #[test]
+    fn trimmed() {
+        let mut tape = SparseTape::from_symbols('0', "0110".chars());
+        assert_eq!(TapeStorage::blank(&tape), Some(&'0'));
+        assert_eq!(tape.content_bounds(), Some((1, 2)));
+
+        tape.grow_left('1');
+        assert_eq!(tape.content_bounds(), Some((0, 3)));
+        assert_eq!(tape.trimmed(), vec!['1', '0', '1', '1']);
+        assert!(tape.content_eq(&Tape::with_blank('0', "010110".chars())));
+        assert_eq!(
+            SparseTape::from_symbols('0', "00".chars()).trimmed(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn set_at_far() {
+        let mut tape = SparseTape::from_symbols('0', "1".chars());
+        tape.set_at(-1_000_000, '1');
+        tape.set_at(1_000_000, '1');
+
+        assert_eq!(tape.bounds(), Some((-1_000_000, 1_000_000)));
+        assert_eq!(tape.len(), 2_000_001);
+        assert_eq!(tape.get_at(500), &'0');
+        assert_eq!(tape.get(0), Some(&'1'));
+        assert_eq!(tape.position(1_000_000), 0);
+    }
+
+    #[test]
+    fn set_blank() {
+        let mut tape = SparseTape::from_symbols('0', "111".chars());
+        tape.set(0, '0');
+        tape.set(2, '0');
+
+        assert_eq!(tape.bounds(), Some((1, 1)));
+        assert_eq!(tape.len(), 3);
+        assert_eq!(tape, SparseTape::from_symbols('0', "010".chars()));
+    }
+
+    #[test]
+    fn equality() {
+        let mut tape = SparseTape::from_symbols('0', "1".chars());
+        tape.grow_right('0');
+
+        assert_eq!(tape.range(), Some((0, 1)));
+        assert_eq!(tape, SparseTape::from_symbols('0', "1".chars()));
+        assert_ne!(tape, SparseTape::from_symbols('0', "01".chars()));
+        assert_ne!(tape, SparseTape::from_symbols('1', "1".chars()));
+    }
+
+    #[test]
+    fn equality_origin() {
+        // Only the position of the index `0` differs
+        let mut tape = SparseTape::from_symbols('0', "1".chars());
+        tape.grow_left('0');
+        assert_eq!(tape.bounds(), Some((0, 0)));
+        assert_ne!(tape, SparseTape::from_symbols('0', "1".chars()));
+
+        // Heads at the positions `-1` and `0`
+        let conf = Configuration::new_nrm(tape).unwrap();
+        let other = Configuration::new_nrm(SparseTape::from_symbols('0', "1".chars())).unwrap();
+        assert_eq!(conf.index(), other.index());
+        assert_ne!(conf, other);
+    }
+
+    #[test]
+    fn grow() {
+        let mut tape = SparseTape::new('0');
+        tape.grow_left('1');
+        tape.grow_left('0');
+        tape.grow_right('1');
+
+        assert_eq!(tape.range(), Some((-1, 1)));
+        assert_eq!(tape.iter().collect::<String>(), String::from("011"));
+    }
+
+    #[test]
+    fn to_string() {
+        let tape = SparseTape::from_symbols('0', "00101000".chars());
+        assert_eq!(tape.to_string(), String::from("101"));
+
+        let tape = SparseTape::from_symbols('0', "000".chars());
+        assert_eq!(tape.to_string(), String::new());
+    }
+
+    #[test]
+    fn configuration() {
+        let mut conf = Configuration::new_nrm(SparseTape::from_symbols('0', "1".chars())).unwrap();
+        conf.shift(Move::Left, '0');
+        conf.set_symbol('1');
+
+        assert_eq!(conf.index(), 0);
+        assert_eq!(conf.tape().range(), Some((-1, 0)));
+        assert_eq!(conf.tape().to_string(), String::from("11"));
+
+        // The output starts at the leftmost non-blank symbol, not at the index `0`
+        conf.shift(Move::Left, '0');
+        let (left, _) = conf.tape().bounds().unwrap();
+        assert_eq!(conf.tape().to_string(), String::from("11"));
+        assert_eq!(conf.tape().position(conf.index()) - left, -1);
+
+        conf.shift(Move::Right, '0');
+        conf.shift(Move::Right, '0');
+        let offset = (conf.tape().position(conf.index()) - left) as usize;
+        let output = conf.tape().to_string();
+        assert_eq!(output.chars().nth(offset), Some(*conf.get_symbol()));
+    }
+
+    #[test]
+    fn classic_execute() {
+        let mut program = Program::new(vec![' ', '0', '1'], State(2));
+        program
+            .extend([
+                (1, ' ', 2, ' ', Move::Right),
+                (1, '0', 1, '1', Move::Left),
+                (1, '1', 1, '0', Move::Left),
+                (2, ' ', 0, ' ', Move::Left),
+                (2, '0', 2, '0', Move::Right),
+                (2, '1', 2, '1', Move::Right),
+            ])
+            .unwrap();
+        let machine: Classic<char, SparseTape<char>> = Classic::for_storage(program, ' ').unwrap();
+
+        let tape = SparseTape::from_symbols(' ', "001100".chars());
+        let result = machine.translate_std(tape).unwrap();
+
+        assert_eq!(result.range(), Some((-1, 6)));
+        assert_eq!(result.bounds(), Some((0, 5)));
+        assert_eq!(result.to_string(), String::from("110011"));
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn from_symbols() {
+        let tape = SparseTape::from_symbols(Box::new('0'), "0110".chars().map(Box::new));
+
+        assert_eq!(tape.len(), 4);
+        assert_eq!(tape.bounds(), Some((1, 2)));
+        assert_eq!(tape.get(1), Some(&Box::new('1')));
+        assert_eq!(tape.get(4), None);
+    }
+
+    #[test]
+    fn set_at_far() {
+        let mut tape = SparseTape::new(Box::new('0'));
+        tape.set_at(-100, Box::new('1'));
+        tape.set_at(100, Box::new('1'));
+
+        assert_eq!(tape.bounds(), Some((-100, 100)));
+        assert_eq!(tape.len(), 201);
+        assert_eq!(tape.get_at(0), &Box::new('0'));
+    }
+
+    #[test]
+    fn classic_execute() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('1'), Move::None),
+                (1, Box::new('1'), 1, Box::new('1'), Move::Left),
+            ])
+            .unwrap();
+        let machine: Classic<Box<char>, SparseTape<Box<char>>> =
+            Classic::for_storage(program, Box::new('0')).unwrap();
+
+        let tape = SparseTape::from_symbols(Box::new('0'), "11".chars().map(Box::new));
+        let result = machine.translate_nrm(tape).unwrap();
+
+        assert_eq!(result.bounds(), Some((-1, 1)));
+        assert_eq!(result.to_string(), String::from("111"));
+    }
+}
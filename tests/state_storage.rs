@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+use std::fmt::{Display, Error, Formatter};
+
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, Debugger};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape, TapeStorage};
+use turing_machine_rs::{Symbol, TuringMachine};
+
+/// Storage that is used only for checking that machines work
+/// with any [`TapeStorage`] realization.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Deque<S: Symbol>(VecDeque<S>);
+
+impl<S: Symbol> Deque<S> {
+    fn new(symbols: impl IntoIterator<Item = S>) -> Self {
+        Deque(symbols.into_iter().collect())
+    }
+}
+
+impl<S: Symbol> TapeStorage<S> for Deque<S> {
+    fn get(&self, index: usize) -> Option<&S> {
+        self.0.get(index)
+    }
+
+    fn set(&mut self, index: usize, symbol: S) {
+        self.0[index] = symbol;
+    }
+
+    fn grow_left(&mut self, symbol: S) {
+        self.0.push_front(symbol);
+    }
+
+    fn grow_right(&mut self, symbol: S) {
+        self.0.push_back(symbol);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &S> + '_> {
+        Box::new(self.0.iter())
+    }
+}
+
+impl<S: Symbol> Display for Deque<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        for symbol in &self.0 {
+            write!(f, "{}", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+/// Broken storage which loses all symbols, it's used only for checking
+/// that machines return errors instead of panics.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Lossy<S: Symbol>(Deque<S>);
+
+impl<S: Symbol> TapeStorage<S> for Lossy<S> {
+    fn get(&self, _: usize) -> Option<&S> {
+        None
+    }
+
+    fn set(&mut self, index: usize, symbol: S) {
+        self.0.set(index, symbol);
+    }
+
+    fn grow_left(&mut self, symbol: S) {
+        self.0.grow_left(symbol);
+    }
+
+    fn grow_right(&mut self, symbol: S) {
+        self.0.grow_right(symbol);
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = &S> + '_> {
+        self.0.iter()
+    }
+}
+
+impl<S: Symbol> Display for Lossy<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    #[test]
+    fn tape_storage() {
+        let mut tape = Tape::from("est");
+        tape.grow_left('t');
+        tape.grow_right('s');
+        TapeStorage::set(&mut tape, 4, '!');
+
+        assert_eq!(TapeStorage::len(&tape), 5);
+        assert_eq!(TapeStorage::get(&tape, 4), Some(&'!'));
+        assert_eq!(
+            String::from("test!"),
+            TapeStorage::iter(&tape).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn configuration_shift() {
+        let mut conf = Configuration::new_nrm(Deque::new("test".chars())).unwrap();
+        conf.shift(Move::Left, '_');
+
+        assert_eq!(conf.tape(), &Deque::new("_test".chars()));
+        assert_eq!(conf.index(), 0);
+
+        for _ in 0..5 {
+            conf.shift(Move::Right, '_');
+        }
+        assert_eq!(conf.tape(), &Deque::new("_test_".chars()));
+        assert_eq!(conf.index(), 5);
+    }
+
+    #[test]
+    fn classic_execute() {
+        let mut program = Program::new(vec![' ', '0', '1'], State(2));
+        program
+            .extend([
+                (1, ' ', 2, ' ', Move::Right),
+                (1, '0', 1, '1', Move::Left),
+                (1, '1', 1, '0', Move::Left),
+                (2, ' ', 0, ' ', Move::Left),
+                (2, '0', 2, '0', Move::Right),
+                (2, '1', 2, '1', Move::Right),
+            ])
+            .unwrap();
+        let machine: Classic<char, Deque<char>> = Classic::for_storage(program, ' ').unwrap();
+
+        let result = machine.translate_std(Deque::new("001100".chars())).unwrap();
+
+        assert_eq!(Deque::new(" 110011 ".chars()), result);
+    }
+
+    #[test]
+    fn lossy_execute() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program.extend([(1, '0', 0, '0', Move::None)]).unwrap();
+        let machine: Classic<char, Lossy<char>> = Classic::for_storage(program, '0').unwrap();
+
+        let conf = Configuration::new_nrm(Lossy(Deque::new("0".chars()))).unwrap();
+        let error =
+            String::from("get_symbol error: tape has no symbol at index 0 but the len is 1");
+        assert_eq!(conf.try_get_symbol(), Err(error.clone()));
+        assert_eq!(machine.execute(conf), Err(error));
+    }
+
+    #[test]
+    fn debugger_execute() {
+        let mut program = Program::new(vec!['0', '1'], State(1));
+        program
+            .extend([(1, '0', 0, '0', Move::None), (1, '1', 1, '0', Move::Right)])
+            .unwrap();
+        let machine: Classic<char, Deque<char>> = Classic::for_storage(program, '0').unwrap();
+        let debugger = Debugger::new(machine);
+
+        let result = debugger.translate_nrm(Deque::new("111".chars())).unwrap();
+
+        assert_eq!(Deque::new("0000".chars()), result);
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn tape_storage() {
+        let mut tape = Tape::new("est".chars().map(Box::new));
+        tape.grow_left(Box::new('t'));
+        tape.grow_right(Box::new('s'));
+        TapeStorage::set(&mut tape, 4, Box::new('!'));
+
+        assert_eq!(TapeStorage::len(&tape), 5);
+        assert_eq!(TapeStorage::get(&tape, 4), Some(&Box::new('!')));
+        assert_eq!(
+            String::from("test!"),
+            TapeStorage::iter(&tape).map(|ch| **ch).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn classic_execute() {
+        let mut program = Program::new(vec![Box::new('0'), Box::new('1')], State(1));
+        program
+            .extend([
+                (1, Box::new('0'), 0, Box::new('0'), Move::None),
+                (1, Box::new('1'), 1, Box::new('0'), Move::Right),
+            ])
+            .unwrap();
+        let machine: Classic<Box<char>, Deque<Box<char>>> =
+            Classic::for_storage(program, Box::new('0')).unwrap();
+
+        let result = machine
+            .translate_nrm(Deque::new("111".chars().map(Box::new)))
+            .unwrap();
+
+        assert_eq!(Deque::new("0000".chars().map(Box::new)), result);
+    }
+}
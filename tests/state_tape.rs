@@ -1,4 +1,4 @@
-use turing_machine_rs::state::Tape;
+use turing_machine_rs::state::{Tape, TapeStorage};
 
 #[cfg(test)]
 mod copy {
@@ -93,6 +93,33 @@ mod copy {
 
         assert_eq!(expected, tape);
     }
+
+    #[test]
+    fn blank() {
+        let mut tape = Tape::from("0110");
+        assert_eq!(tape.blank(), None);
+        assert_eq!(tape.content_bounds(), Some((0, 3)));
+
+        tape.set_blank('0');
+        assert_eq!(tape.blank(), Some(&'0'));
+        assert_eq!(tape.content_bounds(), Some((1, 2)));
+        // Blank symbols are not compared
+        assert_eq!(tape, Tape::with_blank('1', "0110".chars()));
+    }
+
+    #[test]
+    fn trimmed() {
+        let tape = Tape::with_blank('0', "0011010".chars());
+        assert_eq!(tape.content_bounds(), Some((2, 5)));
+        assert_eq!(tape.trimmed(), vec!['1', '1', '0', '1']);
+        assert!(tape.content_eq(&Tape::with_blank('0', "1101".chars())));
+        assert!(!tape.content_eq(&Tape::from("11010")));
+
+        let tape = Tape::with_blank('0', "000".chars());
+        assert_eq!(tape.content_bounds(), None);
+        assert_eq!(tape.trimmed(), vec![]);
+        assert!(tape.content_eq(&Tape::with_blank('0', [])));
+    }
 }
 
 #[cfg(test)]
@@ -101,20 +128,20 @@ mod clone {
 
     #[test]
     fn creation() {
-        let _ = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let _ = Tape::new("test".chars().map(Box::new));
     }
 
     #[test]
     fn as_vec() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
         let vec = tape.as_vec();
 
-        assert_eq!(*vec, Vec::from_iter("test".chars().map(|ch| Box::new(ch))));
+        assert_eq!(*vec, Vec::from_iter("test".chars().map(Box::new)));
     }
 
     #[test]
     fn get() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
 
         assert_eq!(tape.get(0), Some(&Box::new('t')));
         assert_eq!(tape.get(2), Some(&Box::new('s')));
@@ -122,11 +149,11 @@ mod clone {
 
     #[test]
     fn insert() {
-        let mut tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let mut tape = Tape::new("test".chars().map(Box::new));
         tape.insert(0, Box::new('0'));
         tape.insert(5, Box::new('1'));
 
-        let expected = Tape::new("0test1".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("0test1".chars().map(Box::new));
 
         assert_eq!(expected, tape);
     }
@@ -142,7 +169,7 @@ mod clone {
 
     #[test]
     fn len() {
-        let mut tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let mut tape = Tape::new("test".chars().map(Box::new));
         assert_eq!(tape.len(), 4);
 
         tape.insert(0, Box::new('0'));
@@ -151,23 +178,35 @@ mod clone {
 
     #[test]
     fn set() {
-        let mut tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let mut tape = Tape::new("test".chars().map(Box::new));
         tape.set(0, Box::new('n'));
         tape.set(1, Box::new('i'));
         tape.set(2, Box::new('c'));
         tape.set(3, Box::new('e'));
 
-        let expected = Tape::new("nice".chars().map(|ch| Box::new(ch)));
+        let expected = Tape::new("nice".chars().map(Box::new));
 
         assert_eq!(expected, tape);
     }
 
     #[test]
     fn to_string() {
-        let tape = Tape::new("test".chars().map(|ch| Box::new(ch)));
+        let tape = Tape::new("test".chars().map(Box::new));
 
         let expected = String::from("test");
 
         assert_eq!(expected, tape.to_string());
     }
+
+    #[test]
+    fn trimmed() {
+        let tape = Tape::with_blank(Box::new('0'), "0011010".chars().map(Box::new));
+        assert_eq!(tape.blank(), Some(&Box::new('0')));
+        assert_eq!(tape.content_bounds(), Some((2, 5)));
+        assert_eq!(
+            tape.trimmed(),
+            Tape::new("1101".chars().map(Box::new)).as_vec().clone()
+        );
+        assert!(tape.content_eq(&Tape::new("1101".chars().map(Box::new))));
+    }
 }
@@ -0,0 +1,218 @@
+use turing_machine_rs::instruction::{Move, State};
+use turing_machine_rs::machines::{Classic, Tracer};
+use turing_machine_rs::program::{Extend, Program};
+use turing_machine_rs::state::{Configuration, Tape};
+use turing_machine_rs::trace::Trace;
+use turing_machine_rs::TuringMachine;
+
+#[cfg(test)]
+mod copy {
+    use super::*;
+
+    fn new_trace() -> (Program<char>, Trace<char>) {
+        let mut program = Program::new(vec!['0', '1'], State(2));
+        program
+            .extend([
+                (1, '0', 2, '1', Move::Left),
+                (1, '1', 1, '0', Move::Right),
+                (2, '0', 0, '1', Move::None),
+            ])
+            .unwrap();
+        let tracer = Tracer::new(Classic::new(program.clone(), '0').unwrap());
+        let conf = Configuration::new_nrm(Tape::from("10")).unwrap();
+        tracer.execute(conf).unwrap();
+
+        (program, tracer.take_trace().unwrap())
+    }
+
+    #[test]
+    fn empty() {
+        let conf = Configuration::new_nrm(Tape::from("01")).unwrap();
+        let trace = Trace::new(&conf);
+
+        assert!(trace.is_empty());
+        assert_eq!(
+            trace.to_jsonl(),
+            String::from("{\"tape\":[\"0\",\"1\"],\"index\":0,\"state\":1}\n")
+        );
+        assert_eq!(
+            trace.to_csv(),
+            String::from("step,position,state,symbol,next,write,move,event\n")
+        );
+        assert_eq!(trace.to_diagram(), String::from("0 1 |[0] 1 |\n"));
+    }
+
+    #[test]
+    fn to_jsonl() {
+        let (_, trace) = new_trace();
+
+        let expected = concat!(
+            "{\"tape\":[\"1\",\"0\"],\"index\":0,\"state\":1}\n",
+            "{\"step\":1,\"position\":0,\"state\":1,\"symbol\":\"1\",\"next\":1,\"write\":\"0\",\"move\":\">\"}\n",
+            "{\"step\":2,\"position\":1,\"state\":1,\"symbol\":\"0\",\"next\":2,\"write\":\"1\",\"move\":\"<\"}\n",
+            "{\"step\":3,\"position\":0,\"state\":2,\"symbol\":\"0\",\"next\":0,\"write\":\"1\",\"move\":\"-\"}\n",
+        );
+        assert_eq!(trace.to_jsonl(), String::from(expected));
+    }
+
+    #[test]
+    fn to_csv() {
+        let (_, trace) = new_trace();
+
+        let expected = concat!(
+            "step,position,state,symbol,next,write,move,event\n",
+            "1,0,1,1,1,0,>,applied\n",
+            "2,1,1,0,2,1,<,applied\n",
+            "3,0,2,0,0,1,-,applied\n",
+        );
+        assert_eq!(trace.to_csv(), String::from(expected));
+    }
+
+    #[test]
+    fn to_diagram() {
+        let (_, trace) = new_trace();
+
+        let expected = concat!(
+            "0 1 |[1] 0 |\n",
+            "1 1 | 0 [0]|\n",
+            "2 2 |[0] 1 |\n",
+            "3 0 |[1] 1 |\n",
+        );
+        assert_eq!(trace.to_diagram(), String::from(expected));
+    }
+
+    #[test]
+    fn from_jsonl() {
+        let (_, trace) = new_trace();
+
+        let imported: Trace<char> = Trace::from_jsonl(&trace.to_jsonl()).unwrap();
+        assert_eq!(trace, imported);
+    }
+
+    #[test]
+    fn fail_from_jsonl() {
+        assert!(Trace::<char>::from_jsonl("").is_err());
+        assert!(Trace::<char>::from_jsonl("{\"tape\":\"01\",\"index\":0,\"state\":1}").is_err());
+
+        let text = concat!(
+            "{\"tape\":[\"1\"],\"index\":0,\"state\":1}\n",
+            "{\"step\":1,\"position\":0,\"state\":1,\"symbol\":\"1\",\"next\":0,\"write\":\"0\",\"move\":\"^\"}\n",
+        );
+        let error = Trace::<char>::from_jsonl(text).unwrap_err();
+        assert_eq!(error, String::from("import error: line 2: unknown move ^"));
+
+        let error = Trace::<char>::from_jsonl("{\"tape\":[],\"index\":-1,\"state\":1}");
+        assert_eq!(
+            error,
+            Err(String::from(
+                "import error: line 1: index and state cannot be negative"
+            ))
+        );
+        let error = Trace::<char>::from_jsonl("{\"tape\":[\"1\"],\"index\":0,\"state\":-1}");
+        assert_eq!(
+            error,
+            Err(String::from(
+                "import error: line 1: index and state cannot be negative"
+            ))
+        );
+        let error = Trace::<char>::from_jsonl("{\"tape\":[\"1\"],\"index\":1,\"state\":1}");
+        assert_eq!(
+            error,
+            Err(String::from(
+                "import error: line 1: index out of bounds: the len is 1 but the index is 1"
+            ))
+        );
+    }
+
+    #[test]
+    fn verify() {
+        let (program, trace) = new_trace();
+        trace.verify(&program).unwrap();
+
+        let mut other = Program::new(vec!['0', '1'], State(2));
+        other
+            .extend([
+                (1, '0', 2, '1', Move::Left),
+                (1, '1', 1, '0', Move::Right),
+                (2, '0', 0, '0', Move::None),
+            ])
+            .unwrap();
+        assert!(trace.verify(&other).is_err());
+    }
+
+    #[test]
+    fn events() {
+        let mut program = Program::new(vec!['0', '1'], State(2));
+        program.extend([(1, '0', 2, '1', Move::Left)]).unwrap();
+
+        let jsonl = concat!(
+            "{\"tape\":[\"0\"],\"index\":0,\"state\":1}\n",
+            "{\"step\":1,\"position\":0,\"state\":1,\"symbol\":\"0\",\"next\":2,\"write\":\"1\",\"move\":\"<\",\"event\":\"bounded\"}\n",
+            "{\"step\":2,\"position\":0,\"state\":2,\"symbol\":\"1\",\"next\":0,\"event\":\"uncovered\"}\n",
+        );
+        let trace: Trace<char> = Trace::from_jsonl(jsonl).unwrap();
+        assert_eq!(trace.to_jsonl(), String::from(jsonl));
+        assert_eq!(
+            trace.to_csv(),
+            String::from(concat!(
+                "step,position,state,symbol,next,write,move,event\n",
+                "1,0,1,0,2,1,<,bounded\n",
+                "2,0,2,1,0,,,uncovered\n",
+            ))
+        );
+        trace.verify(&program).unwrap();
+
+        program.extend([(2, '1', 0, '1', Move::None)]).unwrap();
+        assert_eq!(
+            trace.verify(&program),
+            Err(String::from(
+                "verify error: step 2 head (2, 1) is covered by instruction (2, 1) -> (0, 1, -)"
+            ))
+        );
+
+        let error = Trace::<char>::from_jsonl(&jsonl.replace("\"<\"", "\">\""));
+        assert_eq!(
+            error,
+            Err(String::from(
+                "import error: line 2: bounded step cannot move >"
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_verify_tampered() {
+        let (program, trace) = new_trace();
+        let jsonl = trace.to_jsonl().replace("\"position\":1", "\"position\":2");
+
+        let tampered: Trace<char> = Trace::from_jsonl(&jsonl).unwrap();
+        assert!(tampered.verify(&program).is_err());
+    }
+}
+
+#[cfg(test)]
+mod clone {
+    use super::*;
+
+    #[test]
+    fn escaped_symbols() {
+        let alphabet: Vec<String> = vec!["a,\"b\"".into(), "\\".into()];
+        let mut program = Program::new(alphabet.clone(), State(1));
+        program
+            .extend([(1, alphabet[0].clone(), 0, alphabet[1].clone(), Move::Right)])
+            .unwrap();
+        let tracer = Tracer::new(Classic::new(program.clone(), alphabet[1].clone()).unwrap());
+        tracer.translate_nrm(Tape::new(alphabet.clone())).unwrap();
+        let trace = tracer.take_trace().unwrap();
+
+        assert_eq!(
+            trace.to_csv(),
+            String::from(
+                "step,position,state,symbol,next,write,move,event\n1,0,1,\"a,\"\"b\"\"\",0,\\,>,applied\n"
+            )
+        );
+
+        let imported: Trace<String> = Trace::from_jsonl(&trace.to_jsonl()).unwrap();
+        assert_eq!(trace, imported);
+        imported.verify(&program).unwrap();
+    }
+}